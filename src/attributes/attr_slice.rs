@@ -13,7 +13,7 @@ impl<'s, A: Attribute> AttrSlice<'s, A>  {
 	pub fn len(&self) -> usize {
 		self.slice.len()
 	}
-	pub fn iter(&self) -> AttrSlice<A> {
+	pub fn iter(&self) -> AttrSlice<'_, A> {
 		AttrSlice {
 			slice: self.slice,
 			current: 0,
@@ -35,7 +35,7 @@ impl<'s, A: Attribute> Iterator for AttrSlice<'s, A>  {
 impl<'s, A: Attribute > Debug for AttrSlice<'s, A> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		for i in self.iter()  {
-			write!(f, "{:?}\n", i)?;
+			writeln!(f, "{:?}", i)?;
 		}
 		write!(f, "")
 	}
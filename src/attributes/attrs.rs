@@ -1,25 +1,29 @@
 use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
-use syn::{bracketed, Token};
 use crate::attributes::{Attribute, AttrSlice, CompiledAttrs, ParamAttr, parse_attribute, TypeAttr};
 
-pub struct Attrs<A: Attribute>(pub Vec<A>);
+/// # Attrs
+/// The Attributes declared on a single Type, Field, Variant or REST Method, along with the
+/// Span each one was declared at, i.e., ``` rename ``` within ``` #[rename = ".."] ```.
+/// Attributes added by Restify itself, after parsing, have no declared Span.
+#[derive(Clone)]
+pub struct Attrs<A: Attribute>(pub Vec<A>, Vec<Span>);
 
 impl<A: Attribute> Default for Attrs<A> {
 	fn default() -> Self {
-		Attrs(vec![])
+		Attrs(vec![], vec![])
 	}
 }
 
 impl<'a> FromIterator<&'a TypeAttr> for Attrs<TypeAttr> {
 	fn from_iter<T: IntoIterator<Item = &'a TypeAttr>>(iter: T) -> Self {
 		let attrs = iter.into_iter().cloned().collect::<Vec<TypeAttr>>();
-		Attrs(attrs)
+		Attrs(attrs, vec![])
 	}
 }
 
 impl<A: Attribute> Attrs<A> {
-	pub fn iter(&self) -> AttrSlice<A> {
+	pub fn iter(&self) -> AttrSlice<'_, A> {
 		return AttrSlice::new(self.0.as_slice());
 	}
 	
@@ -30,6 +34,12 @@ impl<A: Attribute> Attrs<A> {
 		let slice = self.iter();
 		return slice.into();
 	}
+	
+	/// Returns the Span the attribute at index was declared at, or the fallback when it
+	/// wasn't declared by the user.
+	pub fn declared_span(&self, index: usize, fallback: Span) -> Span {
+		return self.1.get(index).copied().unwrap_or(fallback);
+	}
 }
 
 impl Attrs<ParamAttr> {
@@ -37,27 +47,67 @@ impl Attrs<ParamAttr> {
 	/// Returning true if the method returns true.
 	/// Returns False if none of the ParamAttributes are struct-specific
 	pub fn contains_struct_specific(&self) -> Option<Span> {
-		for a in self.iter() {
-			let test = a.struct_specific();
-			if test.0  {
-				return Some(test.1);
+		for (i, a) in self.0.iter().enumerate() {
+			let (specific, span) = a.struct_specific();
+			if specific {
+				return Some(self.declared_span(i, span));
 			}
 		}
 		return None;
 	}
 }
 
+impl Attrs<TypeAttr> {
+	/// Returns the Span of the first enum-specific TypeAttr found, if any.
+	pub fn contains_enum_specific(&self) -> Option<Span> {
+		return self.0.iter()
+			.enumerate()
+			.find_map(|(i, a)| Some(self.declared_span(i, a.enum_specific()?)));
+	}
+	
+	/// Tests that the enum tagging attributes(tag, content & untagged) are combined
+	/// in a way that serde accepts.
+	///   - **content** requires **tag**, i.e., adjacently tagged.
+	///   - **untagged** can't be combined with either **tag** or **content**.
+	pub fn check_enum_tagging(&self) -> syn::Result<()> {
+		let tag = self.iter().find(|a| matches!(a, TypeAttr::Tag(_)));
+		let content = self.iter().find_map(|a| match a {
+			TypeAttr::Content(content) => Some(content),
+			_ => None,
+		});
+		let untagged = self.0.iter().position(|a| matches!(a, TypeAttr::Untagged));
+		
+		if let (Some(untagged), true) = (untagged, tag.is_some() || content.is_some()) {
+			return Err(syn::Error::new(
+				self.declared_span(untagged, Span::call_site()),
+				"TypeAttribute::Untagged - Can't be combined with either 'tag' or 'content'"
+			));
+		}
+		if let (None, Some(content)) = (tag, content) {
+			return Err(syn::Error::new(
+				content.span(),
+				"TypeAttribute::Content - Adjacently tagged enums require a 'tag' attribute as well"
+			));
+		}
+		return Ok(());
+	}
+}
+
 impl<A: Attribute> Parse for Attrs<A> {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let mut attributes = vec![];
+		let mut spans = vec![];
 		loop {
-			match parse_attribute::<A>(&input) {
+			match parse_attribute::<A>(input) {
 				Err(e) => return Err(e),
-				Ok(Some(attribute)) => attributes.push(attribute),
+				Ok(Some((attribute, span))) => {
+					attributes.push(attribute);
+					spans.push(span);
+				}
 				Ok(_) => break,
 			}
 		}
-		return Ok(Attrs(attributes));
+		return Ok(Attrs(attributes, spans));
 	}
 }
 
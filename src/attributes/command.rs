@@ -1,10 +1,19 @@
+
 use crate::parsers::struct_parameter::StructParameterSlice;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro2::Ident;
 use syn::Visibility;
+use crate::attributes::CommandStage;
 
 type BuilderInput<'s> = (&'s Visibility, &'s Ident, &'s StructParameterSlice<'s>);
 pub enum RunCommand<'s> {
 	Builder(Box<dyn FnOnce(BuilderInput<'s>) -> TokenStream2>),
+	Log(Box<dyn FnOnce(BuilderInput<'s>) -> TokenStream2>),
+	/// A Command whose generator has yet to be implemented.
+	/// It still holds its [CommandStage] within the composition order.
+	Pending(CommandStage),
+	/// A Command that isn't run on its own. Its owning Type collects it together with
+	/// every other Command of the same [CommandStage], i.e., every field-level
+	/// ``` #[validate(..)] ``` ends up in a single ``` validate() ``` method.
+	Collected(CommandStage),
 }
-
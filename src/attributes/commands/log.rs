@@ -1,10 +1,12 @@
 use std::fmt::{Debug, Display, Formatter};
 use displaydoc::Display;
-use proc_macro2::Ident;
-use regex::Regex;
-use syn::{LitStr, parenthesized, Token};
+use proc_macro2::{Ident, Span};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{LitStr, parenthesized, Token, Visibility};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
+use crate::parsers::struct_parameter::StructParameterSlice;
 use crate::rest_api::SynError;
 
 /// # LogLevel
@@ -19,7 +21,7 @@ use crate::rest_api::SynError;
 ///   - **Warn**
 ///   - **Debug**
 ///   - **Error**
-#[derive(Clone, Debug, Display)]
+#[derive(Clone, Debug, Display, PartialEq)]
 pub enum LogLevel {
 	/// info
 	Info,
@@ -44,7 +46,48 @@ impl Parse for LogLevel {
 			"error" => Ok(LogLevel::Error),
 			unknown => Err(SynError::new(
 				unknown.span(),
-				&format!("Attribute::Log: Found an unknown level attribute: \"{unknown}\"")
+				format!("Attribute::Log: Found an unknown level attribute: \"{unknown}\"")
+			)),
+		}
+	}
+}
+
+/// # LogBackend
+/// The logging facade the generated log calls are made with.
+///   - ``` #[log(backend = "log", ..)] ```: The default. Generates ``` ::log::info!(..) ```
+///   - ``` #[log(backend = "tracing", ..)] ```: Generates ``` ::tracing::info!(..) ```
+///   - ``` #[log(backend = "slog", ..)] ```: Generates ``` ::slog::info!(logger, ..) ```.
+///     Since slog has no global logger, every generated log method takes a
+///     ``` logger: &::slog::Logger ``` as its first parameter.
+#[derive(Clone, Debug, Default, Display, PartialEq)]
+pub enum LogBackend {
+	/// log
+	#[default]
+	Log,
+	/// tracing
+	Tracing,
+	/// slog
+	Slog,
+}
+impl Parse for LogBackend {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		input.parse::<Token![=]>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"Attribute::Log: backend and the backend's name must be separated by the '=' token"
+			))?;
+		let backend = input.parse::<LitStr>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"Attribute::Log: The backend's name should be a literal string."
+			))?;
+		return match backend.value().as_str() {
+			"log"     => Ok(LogBackend::Log),
+			"tracing" => Ok(LogBackend::Tracing),
+			"slog"    => Ok(LogBackend::Slog),
+			unknown   => Err(SynError::new(
+				backend.span(),
+				format!("Attribute::Log: Found an unknown backend: \"{unknown}\". (log|tracing|slog)")
 			)),
 		}
 	}
@@ -60,12 +103,48 @@ pub struct LogFormatStr {
 	pub msg: LitStr,
 }
 impl LogFormatStr {
-	fn search_for_formatters(msg: &str) -> syn::Result<bool> {
-		//TODO: Once the format_detection utility method is in place. I need to
-		//      pass the parsed LitStr through here to make sure the string is
-		//      properly formatted or not.
-		
-		Ok(true)
+	/// Returns the names of every placeholder within a format string, in the order they
+	/// first appear. ``` "{{" ``` and ``` "}}" ``` are treated as escaped braces, and a
+	/// format spec, i.e., ``` "{ids:?}" ```, is stripped from the name.
+	///
+	/// # Errors:
+	///   - An unclosed or unopened brace.
+	///   - A positional placeholder, i.e., ``` "{}" ```. Every logged value must be named.
+	///   - A placeholder that isn't a plain identifier, i.e., ``` "{a.b}" ``` or ``` "{r#type}" ```,
+	///     or that names ``` self ```, since every placeholder becomes a field or a parameter.
+	pub fn placeholders(msg: &str) -> Result<Vec<String>, String> {
+		let mut names: Vec<String> = vec![];
+		let mut chars = msg.chars().peekable();
+		while let Some(c) = chars.next() {
+			match c {
+				'{' if chars.peek() == Some(&'{') => { chars.next(); }
+				'}' if chars.peek() == Some(&'}') => { chars.next(); }
+				'}' => return Err(format!("Found an unopened '}}' within \"{msg}\"")),
+				'{' => {
+					let mut placeholder = String::new();
+					loop {
+						match chars.next() {
+							Some('}') => break,
+							Some('{') | None => return Err(format!("Found an unclosed '{{' within \"{msg}\"")),
+							Some(c) => placeholder.push(c),
+						}
+					}
+					let name = placeholder.split(':').next().unwrap_or_default().trim().to_string();
+					if name.is_empty() || name.parse::<usize>().is_ok() {
+						return Err(format!("Positional placeholders aren't supported, name the logged value instead: \"{msg}\""));
+					}
+					// Raw identifiers parse, but aren't supported within format strings.
+					if name == "self" || name.starts_with("r#") || syn::parse_str::<Ident>(&name).is_err() {
+						return Err(format!("\"{{{name}}}\" isn't a valid placeholder, expected the name of a field or a parameter: \"{msg}\""));
+					}
+					if !names.contains(&name) {
+						names.push(name);
+					}
+				}
+				_ => {}
+			}
+		}
+		return Ok(names);
 	}
 	pub fn parse_annotation(input: ParseStream) -> syn::Result<(Self, bool)> {
 		let msg = input.parse::<LitStr>()
@@ -73,8 +152,9 @@ impl LogFormatStr {
 				syn.span(),
 				"Attribute::Log: Annotation should be a literal string."
 			))?;
-		let re: Regex = Regex::new(r"\{\w+}").unwrap();
-		let look_back = re.is_match(&msg.value().as_str());
+		let look_back = !LogFormatStr::placeholders(&msg.value())
+			.map_err(|err| SynError::new(msg.span(), format!("Attribute::Log: {err}")))?
+			.is_empty();
 		return Ok((LogFormatStr { msg }, look_back));
 	}
 }
@@ -82,12 +162,12 @@ impl LogFormatStr {
 /// # Attribute::Log
 /// Attribute Command that will command Restify to generate a logging system
 /// per Type or Parameter that the Attribute is assigned to.
-/// For the time being; Attribute::Log will use the [env_logger] within all
-/// generated restify code. Eventually, I would like to implement some kind
-/// of system architecture that would allow users to use whatever logging system
-/// they so choose.
-/// * Note: Even though Attribute::Log will generate and include env_logger calls
-///   within the generated code. It will still be up to the user to initiate env_logger
+/// By default, Attribute::Log will use the **log** facade within all generated restify
+/// code. Users can pick a different facade via ``` #[log(backend = "tracing", ..)] ```,
+/// See [LogBackend].
+/// * Note: Even though Attribute::Log will generate and include log calls
+///   within the generated code. It will still be up to the user to depend on the chosen
+///   facade, and to initiate a logger, i.e., env_logger
 /// # Parameters:
 ///   - [LogLevel] level: Tells Restify which Log level to generate
 ///   - [LogFormatStr] format_str: This format string will be what's logged.
@@ -98,6 +178,22 @@ pub struct LogCmd {
 	pub format_str: LogFormatStr,
 }
 impl LogCmd {
+	/// The name of the log method generated for this command, i.e., ``` log_info ```.
+	fn fn_name(&self) -> Ident {
+		return Ident::new(&format!("log_{}", self.level), Span::call_site());
+	}
+	
+	/// Splits the format string's placeholders into those naming one of the Type's fields,
+	/// and those that become a parameter of the generated log method.
+	fn placeholders(&self, fields: &StructParameterSlice) -> (Vec<Ident>, Vec<Ident>) {
+		let msg = &self.format_str.msg;
+		return LogFormatStr::placeholders(&msg.value())
+			.unwrap_or_default()
+			.iter()
+			.map(|placeholder| Ident::new(placeholder, msg.span()))
+			.partition(|placeholder| fields.iter().any(|field| &field.name == placeholder));
+	}
+	
 	fn parse_cmd(input: ParseStream) -> syn::Result<(Self, bool)> {
 		let level = input.parse::<LogLevel>()?;
 		input.parse::<Token![=]>()
@@ -105,12 +201,13 @@ impl LogCmd {
 				syn.span(),
 				"Attribute::Log: Level Identifier and format string must be separated by the '=' token"
 			))?;
-		let annotation = LogFormatStr::parse_annotation(&input)?;
+		let annotation = LogFormatStr::parse_annotation(input)?;
 		return Ok((LogCmd{level, format_str: annotation.0}, annotation.1));
 	}
 }
 
 /// # Parameters:
+///   - [LogBackend] backend: The logging facade used by the generated log calls.
 ///   - [Vec]<[LogCmd]> commands: A Vector that contains all parsed restify log commands
 ///   - [bool] require_look_back: Before we return [Log] back to the parent method that parsed it. We
 ///     first test to see if any of the log annotations contains a formatter parameter, '{some_val}'.
@@ -118,6 +215,7 @@ impl LogCmd {
 ///     and see if the user included a valid format parameter. I.e., if the variable exists.
 #[derive(Clone)]
 pub struct Log {
+	pub backend: LogBackend,
 	pub commands: Vec<LogCmd>,
 	pub require_look_back: bool,
 }
@@ -127,13 +225,129 @@ impl Log {
 		parenthesized!(content in input);
 		return content.parse();
 	}
+	
+	/// # Log: Generate Log Methods
+	/// Generates one ``` log_<level> ``` method per [LogCmd] for the parent Type.
+	/// Every ``` {placeholder} ``` within a format string that matches one of the Type's
+	/// fields is resolved against ``` self ```. Placeholders that don't match a field,
+	/// i.e., the ``` {error} ``` of a failed request, become a parameter of the
+	/// generated method. A generated ``` build() ``` calls into them, see [Log::quote_build_calls].
+	/// ``` #[sensitive] ``` fields are logged as ``` *** ```.
+	///
+	/// ```ignore
+	/// #[log(info = "Sending {ids:?}", error = "Failed to send: {error}")]
+	/// struct MyIDs<Request> { ids: Vec<u64> }
+	///
+	/// impl MyIDs {
+	///   fn log_info(&self) {
+	///     ::log::info!("Sending {ids:?}", ids = self.ids);
+	///   }
+	///   fn log_error(&self, error: &dyn ::core::fmt::Display) {
+	///     ::log::error!("Failed to send: {error}", error = error);
+	///   }
+	/// }
+	/// ```
+	pub fn quote_log_methods(
+		&self,
+		vis    : &Visibility,
+		name   : &Ident,
+		fields : &StructParameterSlice,
+	) -> TokenStream2 {
+		let (facade, logger, logger_param) = match self.backend {
+			LogBackend::Log     => (quote!(::log), quote!(), quote!()),
+			LogBackend::Tracing => (quote!(::tracing), quote!(), quote!()),
+			LogBackend::Slog    => (quote!(::slog), quote!(logger,), quote!(, logger: &::slog::Logger)),
+		};
+		let methods = self.commands.iter().map(|cmd| {
+			let level = Ident::new(&cmd.level.to_string(), Span::call_site());
+			let fn_name = cmd.fn_name();
+			let msg = &cmd.format_str.msg;
+			let (field_args, params) = cmd.placeholders(fields);
+			// #[sensitive] fields are never logged.
+			let field_values = field_args.iter().map(|arg| {
+				match fields.iter().any(|field| &field.name == arg && field.is_sensitive()) {
+					true  => quote!("***"),
+					false => quote!(self.#arg),
+				}
+			});
+			
+			quote!(
+				#vis fn #fn_name(&self #logger_param #(, #params: &dyn ::core::fmt::Display )*) {
+					#facade::#level!(
+						#logger
+						#msg
+						#(, #field_args = #field_values )*
+						#(, #params = #params )*
+					);
+				}
+			)
+		});
+		let (impl_generics, ty_generics, where_clause) = fields.generics().split_for_impl();
+		return quote!(
+			impl #impl_generics #name #ty_generics #where_clause {
+				#( #methods )*
+			}
+		);
+	}
+	
+	/// # Log: Generated Build
+	/// Quotes the calls a generated ``` build() ``` makes into the log methods of
+	/// [Log::quote_log_methods], around the built value's ``` validate() ```. Returns the calls
+	/// made once the value is valid, ``` info ``` and ``` debug ```, and the calls made with
+	/// its ``` errors ``` once it isn't, ``` warn ``` and ``` error ```.
+	/// Only methods ``` build() ``` has every argument for are called, so never a slog method,
+	/// nor a method taking a placeholder parameter beyond the ``` errors ```.
+	pub fn quote_build_calls(&self, fields: &StructParameterSlice) -> (TokenStream2, TokenStream2) {
+		if self.backend == LogBackend::Slog {
+			return (quote!(), quote!());
+		}
+		let calls = |levels: &[LogLevel], errors: TokenStream2| {
+			let calls = self.commands.iter()
+				.filter(|cmd| levels.contains(&cmd.level))
+				.filter_map(|cmd| {
+					let fn_name = cmd.fn_name();
+					return match cmd.placeholders(fields).1.len() {
+						0 => Some(quote!( built.#fn_name(); )),
+						1 if !errors.is_empty() => Some(quote!( built.#fn_name(#errors); )),
+						_ => None,
+					};
+				});
+			return quote!( #( #calls )* );
+		};
+		return (
+			calls(&[LogLevel::Debug, LogLevel::Info], quote!()),
+			calls(&[LogLevel::Warn, LogLevel::Error], quote!(&errors)),
+		);
+	}
 }
 impl Parse for Log {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let mut commands: Vec<LogCmd> = vec![];
 		let mut require_look_back = false;
+		let mut backend: Option<LogBackend> = None;
 		loop {
-			let (cmd, look_back) = LogCmd::parse_cmd(&input)?;
+			if input.fork().parse::<Ident>().is_ok_and(|ident| ident == "backend") {
+				let ident = input.parse::<Ident>()?;
+				if backend.is_some() {
+					return Err(SynError::new(ident.span(), "Attribute::Log: The backend was already declared"));
+				}
+				backend = Some(input.parse::<LogBackend>()?);
+				
+				if input.is_empty(){ break; }
+				input.parse::<Token![,]>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"Attribute::Log: Multiple log commands should be comma delimited"
+					))?;
+				continue;
+			}
+			let (cmd, look_back) = LogCmd::parse_cmd(input)?;
+			if commands.iter().any(|c: &LogCmd| c.level == cmd.level) {
+				return Err(SynError::new(
+					cmd.format_str.msg.span(),
+					format!("Attribute::Log: The \"{}\" level was already declared", cmd.level)
+				));
+			}
 			if look_back {
 				require_look_back = true;
 			}
@@ -146,12 +360,19 @@ impl Parse for Log {
 					"Attribute::Log: Multiple log commands should be comma delimited"
 				))?;
 		}
-		println!("Commands: ");
 		for c in commands.iter() {
-			println!("\t{}", c);
+			log::trace!("Attribute::Log - {}", c);
+		}
+		
+		if commands.is_empty() {
+			return Err(SynError::new(
+				input.span(),
+				"Attribute::Log: Requires at least one log command, i.e., info = \"..\""
+			));
 		}
 		
 		return Ok(Log{
+			backend: backend.unwrap_or_default(),
 			commands,
 			require_look_back,
 		});
@@ -161,14 +382,17 @@ impl Parse for Log {
 impl Display for Log {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(f, "#[log(")?;
+		if self.backend != LogBackend::Log {
+			write!(f, "backend = \"{}\", ", self.backend)?;
+		}
 		if self.commands.len() == 1 {
-			write!(f, "{})]\n", self.commands.first().unwrap());
+			writeln!(f, "{})]", self.commands.first().unwrap())?;
 			return Ok(());
 		}
 		for log in self.commands.iter() {
-			write!(f, "{}\n", log)?;
+			writeln!(f, "{}", log)?;
 		}
-		write!(f, ")]\n")
+		writeln!(f, ")]")
 	}
 }
 impl Display for LogCmd {
@@ -181,4 +405,69 @@ impl Debug for Log {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}", self)
 	}
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod log_tests {
+	use super::*;
+	use quote::quote;
+	use crate::generators::generate;
+	
+	#[test] fn placeholders() {
+		assert_eq!(
+			LogFormatStr::placeholders("Sending {ids:?} for {user}, {{literal}} {ids}").unwrap(),
+			vec!["ids".to_string(), "user".to_string()]
+		);
+		for invalid in ["{}", "{0}", "{a.b}", "{1a}", "{r#type}", "{self}", "{type}", "{_}", "{open", "close}"] {
+			assert!(LogFormatStr::placeholders(invalid).is_err(), "{invalid}");
+		}
+	}
+	
+	#[test] fn invalid_placeholders_are_spanned_errors() {
+		for invalid in [r#"info = "{a.b}""#, r#"info = "{1a}""#, r#"info = "{r#type}""#, r#"error = "{self}""#] {
+			let err = syn::parse_str::<Log>(invalid).err().unwrap_or_else(|| panic!("{invalid}"));
+			assert!(err.to_string().starts_with("Attribute::Log: "), "{err}");
+		}
+	}
+	
+	#[test] fn log_methods() {
+		let request = generate(r#"
+			#[log(info = "Sending {ids:?} for {user}", error = "Failed to send {ids:?}: {error}")]
+			struct Request {
+				ids: Vec<u64>,
+				user: String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			fn log_info(&self) {
+				::log::info!("Sending {ids:?} for {user}", ids = self.ids, user = self.user);
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(
+			fn log_error(&self, error: &dyn ::core::fmt::Display) {
+				::log::error!("Failed to send {ids:?}: {error}", ids = self.ids, error = error);
+			}
+		).to_string()));
+	}
+	
+	#[test] fn log_backends() {
+		let tracing = generate(r#"
+			#[log(backend = "tracing", warn = "Retrying {id}")]
+			struct Request {
+				id: u64,
+			}
+		"#);
+		assert!(tracing.contains(&quote!(::tracing::warn!("Retrying {id}", id = self.id);).to_string()));
+		
+		let slog = generate(r#"
+			#[log(debug = "Received {id}", backend = "slog")]
+			struct Response {
+				id: u64,
+			}
+		"#);
+		assert!(slog.contains(&quote!(
+			fn log_debug(&self, logger: &::slog::Logger) {
+				::slog::debug!(logger, "Received {id}", id = self.id);
+			}
+		).to_string()));
+	}
+}
@@ -1,6 +1,6 @@
 mod validate;
 mod log;
 
-pub use validate::{ValidateAction, ValidateChain};
+pub use validate::{ValidateAction, ValidateBackend, ValidateChain};
 pub use log::*;
 
@@ -1,14 +1,14 @@
-use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
-use std::str::FromStr;
 use displaydoc::Display;
-use syn::{LitInt, LitStr, parenthesized, Token};
+use syn::{LitInt, LitStr, parenthesized, Token, Type};
 use syn::parse::{Parse, ParseStream};
-use crate::parsers::tools::{Lookahead, SynExtent};
 use proc_macro2::Ident;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 use syn::spanned::Spanned;
-use crate::attributes::{Attrs, ParamAttr, TypeAttr};
+use crate::attributes::{ParamAttr, TypeAttr};
+use crate::parsers::tools::is_string_type;
 use crate::rest_api::SynError;
 
 /// Identifiers for Parameter-only Validate Attributes
@@ -16,7 +16,14 @@ use crate::rest_api::SynError;
 ///   - Required
 ///   - Regex
 ///   - Email
+///   - Url
 ///   - Range
+///   - Length
+///   - OneOf
+///   - MinItems
+///   - MaxItems
+///   - Unique
+///   - Nested
 ///   - Custom
 #[derive(Clone, Display)]
 pub enum ParameterValidate {
@@ -26,8 +33,22 @@ pub enum ParameterValidate {
 	Regex,
 	/// email
 	Email,
+	/// url
+	Url,
 	/// range
 	Range,
+	/// length
+	Length,
+	/// one_of
+	OneOf,
+	/// min_items
+	MinItems,
+	/// max_items
+	MaxItems,
+	/// unique
+	Unique,
+	/// nested
+	Nested,
 	/// custom
 	Custom,
 }
@@ -35,21 +56,137 @@ impl TryFrom<Ident> for ParameterValidate {
 	type Error = syn::Error;
 	fn try_from(ident: Ident) -> Result<Self, Self::Error> {
 		let ident = ident.to_string();
-		println!("VALIDATE: {ident}");
+		log::trace!("ParameterValidate - {ident}");
 		match ident.as_str() {
 			"required" => Ok(ParameterValidate::Required),
 			"regex"    => Ok(ParameterValidate::Regex),
 			"email"    => Ok(ParameterValidate::Email),
+			"url"      => Ok(ParameterValidate::Url),
 			"range"    => Ok(ParameterValidate::Range),
+			"length"   => Ok(ParameterValidate::Length),
+			"one_of"   => Ok(ParameterValidate::OneOf),
+			"min_items" => Ok(ParameterValidate::MinItems),
+			"max_items" => Ok(ParameterValidate::MaxItems),
+			"unique"   => Ok(ParameterValidate::Unique),
+			"nested"   => Ok(ParameterValidate::Nested),
 			"custom"   => Ok(ParameterValidate::Custom),
 			unknown    => Err(SynError::new(
 				unknown.span(),
-				&format!("ValidateAttribute Contained an Unknown Identifier: \"{}\"", unknown)
+				format!("ValidateAttribute Contained an Unknown Identifier: \"{}\"", unknown)
 			)),
 		}
 	}
 }
 
+/// Identifiers for Type-only Validate Attributes
+/// # Enumerations:
+///   - RequiresTogether
+///   - MutuallyExclusive
+///   - Custom
+///   - Backend
+#[derive(Clone, Display)]
+pub enum TypeValidate {
+	/// requires_together
+	RequiresTogether,
+	/// mutually_exclusive
+	MutuallyExclusive,
+	/// custom
+	Custom,
+	/// backend
+	Backend,
+}
+impl TryFrom<Ident> for TypeValidate {
+	type Error = syn::Error;
+	fn try_from(ident: Ident) -> Result<Self, Self::Error> {
+		return match ident.to_string().as_str() {
+			"requires_together"  => Ok(TypeValidate::RequiresTogether),
+			"mutually_exclusive" => Ok(TypeValidate::MutuallyExclusive),
+			"custom"             => Ok(TypeValidate::Custom),
+			"backend"            => Ok(TypeValidate::Backend),
+			unknown              => Err(SynError::new(
+				ident.span(),
+				format!("ValidateAttribute Contained an Unknown Type-level Identifier: \"{}\"", unknown)
+			)),
+		}
+	}
+}
+
+/// # ValidateBackend
+/// The runtime a Type's validation is delegated to, declared at the Type-level.
+///   - ``` #[validate(backend = "restify")] ```: The default. Generates a bespoke
+///     ``` validate() ``` method, see [crate::generators::validate::gen_validate]
+///   - ``` #[validate(backend = "validator")] ```: Derives ``` ::validator::Validate ```,
+///     and translates every Validate Action into its **validator** field attribute.
+///     **one_of**, **unique**, **requires_together** and **mutually_exclusive** have no
+///     equivalent, and custom functions must follow **validator**'s signature.
+///   - ``` #[validate(backend = "garde")] ```: Derives ``` ::garde::Validate ```, and translates
+///     every Validate Action into its **garde** field attribute. Fields without any action
+///     are marked ``` #[garde(skip)] ```, as garde requires. **one_of**, **unique** and every
+///     type-level rule have no equivalent, and custom functions must follow **garde**'s signature.
+#[derive(Clone, Debug, Default, Display, PartialEq)]
+pub enum ValidateBackend {
+	/// restify
+	#[default]
+	Restify,
+	/// validator
+	Validator,
+	/// garde
+	Garde,
+}
+impl Parse for ValidateBackend {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		input.parse::<Token![=]>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"Validate::Backend: backend and the backend's name must be separated by the '=' token"
+			))?;
+		let backend = input.parse::<LitStr>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"Validate::Backend: The backend's name should be a literal string."
+			))?;
+		return match backend.value().as_str() {
+			"restify"   => Ok(ValidateBackend::Restify),
+			"validator" => Ok(ValidateBackend::Validator),
+			"garde"     => Ok(ValidateBackend::Garde),
+			unknown     => Err(SynError::new(
+				backend.span(),
+				format!("Validate::Backend: Found an unknown backend: \"{unknown}\". (restify|validator|garde)")
+			)),
+		}
+	}
+}
+impl ValidateBackend {
+	/// Returns the backend declared by a Type's ``` #[validate(..)] ``` actions.
+	pub fn from_actions<'a>(actions: impl IntoIterator<Item = &'a ValidateAction<TypeAttr>>) -> Self {
+		return actions.into_iter()
+			.find_map(|action| match action {
+				ValidateAction::Backend(backend) => Some(backend.clone()),
+				_ => None,
+			})
+			.unwrap_or_default();
+	}
+	
+	/// Quotes the derive the backend's validation is generated with.
+	pub fn quote_derive(&self) -> TokenStream2 {
+		return match self {
+			ValidateBackend::Restify => quote!(),
+			ValidateBackend::Validator => quote!( #[derive(::validator::Validate)] ),
+			ValidateBackend::Garde => quote!( #[derive(::garde::Validate)] ),
+		}
+	}
+	
+	/// Quotes a field attribute holding every backend argument of a single field.
+	pub fn quote_field_attr(&self, args: &[TokenStream2]) -> TokenStream2 {
+		return match (self, args.is_empty()) {
+			(ValidateBackend::Restify, _) | (ValidateBackend::Validator, true) => quote!(),
+			(ValidateBackend::Validator, false) => quote!( #[validate( #( #args ),* )] ),
+			(ValidateBackend::Garde, true) => quote!( #[garde(skip)] ),
+			(ValidateBackend::Garde, false) => quote!( #[garde( #( #args ),* )] ),
+		}
+	}
+}
+
 /// # ValidateAction
 /// This enum holds all the possible Validate Actions within Restify.
 /// Centralized to make refactoring easier. ValidateAction takes in a
@@ -62,88 +199,196 @@ impl TryFrom<Ident> for ParameterValidate {
 pub enum ValidateAction<Kind> {
 	Required,
 	Email,
+	Url,
 	Range{
 		min: Option<LitInt>,
 		max: Option<LitInt>,
 	},
+	Length{
+		min: Option<LitInt>,
+		max: Option<LitInt>,
+	},
+	OneOf(Vec<LitStr>),
+	MinItems(LitInt),
+	MaxItems(LitInt),
+	Unique,
+	Nested,
 	Regex(LitStr),
 	Custom(LitStr),
+	RequiresTogether(Vec<Ident>),
+	MutuallyExclusive(Vec<Ident>),
+	Backend(ValidateBackend),
 	
 	_Kind_(PhantomData<Kind>),
 }
+/// Parses the parenthesized ``` (min: 1, max: 10) ``` bounds shared by the
+/// **range** and **length** Validate Actions. Either bound may be omitted, but
+/// **min** must come first.
+fn parse_bounds(
+	input  : ParseStream,
+	action : &str,
+) -> syn::Result<(Option<LitInt>, Option<LitInt>)> {
+	let parse_bound = |content: ParseStream| -> syn::Result<LitInt> {
+		content.parse::<Token![:]>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				format!("Validate::{action}: Literals must be proceeded by a ':' token")
+			))?;
+		content.parse::<LitInt>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				format!("Validate::{action}: Commands must be an Integer")
+			))
+	};
+	let content;
+	parenthesized!(content in input);
+	
+	let mut min = None;
+	let mut max = None;
+	let mut ident_check = content.parse::<Ident>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			format!("Validate::{action}: Must start with an identifier. (min|max)")
+		))?;
+	let mut ident_str = ident_check.to_string();
+	
+	if ident_str.as_str() != "min" && ident_str.as_str() != "max" {
+		return Err(SynError::new(
+			ident_check.span(),
+			format!("Validate::{action}: Unknown identifier found: \"{ident_str}\"")
+		));
+	}
+	
+	if ident_str.as_str() == "min" {
+		min = Some(parse_bound(&content)?);
+		if content.is_empty() {
+			return Ok((min, max));
+		}
+		
+		content.parse::<Token![,]>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				format!("Validate::{action}: Min and Max commands should be seperated by a comma")
+			))?;
+		ident_check = content.parse::<Ident>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				format!("Validate::{action}: max command must be an Identifier")
+			))?;
+		ident_str = ident_check.to_string();
+	}
+	
+	if ident_str.as_str() != "max" {
+		return Err(SynError::new(
+			ident_check.span(),
+			format!("Validate::{action}: Unknown identifier found: \"{ident_str}\"")
+		));
+	}
+	max = Some(parse_bound(&content)?);
+	if !content.is_empty() {
+		return Err(SynError::new(
+			content.span(),
+			format!("Validate::{action}: Max command should be the last command included in {action}. ")
+		));
+	}
+	return Ok((min, max));
+}
+
+/// Parses the ``` = 10 ``` item count of the **min_items** and **max_items** Validate Actions.
+fn parse_item_count(input: ParseStream, action: &str) -> syn::Result<LitInt> {
+	input.parse::<Token![=]>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			format!("Validate::{action}: Identifier should be followed by an '=' token")
+		))?;
+	return input.parse::<LitInt>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			format!("Validate::{action}: Command should be an Integer")
+		));
+}
+
+/// Parses the ``` = "path::to::fn" ``` of the **custom** Validate Action.
+fn parse_custom(input: ParseStream) -> syn::Result<LitStr> {
+	input.parse::<Token![=]>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			"Validate::Custom: Identifier should be followed by an '=' token"
+		))?;
+	let custom = input.parse::<LitStr>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			"Validate::Custom: Command should be a literal string."
+		))?;
+	custom.parse::<syn::Path>()
+		.map_err(|_| SynError::new(
+			custom.span(),
+			"Validate::Custom: Command should be the path to a function, i.e., \"my_mod::validate_id\""
+		))?;
+	return Ok(custom);
+}
+
+/// Parses the parenthesized field list of a type-level Validate Action,
+/// i.e., ``` requires_together(start, end) ```. Requires at least two fields.
+fn parse_field_list(input: ParseStream, action: &str) -> syn::Result<Vec<Ident>> {
+	let content;
+	parenthesized!(content in input);
+	let fields: Vec<Ident> = content.parse_terminated(Ident::parse, Token![,])
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			format!("Validate::{action}: Fields should be comma-delimited identifiers")
+		))?
+		.into_iter()
+		.collect();
+	if fields.len() < 2 {
+		return Err(SynError::new(
+			content.span(),
+			format!("Validate::{action}: Requires at least two fields")
+		));
+	}
+	return Ok(fields);
+}
+
 impl Parse for ValidateAction<ParamAttr> {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		return match ParameterValidate::try_from(input.parse::<Ident>()?)? {
 			ParameterValidate::Required => Ok(ValidateAction::Required),
 			ParameterValidate::Email => Ok(ValidateAction::Email),
+			ParameterValidate::Url => Ok(ValidateAction::Url),
 			ParameterValidate::Range => {
-				let parse_range_cmd = |content: ParseStream| -> syn::Result<LitInt> {
-					content.parse::<Token![:]>()
-						.map_err(|syn| SynError::new(
-							syn.span(),
-							"Validate::Range: Literals must be proceeded by a ':' token"
-						))?;
-					content.parse::<LitInt>()
-						.map_err(|syn| SynError::new(
-							syn.span(),
-							"Validate::Range: Commands must be an Integer"
-						))
-				};
+				let (min, max) = parse_bounds(input, "Range")?;
+				return Ok(ValidateAction::Range{ min, max });
+			},
+			ParameterValidate::Length => {
+				let (min, max) = parse_bounds(input, "Length")?;
+				return Ok(ValidateAction::Length{ min, max });
+			},
+			ParameterValidate::OneOf => {
 				let content;
 				parenthesized!(content in input);
-				
-				let mut min = None;
-				let mut max = None;
-				let mut ident_check = content.parse::<Ident>()
+				let values: Vec<LitStr> = content.parse_terminated(|c| c.parse::<LitStr>(), Token![,])
 					.map_err(|syn| SynError::new(
 						syn.span(),
-						"Validate::Range: Must start with an identifier. (min|max)"
-					))?;
-				let mut ident_str = ident_check.to_string();
-				
-				if ident_str.as_str() != "min" && ident_str.as_str() != "max" {
-					return Err(SynError::new(
-						ident_str.span(),
-						&format!("Validate::Range: Unknown identifier found: \"{ident_str}\"")
-					));
-				}
-				
-				if ident_str.as_str() == "min" {
-					min = Some(parse_range_cmd(&content)?);
-					if content.is_empty() {
-						return Ok(ValidateAction::Range{ min, max, })
-					}
-					
-					content.parse::<Token![,]>()
-						.map_err(|syn| SynError::new(
-							syn.span(),
-							"Validate::Range: Min and Max commands should be seperated by a comma"
-						))?;
-				}
-				if min.is_some() {
-					ident_check = content.parse::<Ident>()
-						.map_err(|syn| SynError::new(
-							syn.span(),
-							"Validate::Range: max command must be an Identifier"
-						))?;
-					ident_str = ident_check.to_string();
-				}
-				
-				if ident_str.as_str() != "max" {
-					return Err(SynError::new(
-						ident_str.span(),
-						&format!("Validate::Range: Unknown identifier found: \"{ident_str}\"")
-					));
-				}
-				max = Some(parse_range_cmd(&content)?);
-				if !content.is_empty() {
+						"Validate::OneOf: Permitted values should be comma-delimited string literals"
+					))?
+					.into_iter()
+					.collect();
+				if values.is_empty() {
 					return Err(SynError::new(
 						content.span(),
-						"Validate::Range: Max command should be the last command included in Range. "
+						"Validate::OneOf: Requires at least one permitted value"
 					));
 				}
-				return Ok(ValidateAction::Range{ min, max });
+				return Ok(ValidateAction::OneOf(values));
 			},
+			ParameterValidate::MinItems => {
+				return Ok(ValidateAction::MinItems(parse_item_count(input, "MinItems")?));
+			},
+			ParameterValidate::MaxItems => {
+				return Ok(ValidateAction::MaxItems(parse_item_count(input, "MaxItems")?));
+			},
+			ParameterValidate::Unique => Ok(ValidateAction::Unique),
+			ParameterValidate::Nested => Ok(ValidateAction::Nested),
 			ParameterValidate::Regex => {
 				input.parse::<Token![=]>()
 					.map_err(|syn| SynError::new(
@@ -155,27 +400,317 @@ impl Parse for ValidateAction<ParamAttr> {
 						syn.span(),
 						"Validate::Regex: Command should be a literal string."
 					))?;
+				if let Err(err) = regex::Regex::new(&regex.value()) {
+					return Err(SynError::new(
+						regex.span(),
+						format!("Validate::Regex: Invalid regular expression: {err}")
+					));
+				}
 				return Ok(ValidateAction::Regex(regex));
 			},
-			ParameterValidate::Custom => {
-				input.parse::<Token![=]>()
-					.map_err(|syn| SynError::new(
-						syn.span(),
-						"Validate::Custom: Identifier should be followed by an '=' token"
-					))?;
-				let custom = input.parse::<LitStr>()
-					.map_err(|syn| SynError::new(
-						syn.span(),
-						"Validate::Custom: Command should be a literal string."
-					))?;
-				return Ok(ValidateAction::Custom(custom));
+			ParameterValidate::Custom => Ok(ValidateAction::Custom(parse_custom(input)?)),
+		}
+	}
+}
+impl ValidateAction<ParamAttr> {
+	pub fn is_required(&self) -> bool {
+		return matches!(self, ValidateAction::Required);
+	}
+	
+	/// Returns true if this Validate Action can be delegated to the provided backend.
+	pub fn supported_by(&self, backend: &ValidateBackend) -> bool {
+		return match backend {
+			ValidateBackend::Restify => true,
+			ValidateBackend::Validator
+			| ValidateBackend::Garde => !matches!(self, ValidateAction::OneOf(_) | ValidateAction::Unique),
+		}
+	}
+	
+	/// Quotes this Validate Action as an argument of the backend's field attribute, i.e.,
+	/// ``` length(min = 1) ``` for ``` #[validate(length(min = 1))] ```.
+	/// **regex** refers to the static the pattern was compiled into, see
+	/// [crate::parsers::struct_parameter::StructParameter::quote_backend_regexes]
+	pub fn quote_backend_arg(&self, backend: &ValidateBackend, regex: &Ident) -> TokenStream2 {
+		if *backend == ValidateBackend::Garde {
+			return self.quote_garde_arg();
+		}
+		if *backend != ValidateBackend::Validator {
+			return quote!();
+		}
+		let bounds = |min: &Option<LitInt>, max: &Option<LitInt>| {
+			let min = min.as_ref().map(|min| quote!(min = #min));
+			let max = max.as_ref().map(|max| quote!(max = #max));
+			min.into_iter().chain(max).collect::<Vec<_>>()
+		};
+		return match self {
+			ValidateAction::Required => quote!(required),
+			ValidateAction::Email => quote!(email),
+			ValidateAction::Url => quote!(url),
+			ValidateAction::Range { min, max } => {
+				let bounds = bounds(min, max);
+				quote!( range(#( #bounds ),*) )
+			},
+			ValidateAction::Length { min, max } => {
+				let bounds = bounds(min, max);
+				quote!( length(#( #bounds ),*) )
+			},
+			ValidateAction::MinItems(min) => quote!( length(min = #min) ),
+			ValidateAction::MaxItems(max) => quote!( length(max = #max) ),
+			ValidateAction::Nested => quote!(nested),
+			ValidateAction::Regex(_) => quote!( regex(path = *#regex) ),
+			ValidateAction::Custom(custom) => quote!( custom(function = #custom) ),
+			_ => quote!(),
+		}
+	}
+	
+	/// Quotes this Validate Action as an argument of garde's field attribute.
+	/// Unlike **validator**, garde takes regex patterns and custom functions directly.
+	fn quote_garde_arg(&self) -> TokenStream2 {
+		let bounds = |min: &Option<LitInt>, max: &Option<LitInt>| {
+			let min = min.as_ref().map(|min| quote!(min = #min));
+			let max = max.as_ref().map(|max| quote!(max = #max));
+			min.into_iter().chain(max).collect::<Vec<_>>()
+		};
+		return match self {
+			ValidateAction::Required => quote!(required),
+			ValidateAction::Email => quote!(email),
+			ValidateAction::Url => quote!(url),
+			ValidateAction::Range { min, max } => {
+				let bounds = bounds(min, max);
+				quote!( range(#( #bounds ),*) )
+			},
+			ValidateAction::Length { min, max } => {
+				let bounds = bounds(min, max);
+				quote!( length(#( #bounds ),*) )
+			},
+			ValidateAction::MinItems(min) => quote!( length(min = #min) ),
+			ValidateAction::MaxItems(max) => quote!( length(max = #max) ),
+			ValidateAction::Nested => quote!(dive),
+			ValidateAction::Regex(regex) => quote!( pattern(#regex) ),
+			ValidateAction::Custom(custom) => {
+				let custom = custom.parse::<syn::Path>().expect("Validated while parsing");
+				quote!( custom(#custom) )
+			},
+			_ => quote!(),
+		}
+	}
+	
+	/// # ValidateAction: Generate Check
+	/// Quotes the check for a single field-level Validate Action. The generated check
+	/// expects the field's value to be bound to ``` value ```, as a reference, and pushes
+	/// any failure onto ``` errors ```. See [crate::generators::validate::gen_validate]
+	///   - **required**: Handled by the caller, since only optional fields can be missing.
+	///   - **email**: A lightweight ``` local@domain.tld ``` check.
+	///   - **url**: A lightweight ``` scheme://host ``` check, so ``` url ``` isn't a required dependency.
+	///   - **range(min, max)**: Inclusive bounds.
+	///   - **length(min, max)**: Inclusive bounds on a string's char count, or on any other
+	///     **ty**'s ``` value.len() ```, i.e., a Vec's item count.
+	///   - **one_of("a", "b")**: Membership check, listing every permitted value on failure.
+	///   - **min_items = n**, **max_items = n**: Inclusive bounds on a collection's item count.
+	///   - **unique**: No two items are equal. Only requires ``` PartialEq ``` of the items.
+	///   - **nested**: Calls the field type's own generated ``` validate() ```, reporting each
+	///     nested failure under this field, i.e., ``` address: zip: must be at least 5 ```
+	///   - **regex**: Compiled once, on first use.
+	///   - **custom**: Calls ``` fn(&T) -> Result<(), impl Into<String>> ```
+	pub fn quote_check(&self, field: &str, ty: &Type) -> TokenStream2 {
+		return match self {
+			ValidateAction::Required
+			| ValidateAction::RequiresTogether(_)
+			| ValidateAction::MutuallyExclusive(_)
+			| ValidateAction::Backend(_)
+			| ValidateAction::_Kind_(_) => quote!(),
+			ValidateAction::Email => quote! {
+				let valid = match ::core::convert::AsRef::<str>::as_ref(value).split_once('@') {
+					::core::option::Option::Some((local, domain)) => !local.is_empty()
+						&& !domain.contains('@')
+						&& domain.contains('.')
+						&& !domain.starts_with('.')
+						&& !domain.ends_with('.'),
+					::core::option::Option::None => false,
+				};
+				if !valid {
+					errors.push(#field, "must be a valid email address");
+				}
+			},
+			ValidateAction::Url => quote! {
+				let valid = match ::core::convert::AsRef::<str>::as_ref(value).split_once("://") {
+					::core::option::Option::Some((scheme, rest)) => scheme
+						.chars()
+						.next()
+						.map_or(false, |c| c.is_ascii_alphabetic())
+						&& scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+						&& rest.split(|c| c == '/' || c == '?' || c == '#')
+							.next()
+							.map_or(false, |host| !host.is_empty() && !host.contains(char::is_whitespace)),
+					::core::option::Option::None => false,
+				};
+				if !valid {
+					errors.push(#field, "must be a valid URL");
+				}
+			},
+			ValidateAction::Range { min, max } => {
+				let min = min.as_ref().map(|min| quote! {
+					if *value < #min {
+						errors.push(#field, ::std::format!("must be at least {}", #min));
+					}
+				});
+				let max = max.as_ref().map(|max| quote! {
+					if *value > #max {
+						errors.push(#field, ::std::format!("must be at most {}", #max));
+					}
+				});
+				quote!( #min #max )
+			},
+			ValidateAction::Length { min, max } => {
+				// A string's byte length isn't its length, i.e., "ééé" is 6 bytes long.
+				let length = match is_string_type(ty) {
+					true  => quote!( value.chars().count() ),
+					false => quote!( value.len() ),
+				};
+				let min = min.as_ref().map(|min| quote! {
+					if #length < #min {
+						errors.push(#field, ::std::format!("must have a length of at least {}", #min));
+					}
+				});
+				let max = max.as_ref().map(|max| quote! {
+					if #length > #max {
+						errors.push(#field, ::std::format!("must have a length of at most {}", #max));
+					}
+				});
+				quote!( #min #max )
+			},
+			ValidateAction::OneOf(values) => {
+				let permitted = values.iter()
+					.map(|v| format!("\"{}\"", v.value()))
+					.collect::<Vec<_>>()
+					.join(", ");
+				let message = format!("must be one of: {permitted}");
+				quote! {
+					if ![#(#values),*].contains(&::core::convert::AsRef::<str>::as_ref(value)) {
+						errors.push(#field, #message);
+					}
+				}
+			},
+			ValidateAction::MinItems(min) => quote! {
+				if value.len() < #min {
+					errors.push(#field, ::std::format!("must contain at least {} items", #min));
+				}
+			},
+			ValidateAction::MaxItems(max) => quote! {
+				if value.len() > #max {
+					errors.push(#field, ::std::format!("must contain at most {} items", #max));
+				}
+			},
+			ValidateAction::Unique => quote! {
+				let duplicate = value.iter()
+					.enumerate()
+					.any(|(i, item)| value.iter().skip(i + 1).any(|other| item == other));
+				if duplicate {
+					errors.push(#field, "must not contain duplicate items");
+				}
+			},
+			ValidateAction::Nested => quote! {
+				if let ::core::result::Result::Err(nested) = value.validate() {
+					for error in nested.iter() {
+						errors.push(#field, ::std::format!("{}: {}", error.field, error.message));
+					}
+				}
+			},
+			ValidateAction::Regex(regex) => quote! {
+				let regex = {
+					static REGEX: ::std::sync::OnceLock<::regex::Regex> = ::std::sync::OnceLock::new();
+					REGEX.get_or_init(|| ::regex::Regex::new(#regex).unwrap())
+				};
+				if !regex.is_match(::core::convert::AsRef::<str>::as_ref(value)) {
+					errors.push(#field, ::std::format!("must match the pattern \"{}\"", #regex));
+				}
+			},
+			ValidateAction::Custom(custom) => {
+				let custom = custom.parse::<syn::Path>().expect("Validated while parsing");
+				quote! {
+					if let ::core::result::Result::Err(message) = #custom(value) {
+						errors.push(#field, message);
+					}
+				}
 			},
 		}
 	}
 }
 impl Parse for ValidateAction<TypeAttr> {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		todo!()
+		return match TypeValidate::try_from(input.parse::<Ident>()?)? {
+			TypeValidate::RequiresTogether => Ok(ValidateAction::RequiresTogether(
+				parse_field_list(input, "RequiresTogether")?
+			)),
+			TypeValidate::MutuallyExclusive => Ok(ValidateAction::MutuallyExclusive(
+				parse_field_list(input, "MutuallyExclusive")?
+			)),
+			TypeValidate::Custom => Ok(ValidateAction::Custom(parse_custom(input)?)),
+			TypeValidate::Backend => Ok(ValidateAction::Backend(input.parse()?)),
+		}
+	}
+}
+impl ValidateAction<TypeAttr> {
+	/// Returns the fields this type-level Validate Action refers to.
+	pub fn fields(&self) -> &[Ident] {
+		return match self {
+			ValidateAction::RequiresTogether(fields)
+			| ValidateAction::MutuallyExclusive(fields) => fields.as_slice(),
+			_ => &[],
+		}
+	}
+	
+	/// # ValidateAction: Generate Type Check
+	/// Quotes the check for a single type-level Validate Action. Unlike field-level checks,
+	/// these run against ``` self ```, and report their failures under the listed fields.
+	///   - **requires_together(a, b)**: Either every listed optional field is set, or none are.
+	///   - **mutually_exclusive(a, b)**: At most one of the listed optional fields is set.
+	///   - **custom**: Calls ``` fn(&Self) -> Result<(), impl Into<String>> ```
+	pub fn quote_type_check(&self, name: &Ident) -> TokenStream2 {
+		let label = fmt_fields(self.fields());
+		let fields = self.fields();
+		return match self {
+			ValidateAction::RequiresTogether(_) => quote! {
+				let set = [#( self.#fields.is_some() ),*];
+				if set.contains(&true) && set.contains(&false) {
+					errors.push(#label, "must either all be set, or all be unset");
+				}
+			},
+			ValidateAction::MutuallyExclusive(_) => quote! {
+				let set = [#( self.#fields.is_some() ),*];
+				if set.iter().filter(|set| **set).count() > 1 {
+					errors.push(#label, "at most one may be set");
+				}
+			},
+			ValidateAction::Custom(custom) => {
+				let custom = custom.parse::<syn::Path>().expect("Validated while parsing");
+				let name = name.to_string();
+				quote! {
+					if let ::core::result::Result::Err(message) = #custom(self) {
+						errors.push(#name, message);
+					}
+				}
+			},
+			_ => quote!(),
+		}
+	}
+	
+	/// Returns true if this type-level Validate Action can be delegated to the provided backend.
+	pub fn supported_by(&self, backend: &ValidateBackend) -> bool {
+		return match backend {
+			ValidateBackend::Restify => true,
+			ValidateBackend::Validator => matches!(self, ValidateAction::Custom(_) | ValidateAction::Backend(_)),
+			ValidateBackend::Garde => matches!(self, ValidateAction::Backend(_)),
+		}
+	}
+	
+	/// Quotes the Type attribute this Validate Action is delegated to a backend with.
+	pub fn quote_backend_attr(&self, backend: &ValidateBackend) -> TokenStream2 {
+		return match (backend, self) {
+			(ValidateBackend::Validator, ValidateAction::Custom(custom))
+				=> quote!( #[validate(schema(function = #custom))] ),
+			_ => quote!(),
+		}
 	}
 }
 
@@ -189,7 +724,7 @@ impl<Kind> ValidateChain<Kind> where ValidateAction<Kind>: Parse
 fn parse_chain(input: ParseStream) -> syn::Result<Self> {
 		let mut actions = vec![];
 		loop {
-			match ValidateAction::parse(&input) {
+			match ValidateAction::parse(input) {
 				Ok(action) => actions.push(action),
 				Err(syn) => return Err(syn),
 			}
@@ -211,33 +746,64 @@ fn parse_chain(input: ParseStream) -> syn::Result<Self> {
 }
 impl Parse for ValidateChain<TypeAttr>{
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		return ValidateChain::parse_chain(&input);
+		return ValidateChain::parse_chain(input);
 	}
 }
 impl Parse for ValidateChain<ParamAttr>{
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		return ValidateChain::parse_chain(&input);
+		return ValidateChain::parse_chain(input);
 	}
 }
 
-impl Debug for ValidateAction<ParamAttr> {
+fn fmt_bounds(min: &Option<LitInt>, max: &Option<LitInt>) -> String {
+	return match (min, max) {
+		(Some(min), Some(max)) => format!("min: {}, max: {}", min, max),
+		(Some(min), None) => format!("min: {}", min),
+		(None, Some(max)) => format!("max: {}", max),
+		_ => unreachable!("Should not happen")
+	}
+}
+
+fn fmt_fields(fields: &[Ident]) -> String {
+	return fields.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ");
+}
+
+impl<Kind> Debug for ValidateAction<Kind> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
 			ValidateAction::Required
 				=> write!(f, "required"),
 			ValidateAction::Email
 				=> write!(f, "email"),
+			ValidateAction::Url
+				=> write!(f, "url"),
 			ValidateAction::Range { min, max }
-				=> write!(f, "range({})", match (min, max) {
-				(Some(min), Some(max)) => format!("min: {}, max: {}", min.to_string(), max.to_string()),
-				(Some(min), None) => format!("min: {}", min.to_string()),
-				(None, Some(max)) => format!("max: {}", max.to_string()),
-				_ => unreachable!("Should not happen")
-			}),
+				=> write!(f, "range({})", fmt_bounds(min, max)),
+			ValidateAction::Length { min, max }
+				=> write!(f, "length({})", fmt_bounds(min, max)),
+			ValidateAction::OneOf(values)
+				=> write!(f, "one_of({})", values.iter()
+					.map(|v| format!("\"{}\"", v.value()))
+					.collect::<Vec<_>>()
+					.join(", ")),
+			ValidateAction::MinItems(min)
+				=> write!(f, "min_items = {}", min),
+			ValidateAction::MaxItems(max)
+				=> write!(f, "max_items = {}", max),
+			ValidateAction::Unique
+				=> write!(f, "unique"),
+			ValidateAction::Nested
+				=> write!(f, "nested"),
 			ValidateAction::Regex(reg)
 				=> write!(f, "regex = \"{}\"", reg.value()),
 			ValidateAction::Custom(custom)
 				=> write!(f, "custom = \"{}\"", custom.value()),
+			ValidateAction::RequiresTogether(fields)
+				=> write!(f, "requires_together({})", fmt_fields(fields)),
+			ValidateAction::MutuallyExclusive(fields)
+				=> write!(f, "mutually_exclusive({})", fmt_fields(fields)),
+			ValidateAction::Backend(backend)
+				=> write!(f, "backend = \"{backend}\""),
 			ValidateAction::_Kind_(_)
 				=> write!(f, ""),
 		}
@@ -253,7 +819,7 @@ impl Debug for ValidateChain<ParamAttr> {
 				write!(f, ",")?;
 			}
 		}
-		write!(f, ")\n")
+		writeln!(f, ")")
 	}
 }
 
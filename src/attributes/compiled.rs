@@ -1,8 +1,10 @@
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
-use crate::attributes::{AttrCommands, Attribute, Attrs, AttrSlice, ParamAttr, TypeAttr};
+use crate::attributes::{AttrCommands, Attribute, Attrs, AttrSlice, CommandStage, DeriveOp, Derives, ParamAttr, TypeAttr};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
+use syn::{Path, Token};
+use syn::punctuated::Punctuated;
 use crate::attributes::kinds::AttrKind;
 use crate::generators::tools::RestType;
 
@@ -15,9 +17,13 @@ use crate::generators::tools::RestType;
 ///     with the final generated product.
 ///   * [Vec]<[AttrCommands]> commands: Special Attributes that command the
 ///     Restify Generator with special actions it will need to make.
+///     Commands are kept sorted by their [CommandStage], so iterating over them
+///     always follows Restify's composition order.
+///   * [Vec]<[Derives]> derives: Operations on the generated Type's default derives.
 pub struct CompiledAttrs<A: Attribute> {
 	pub quotes: Vec<TokenStream2>,
 	pub commands: Vec<AttrCommands>,
+	pub derives: Vec<Derives>,
 	_kind: PhantomData<A>
 }
 
@@ -28,12 +34,41 @@ impl<A: Attribute> CompiledAttrs<A> {
 	pub fn commands_ref(&self) -> &[AttrCommands] {
 		self.commands.as_slice()
 	}
+	/// Returns true if any of the compiled commands run within the provided [CommandStage].
+	pub fn has_stage(&self, stage: CommandStage) -> bool {
+		self.commands.iter().any(|cmd| cmd.stage() == stage)
+	}
 }
 impl CompiledAttrs<TypeAttr> {
+	/// Applies every ``` #[derives(..)] ``` operation onto a generator's default derives,
+	/// and quotes the resulting ``` #[derive(..)] ``` attribute.
+	/// Returns an empty TokenStream if every derive was removed.
+	pub fn quote_derives(&self, defaults: Punctuated<Path, Token![,]>) -> TokenStream2 {
+		let mut defaults: Vec<Path> = defaults.into_iter().collect();
+		for derives in self.derives.iter() {
+			derives.apply(&mut defaults);
+		}
+		if defaults.is_empty() {
+			return quote!();
+		}
+		return quote!( #[derive( #( #defaults ),* )] );
+	}
+	
+	/// Removes a default derive, so it can be implemented manually instead.
+	/// Returns true if the derive would have otherwise been derived, i.e., the user
+	/// didn't remove it themselves via ``` #[derives(remove(..))] ```
+	pub fn take_derive(&mut self, derive: Path) -> bool {
+		let mut derives = vec![derive.clone()];
+		for ops in self.derives.iter() {
+			ops.apply(&mut derives);
+		}
+		self.derives.push(Derives { ops: vec![DeriveOp::Remove(vec![derive])] });
+		return !derives.is_empty();
+	}
 }
 impl CompiledAttrs<ParamAttr> {
 	/// Ensures that essential Serde attributes are present in the TokenStream.
-	/// This function checks a given TokenStream for specific Serde attributes (`#[serde(skip_serializing_if="..")]` and `#[serde(default="...")]`). If any are missing, the function inserts default values based on the `rest_type`.
+	/// This function checks the compiled attributes quoted within the TokenStream for specific Serde attributes (`#[serde(skip_serializing_if="..")]` and `#[serde(default="...")]`). If any are missing, the function inserts default values based on the `rest_type`.
 	///
 	/// This functionality is critical for allowing users to manually specify Serde attributes in `restify!` invocations. By default, when a type parameter in `restify!` is marked as optional (e.g., `my_optional: ?MyType`), the appropriate Serde attribute is automatically added unless manually specified.
 	///
@@ -61,11 +96,13 @@ impl CompiledAttrs<ParamAttr> {
 		mut stream: TokenStream2,
 		rest_type: RestType
 	) -> TokenStream2 {
-		let quote_str = stream.to_string();
+		// Only the compiled attributes can carry serde attributes, the field's own
+		// declaration is never stringified.
+		let quote_str = self.quotes.iter().map(ToString::to_string).collect::<String>();
 		if let RestType::Serializable | RestType::Both = rest_type {
 			if !quote_str.contains("skip_serializing_if") {
 				stream = quote! {
-					#[serde(skip_serializing_if="Option::is_none")]
+					#[serde(skip_serializing_if="::core::option::Option::is_none")]
 					#stream
 				};
 			}
@@ -96,19 +133,24 @@ impl<'s, A: Attribute> From<AttrSlice<'s, A>> for CompiledAttrs<A> {
 	fn from(attributes: AttrSlice<'s, A>) -> Self {
 		let (
 			quotes,
-			commands
-		): (Vec<TokenStream2>, Vec<AttrCommands>) = attributes
+			mut commands,
+			derives,
+		): (Vec<TokenStream2>, Vec<AttrCommands>, Vec<Derives>) = attributes
 			.iter()
-			.fold((vec![], vec![]), |(mut quotes, mut commands), attribute| {
+			.fold((vec![], vec![], vec![]), |(mut quotes, mut commands, mut derives), attribute| {
 				match attribute.expand() {
 					AttrKind::Quote(quote)     => quotes.push(quote),
-					AttrKind::Command(command) => commands.push(command)
+					AttrKind::Command(command) => commands.push(command),
+					AttrKind::Derives(ops)     => derives.push(ops),
 				}
-				(quotes, commands)
+				(quotes, commands, derives)
 			});
+		// Stable sort, commands sharing a stage keep their declaration order.
+		commands.sort_by_key(AttrCommands::stage);
 		return CompiledAttrs {
 			quotes,
 			commands,
+			derives,
 			_kind: PhantomData,
 		};
 	}
@@ -117,11 +159,46 @@ impl<'s, A: Attribute> From<AttrSlice<'s, A>> for CompiledAttrs<A> {
 impl<A: Attribute> Debug for CompiledAttrs<A> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		for q in self.quotes.iter() {
-			write!(f, "Quote: \"{:?}\"\n", q.to_string())?;
+			writeln!(f, "Quote: \"{:?}\"", q.to_string())?;
 		}
 		for c in self.commands.iter() {
-			write!(f, "  CMD: \"{}\"\n", c)?;
+			writeln!(f, "  CMD: \"{}\"", c)?;
 		}
 		write!(f, "")
 	}
 }
+
+#[cfg(test)]
+mod compiled_tests {
+	use super::*;
+	
+	fn stages(attrs: &str) -> Vec<CommandStage> {
+		let attrs = syn::parse_str::<Attrs<TypeAttr>>(attrs).unwrap();
+		return attrs.compile()
+			.commands_ref()
+			.iter()
+			.map(AttrCommands::stage)
+			.collect();
+	}
+	
+	#[test] fn composition_order() {
+		let expected = vec![CommandStage::Builder, CommandStage::Log, CommandStage::Async];
+		
+		assert_eq!(stages(r#"#[async] #[log(info="sent")] #[builder]"#), expected);
+		assert_eq!(stages(r#"#[log(info="sent")] #[builder] #[async]"#), expected);
+		assert_eq!(stages(r#"#[builder] #[async] #[log(info="sent")]"#), expected);
+	}
+	
+	#[test] fn quotes_keep_declaration_order() {
+		let attrs = syn::parse_str::<Attrs<TypeAttr>>(
+			r#"#[async] #[rename_all="camelCase"] #[builder] #[remote="other::Remote"]"#
+		).unwrap();
+		let compiled = attrs.compile();
+		
+		assert!(compiled.has_stage(CommandStage::Builder));
+		assert!(!compiled.has_stage(CommandStage::Validate));
+		assert_eq!(compiled.quotes_ref().len(), 2);
+		assert!(compiled.quotes_ref()[0].to_string().contains("rename_all"));
+		assert!(compiled.quotes_ref()[1].to_string().contains("remote"));
+	}
+}
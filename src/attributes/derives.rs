@@ -0,0 +1,136 @@
+use proc_macro2::Ident;
+use syn::{parenthesized, Path, Token};
+use syn::parse::{Parse, ParseStream};
+use crate::rest_api::SynError;
+
+/// # DeriveOp
+/// A single operation performed on the list of derives Restify generates for a Type.
+///   - ``` #[derives(replace(Debug, Clone))] ```: Replaces the default derives entirely.
+///   - ``` #[derives(add(Eq, Hash))] ```: Appends onto the default derives.
+///   - ``` #[derives(remove(Clone))] ```: Removes from the default derives.
+#[derive(Clone)]
+pub enum DeriveOp {
+	Replace(Vec<Path>),
+	Add(Vec<Path>),
+	Remove(Vec<Path>),
+}
+
+/// # Derives
+/// Holds every [DeriveOp] parsed from a ``` #[derives(..)] ``` Type Attribute.
+/// Operations are applied in the order they were declared in, i.e.,
+/// ``` #[derives(replace(Debug), add(Clone))] ``` results in ``` #[derive(Debug, Clone)] ```
+#[derive(Clone)]
+pub struct Derives {
+	pub ops: Vec<DeriveOp>,
+}
+
+impl Derives {
+	/// Parses the parenthesized operations of a ``` #[derives(..)] ``` attribute.
+	pub fn parse_derives(input: ParseStream) -> syn::Result<Self> {
+		let content;
+		parenthesized!(content in input);
+		return content.parse();
+	}
+
+	/// Applies every [DeriveOp] onto a generator's default derives.
+	/// Derives are compared by their last path segment, so ``` remove(Serialize) ```
+	/// also removes ``` serde::Serialize ```.
+	pub fn apply(&self, derives: &mut Vec<Path>) {
+		let name = |path: &Path| path.segments.last().map(|s| s.ident.clone());
+		for op in self.ops.iter() {
+			match op {
+				DeriveOp::Replace(paths) => {
+					*derives = paths.clone();
+				}
+				DeriveOp::Add(paths) => {
+					for path in paths.iter() {
+						if !derives.iter().any(|d| name(d) == name(path)) {
+							derives.push(path.clone());
+						}
+					}
+				}
+				DeriveOp::Remove(paths) => {
+					derives.retain(|d| !paths.iter().any(|p| name(p) == name(d)));
+				}
+			}
+		}
+	}
+}
+
+impl Parse for DeriveOp {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let op = input.parse::<Ident>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"TypeAttribute::Derives - Expected an operation. (replace|add|remove)"
+			))?;
+		let content;
+		parenthesized!(content in input);
+		let paths: Vec<Path> = content.parse_terminated(Path::parse_mod_style, Token![,])
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"TypeAttribute::Derives - Derive paths should be comma-delimited"
+			))?
+			.into_iter()
+			.collect();
+
+		return match op.to_string().as_str() {
+			"replace" => Ok(DeriveOp::Replace(paths)),
+			"add"     => Ok(DeriveOp::Add(paths)),
+			"remove"  => Ok(DeriveOp::Remove(paths)),
+			unknown   => Err(SynError::new(
+				op.span(),
+				format!("TypeAttribute::Derives - Unknown operation found: \"{unknown}\"")
+			)),
+		}
+	}
+}
+
+impl Parse for Derives {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let ops: Vec<DeriveOp> = input.parse_terminated(DeriveOp::parse, Token![,])?
+			.into_iter()
+			.collect();
+		if ops.is_empty() {
+			return Err(SynError::new(
+				input.span(),
+				"TypeAttribute::Derives - Requires at least one operation. (replace|add|remove)"
+			));
+		}
+		return Ok(Derives { ops });
+	}
+}
+
+#[cfg(test)]
+mod derives_tests {
+	use quote::quote;
+	use crate::generators::generate;
+	
+	#[test] fn derive_set_control() {
+		let request = generate(r#"
+			#[derives(add(PartialEq, Eq, Hash), remove(Clone))]
+			struct Request {
+				id: u64,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			#[derive(::core::fmt::Debug, ::serde::Serialize, PartialEq, Eq, Hash)]
+		).to_string()));
+		
+		let response = generate(r#"
+			#[derives(replace(Debug, serde::Deserialize), add(Clone, Debug))]
+			struct Response {
+				id: u64,
+			}
+		"#);
+		assert!(response.contains(&quote!(#[derive(Debug, serde::Deserialize, Clone)]).to_string()));
+		
+		let en = generate(r#"
+			#[derives(remove(Debug, Serialize, Deserialize))]
+			enum Status {
+				Active,
+			}
+		"#);
+		assert!(!en.contains("derive"));
+	}
+}
@@ -1,19 +1,19 @@
 use std::fmt::{Debug, Display, Formatter};
-use std::marker::PhantomData;
 use displaydoc::Display;
 use proc_macro2::{Ident, Span};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{LitStr, parenthesized, Token};
-use syn::parse::{Parse, Parser, ParseStream, Peek};
+use syn::{LitInt, LitStr, parenthesized, Token};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use log::log;
-use crate::attributes::Attribute;
+use crate::attributes::{Attribute, Derives};
 use crate::attributes::command::RunCommand;
 use crate::attributes::commands::{Log, ValidateChain};
-use crate::parse::{RestifyParser, RParsed};
-use crate::parsers::tools::SynExtent;
+use crate::generators::builder::gen_builder;
+use crate::parsers::tools::{Lookahead, SynExtent};
 use crate::rest_api::SynError;
+use crate::utils::{did_you_mean, snake_case, RestMethods};
 
 
 /// # AttrType:
@@ -29,9 +29,12 @@ use crate::rest_api::SynError;
 ///     portion of the final product,
 ///     i.e., TypeAttribute::Builder - A Command that tells Restify to generate
 ///     the Builder Pattern for Type definition it's attached to.
+///   - **AttributeType::Derives**: Wraps ``` #[derives(..)] ``` operations, which are applied
+///     onto the default derives of the generator the Attribute's Type is passed to.
 pub enum AttrKind {
 	Quote(TokenStream2),
 	Command(AttrCommands),
+	Derives(Derives),
 }
 
 /// # AttrCommands:
@@ -52,9 +55,21 @@ pub enum AttrKind {
 ///     - **Builder**: Tells Restify to generate the Builder Pattern for the parent Type.
 ///   -  ``` #[log(info="..")] ```
 ///      - **Log([Log])**:  Tells Restify to generate logging for either the parent
-///      type or parameter.
+///        type or parameter.
 ///   - Validate([ValidateChain]) ``` #[validate(required,..)] ```: Tells Restify to generate specific
 ///     validation checks for the parent type or parameter.
+///
+/// # Composition Order:
+/// Commands are always run in the order of their [CommandStage], never in the order
+/// they were declared in. Commands sharing a stage keep their declaration order.
+///   1. **Validate**: Collected into the Type's ``` validate() ```.
+///   2. **Builder**: Generates the builder, whose ``` build() ``` calls ``` validate() ```
+///      when the Type is validated.
+///   3. **Log**: Generates the Type's log methods, which wrap ``` build() ```'s validation:
+///      ``` warn ``` and ``` error ``` are logged with the errors of an invalid value, then
+///      ``` debug ``` and ``` info ``` once the value is built and valid.
+///      See [Log::quote_build_calls].
+///   4. **Async**: Still [RunCommand::Pending], so it doesn't generate anything yet.
 #[derive(Clone, Display)]
 pub enum AttrCommands {
 	/// Async
@@ -69,27 +84,55 @@ pub enum AttrCommands {
 	ParamValidate(ValidateChain<ParamAttr>),
 }
 
+/// # CommandStage:
+/// The position an [AttrCommands] takes within Restify's composition order.
+/// Earlier stages are generated first, and later stages are allowed to wrap
+/// or reference anything generated by an earlier stage.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, Ord, PartialOrd)]
+pub enum CommandStage {
+	/// Validate
+	Validate,
+	/// Builder
+	Builder,
+	/// Log
+	Log,
+	/// Async
+	Async,
+}
+
 impl AttrCommands {
-	pub fn run_cmd(&self) -> RunCommand{
+	/// Returns the [CommandStage] this command is run in.
+	pub fn stage(&self) -> CommandStage {
+		return match self {
+			AttrCommands::TypeValidate(_)
+			| AttrCommands::ParamValidate(_) => CommandStage::Validate,
+			AttrCommands::Builder            => CommandStage::Builder,
+			AttrCommands::Log(_)             => CommandStage::Log,
+			AttrCommands::Async              => CommandStage::Async,
+		}
+	}
+	pub fn run_cmd(&self) -> RunCommand<'_>{
 		match self {
 			AttrCommands::Builder => RunCommand::Builder(Box::new(
 				|(vis, name, fields)| -> TokenStream2 {
-					let build_methods = fields.quote_builder_fn(vis);
-					quote!(
-						impl #name {
-							#( #build_methods )*
-						}
-					).into()
+					gen_builder(vis, name, fields)
 				}
 			)),
-			AttrCommands::TypeValidate(val)
-			=> todo!(),
-			AttrCommands::ParamValidate(val)
-				=> todo!(),
+			// Collected into the owning type's validate(), see generators::validate
+			AttrCommands::TypeValidate(_)
+			| AttrCommands::ParamValidate(_)
+				=> RunCommand::Collected(self.stage()),
+			//TODO: Implement a method for telling Restify to Make Type methods async. and to use Asynchronous HTTP methods
 			AttrCommands::Async
-			  => todo!("TODO: Implement a method for telling Restify to Make Type methods async. and to use Asynchronous HTTP methods"),
-			AttrCommands::Log(log)
-			  => todo!("Todo: Take Log's internal data, and tell Restify how to incorporate Logging into the generate code")
+				=> RunCommand::Pending(self.stage()),
+			AttrCommands::Log(log) => {
+				let log = log.clone();
+				RunCommand::Log(Box::new(
+					move |(vis, name, fields)| -> TokenStream2 {
+						log.quote_log_methods(vis, name, fields)
+					}
+				))
+			}
 		}
 	}
 }
@@ -97,9 +140,211 @@ impl AttrCommands {
 /// # Endpoint Attributes:
 /// Endpoint Specific: These will be Attributes that will tell Restify how to parse and
 /// generate the Endpoints themselves.
+/// # Attributes:
+///   - **Prefix([LitStr])**: A base path every REST Method URI of the Endpoint is joined to,
+///     i.e., ``` #[prefix = "/api/v2"] ``` turns ``` GET "/user/{id}" ``` into
+///     ``` GET "/api/v2/user/{id}" ```.
 #[derive(Clone)]
 pub enum EndpointAttr {
-	Export(LitStr),
+	Prefix(LitStr),
+}
+impl EndpointAttr {
+	/// The name of every Endpoint Attribute, for suggestions on unknown names.
+	pub const NAMES: [&'static str; 1] = ["prefix"];
+	
+	/// Joins a prefix and a REST Method URI with a single '/' between them.
+	pub fn join_prefix(prefix: &LitStr, uri: &LitStr) -> LitStr {
+		let joined = format!(
+			"{}/{}",
+			prefix.value().trim_end_matches('/'),
+			uri.value().trim_start_matches('/'),
+		);
+		return LitStr::new(&joined, uri.span());
+	}
+}
+impl Attribute for EndpointAttr {
+	fn expand(&self) -> AttrKind {
+		return match self {
+			// Prefix is applied to every REST Method URI while parsing, see Endpoint::parse
+			EndpointAttr::Prefix(_) => AttrKind::Quote(quote!()),
+		}
+	}
+}
+impl Parse for EndpointAttr {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let ident = input.parse::<Ident>()?;
+		return match ident.to_string().as_str() {
+			"prefix" => {
+				let prefix = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitStr>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"EndpointAttribute::Prefix - Expected a string literal, i.e., #[prefix = \"/api/v2\"]"
+					))?;
+				if !prefix.value().starts_with('/') {
+					return Err(SynError::new(
+						prefix.span(),
+						"EndpointAttribute::Prefix - The prefix must start with a '/'"
+					));
+				}
+				return Ok(EndpointAttr::Prefix(prefix));
+			}
+			unknown => Err(SynError::new(
+				ident.span(),
+				format!("EndpointAttribute: Unknown attribute found: \"{unknown}\"{}", did_you_mean(unknown, &EndpointAttr::NAMES))
+			)),
+		}
+	}
+}
+impl Display for EndpointAttr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			EndpointAttr::Prefix(prefix)
+				=> writeln!(f, "<RESTIFY: Prefix = \"{}\">", prefix.value()),
+		}
+	}
+}
+impl Debug for EndpointAttr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self)
+	}
+}
+
+/// # MethodAttr:
+/// Attributes specifically designed for REST Method declarations, i.e., placed
+/// directly above ``` GET "/api/user/{id}" => { .. } ```
+/// # Attributes:
+///   - **Trace**: Tells Restify to generate ``` span() ```, a ``` tracing ``` span carrying the
+///     Endpoint's name, the REST Method and its URI, and ``` record_status(..) ```, which records
+///     the response status onto it. Restify doesn't generate the client call itself, so the
+///     caller runs it within the span.
+///   - **ValidateOnSend**: Tells Restify to generate the REST Method's ``` validate() ```, which
+///     validates every ``` #[validate(..)] ``` component of the REST Method, to run before its
+///     client call performs any network I/O.
+///   - **Timeout([u64])**: The REST Method's request timeout, written as a duration, i.e.,
+///     ``` #[timeout = "30s"] ```. Stored in milliseconds, and generated as ``` TIMEOUT ```.
+///   - **Retry([LitInt])**: How many times a failed call of the REST Method is retried, i.e.,
+///     ``` #[retry = 3] ```. Generated as ``` RETRIES ```.
+///   - **ContentType([LitStr])**: The REST Method's request content type, i.e.,
+///     ``` #[content_type = "application/json"] ```. Generated as ``` CONTENT_TYPE ```.
+///   - **Feature([LitStr])**: Only compiles the REST Method, along with every Type declared
+///     within it, when the crate feature is enabled, i.e., ``` #[feature = "admin"] ```.
+#[derive(Clone)]
+pub enum MethodAttr {
+	Trace,
+	ValidateOnSend,
+	Timeout(u64),
+	Retry(LitInt),
+	ContentType(LitStr),
+	Feature(LitStr),
+}
+impl MethodAttr {
+	/// The name of every Method Attribute, for suggestions on unknown names.
+	pub const NAMES: [&'static str; 6] = ["trace", "validate_on_send", "timeout", "retry", "content_type", "feature"];
+}
+impl Attribute for MethodAttr {
+	fn expand(&self) -> AttrKind {
+		return match self {
+			// Handled by Restify's REST Method generator.
+			MethodAttr::Trace
+			| MethodAttr::ValidateOnSend
+			| MethodAttr::Timeout(_)
+			| MethodAttr::Retry(_)
+			| MethodAttr::ContentType(_)
+			| MethodAttr::Feature(_) => AttrKind::Quote(quote!()),
+		}
+	}
+}
+/// Parses the ``` = value ``` of a Method Attribute.
+fn parse_method_value<T: Parse>(input: ParseStream, attribute: &str, example: &str) -> syn::Result<T> {
+	input.parse::<Token![=]>()
+		.and_then(|_| input.parse::<T>())
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			format!("MethodAttribute::{attribute} - Expected a value, i.e., #[{example}]")
+		))
+}
+/// Parses a duration like ``` "500ms" ```, ``` "30s" ``` or ``` "2m" ``` into milliseconds.
+fn parse_timeout(duration: &LitStr) -> syn::Result<u64> {
+	let value = duration.value();
+	let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+	let (amount, unit) = value.split_at(split);
+	let scale = match unit {
+		"ms" => 1,
+		"s"  => 1_000,
+		"m"  => 60_000,
+		_    => 0,
+	};
+	return match amount.parse::<u64>() {
+		Ok(amount) if scale != 0 && amount != 0 => Ok(amount * scale),
+		_ => Err(SynError::new(
+			duration.span(),
+			"MethodAttribute::Timeout - Expected a non-zero duration, i.e., \"500ms\", \"30s\" or \"2m\""
+		)),
+	};
+}
+impl Parse for MethodAttr {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let ident = input.parse::<Ident>()?;
+		return match ident.to_string().as_str() {
+			"trace" => {
+				if !input.is_empty() {
+					return Err(SynError::new(input.span(), "MethodAttribute::Trace - This attribute doesn't take any arguments."));
+				}
+				return Ok(MethodAttr::Trace);
+			}
+			"validate_on_send" => {
+				if !input.is_empty() {
+					return Err(SynError::new(input.span(), "MethodAttribute::ValidateOnSend - This attribute doesn't take any arguments."));
+				}
+				return Ok(MethodAttr::ValidateOnSend);
+			}
+			"timeout" => {
+				let duration = parse_method_value::<LitStr>(input, "Timeout", "timeout = \"30s\"")?;
+				return Ok(MethodAttr::Timeout(parse_timeout(&duration)?));
+			}
+			"retry" => {
+				let retries = parse_method_value::<LitInt>(input, "Retry", "retry = 3")?;
+				retries.base10_parse::<u32>()?;
+				return Ok(MethodAttr::Retry(retries));
+			}
+			"content_type" => {
+				let content_type = parse_method_value(input, "ContentType", "content_type = \"application/json\"")?;
+				return Ok(MethodAttr::ContentType(content_type));
+			}
+			"feature" => {
+				let feature = parse_method_value(input, "Feature", "feature = \"name\"")?;
+				return Ok(MethodAttr::Feature(feature));
+			}
+			unknown => Err(SynError::new(
+				ident.span(),
+				format!("MethodAttribute: Unknown attribute found: \"{unknown}\"{}", did_you_mean(unknown, &MethodAttr::NAMES))
+			)),
+		}
+	}
+}
+impl Display for MethodAttr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MethodAttr::Trace
+				=> writeln!(f, "<RESTIFY: Trace = TRUE>"),
+			MethodAttr::ValidateOnSend
+				=> writeln!(f, "<RESTIFY: ValidateOnSend = TRUE>"),
+			MethodAttr::Timeout(millis)
+				=> writeln!(f, "<RESTIFY: Timeout = {}ms>", millis),
+			MethodAttr::Retry(retries)
+				=> writeln!(f, "<RESTIFY: Retry = {}>", retries),
+			MethodAttr::ContentType(content_type)
+				=> writeln!(f, "<RESTIFY: ContentType = \"{}\">", content_type.value()),
+			MethodAttr::Feature(feature)
+				=> writeln!(f, "#[cfg(feature = \"{}\")]", feature.value()),
+		}
+	}
+}
+impl Debug for MethodAttr {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self)
+	}
 }
 
 /// # TypeAttr:
@@ -111,6 +356,13 @@ pub enum EndpointAttr {
 ///     implementations as async.
 ///   - **Derive([Vec]<[Ident]>)**: A quotable attribute that will include a '#\[derive(..)]' in the
 ///     generated code.
+///   - **Doc([LitStr])**: A quotable attribute parsed from a ``` /// text ``` doc comment on the
+///     parent type, which is passed through as ``` #[doc = ".."] ``` onto the generated type.
+///   - **Derives([Derives])**: Controls the default derive set Restify generates for the
+///     parent type, via ``` replace(..) ```, ``` add(..) ``` and ``` remove(..) ``` operations.
+///   - **DenyUnknownFields**: A quotable attribute that will include
+///     '#\[serde(deny_unknown_fields)]' for the parent type. Useful for strict contract
+///     testing against the server.
 ///   - Log([Log]): A Command Attribute that tells Restify to include logging functionalities for the
 ///     parent Rust Type/Type Field.
 ///   - **Builder**: A Command Attribute that tells Restify to generate the builder pattern
@@ -123,15 +375,108 @@ pub enum EndpointAttr {
 ///     [More Info]
 ///   - **Validate([ValidateChain<[TypeAttr]>])**: A Command Attribute that tells Restify to include
 ///     special Validation layers in the generated code for the parent type.
+///   - **Tag([LitStr])**: Serde's **tag** attribute. Generates an internally tagged enum, or
+///     adjacently tagged when combined with **Content**.
+///   - **Content([LitStr])**: Serde's **content** attribute. Enum-only, and must be used
+///     together with **Tag**.
+///   - **Untagged**: Serde's **untagged** attribute. Enum-only, and can't be combined with
+///     either **Tag** or **Content**.
+///   - **SkipNone**: Tells Restify to skip serializing every optional field of the parent
+///     Request, Query, Header or ReqRes struct when its value is None, including fields
+///     declared with an explicit ``` Option<T> ``` type.
+///   - **NoAutoSerde**: Tells Restify to stop injecting ``` #[serde(default)] ``` and
+///     ``` #[serde(skip_serializing_if="Option::is_none")] ``` into every optional field of
+///     the parent type. For APIs that expect explicit nulls.
+///   - **Repr([Ident])**: Passes ``` #[repr(u8)] ``` through to a unit-only Enum, which will
+///     then be (de)serialized as its integer discriminant via **serde_repr**, instead of serde.
+///   - **Constructor**: Tells Restify to generate ``` fn new(..) -> Self ``` for the parent
+///     struct, taking every required field in declaration order. Optional fields start out
+///     as None. A lighter alternative to **Builder**.
+///   - **Name([LitStr])**: Overrides the identifier the parent Struct or Enum is generated
+///     with, i.e., ``` #[name = "UserResponse"] ```. References to the Type keep using its
+///     declared name.
+///   - **ZeroCopy**: Tells Restify to generate every ``` String ``` field of the parent
+///     Response struct as a ``` Cow<'a, str> ```, borrowed from the response body whenever
+///     possible. Avoids allocating while parsing hot-path responses.
+///   - **ArrayFormat([LitStr])**: How a Query struct's sequence fields are rendered by its
+///     generated ``` to_string ```. Either ``` "comma" ``` (``` ids=1,2 ```), ``` "brackets" ```
+///     (``` ids[]=1&ids[]=2 ```) or ``` "repeat" ``` (``` ids=1&ids=2 ```). Without it, serde_qs'
+///     indexed format is used, i.e., ``` ids[0]=1&ids[1]=2 ```.
+///   - **Encode([QueryEncoding])**: How a Query struct's generated ``` to_string ```
+///     percent-encodes the query, i.e., ``` #[encode(space = "percent")] ``` or
+///     ``` #[encode(strict)] ```.
+///   - **MaxDepth([LitInt])**: How deeply nested a Query struct's generated ``` from_query_str ```
+///     parses bracketed keys, i.e., ``` filter[status]=open ```. Defaults to 5.
+///   - **QueryBackend([LitStr])**: The crate a Query struct is (de)serialized with, either
+///     ``` "serde_qs" ```, the default, or ``` "urlencoded" ``` for **serde_urlencoded**, which
+///     only supports flat structs, without any nested or sequence fields.
+///   - **CollectExtra**: Tells Restify to generate a ``` #[serde(flatten)] extra ``` map on the
+///     parent Response struct, holding every field of the response body it doesn't declare.
+///   - **Envelope([Envelope])**: The key a Response struct's payload is wrapped within, i.e.,
+///     ``` #[envelope = "data"] ``` for ``` { "data": {..}, "meta": {..} } ```. The generated
+///     ``` from_json ``` and ``` from_slice ``` unwrap it, and ``` #[envelope("data", meta = "meta")] ```
+///     captures the sibling ``` meta ``` key as well.
+///   - **Hal([Vec]<[HalFollow]>)**: Tells Restify to parse the HAL ``` _links ``` object of the
+///     parent Response struct into a generated ``` links ``` field. Each ``` rel = Endpoint::METHOD ```
+///     argument, i.e., ``` #[hal(author = User::GET)] ```, generates a ``` follow_author() ``` method,
+///     parsing the linked href into that REST Method's Path struct.
+///   - **ContentType([LitStr])**: The body format of the parent Request, Response or ReqRes
+///     struct, either ``` "json" ```, the default, or ``` "xml" ```, which generates
+///     ``` to_xml ``` and ``` from_xml ``` through **quick-xml**.
+///   - **Ndjson**: Tells Restify to generate ``` from_ndjson ``` and ``` from_ndjson_stream ``` for
+///     the parent Response struct, decoding a newline-delimited JSON body one line at a time.
+///   - **Partial**: Tells Restify to also generate the parent struct's all-optional mirror,
+///     i.e., ``` UserRequestPatch ```, along with its ``` apply() ```. See [gen_partial](crate::generators::partial::gen_partial)
 #[derive(Clone)]
 pub enum TypeAttr {
 	Async,
 	Builder,
+	Constructor,
 	Derive(Vec<Ident>),
+	Derives(Derives),
+	DenyUnknownFields,
+	Doc(LitStr),
 	Log(Log),
 	RenameAll(LitStr),
 	Remote(LitStr),
+	Tag(LitStr),
+	Content(LitStr),
+	Untagged,
+	Repr(Ident),
+	SkipNone,
+	NoAutoSerde,
 	Validate(ValidateChain<TypeAttr>),
+	Name(LitStr),
+	ZeroCopy,
+	ArrayFormat(LitStr),
+	Encode(QueryEncoding),
+	MaxDepth(LitInt),
+	QueryBackend(LitStr),
+	CollectExtra,
+	Envelope(Envelope),
+	Hal(Vec<HalFollow>),
+	ContentType(LitStr),
+	Ndjson,
+	Partial,
+}
+impl TypeAttr {
+	/// The name of every Type Attribute, for suggestions on unknown names.
+	pub const NAMES: [&'static str; 29] = [
+		"async", "derive", "derives", "deny_unknown_fields", "doc", "rename_all", "remote", "tag",
+		"content", "untagged", "repr", "collect_extra", "ndjson", "partial", "skip_none",
+		"no_auto_serde", "constructor", "name", "zero_copy", "array_format", "max_depth",
+		"query_backend", "content_type", "hal", "envelope", "encode", "builder", "validate", "log",
+	];
+	
+	/// Returns the Span of self if self can only be attached to an Enum.
+	pub fn enum_specific(&self) -> Option<Span> {
+		return match self {
+			TypeAttr::Content(content) => Some(content.span()),
+			TypeAttr::Untagged         => Some(Span::call_site()),
+			TypeAttr::Repr(ty)         => Some(ty.span()),
+			_ => None,
+		}
+	}
 }
 
 impl From<&TypeAttr> for Option<AttrCommands> {
@@ -153,7 +498,6 @@ impl From<&TypeAttr> for Option<AttrCommands> {
 
 impl Attribute for TypeAttr {
 	fn expand(&self) -> AttrKind {
-		let t: Option<AttrCommands> = self.into();
 		return match self {
 			TypeAttr::Async
 				=> AttrKind::Command(AttrCommands::Async),
@@ -161,21 +505,76 @@ impl Attribute for TypeAttr {
 				=> AttrKind::Command(AttrCommands::Builder),
 			TypeAttr::Derive(derives)
 				=> AttrKind::Quote(quote! {#[derive( #( #derives, )* )]}),
+			TypeAttr::Derives(derives)
+				=> AttrKind::Derives(derives.clone()),
+			TypeAttr::DenyUnknownFields
+				=> AttrKind::Quote(quote! {#[serde(deny_unknown_fields)]}),
+			TypeAttr::Doc(doc)
+				=> AttrKind::Quote(quote! {#[doc = #doc]}),
 			TypeAttr::RenameAll(pattern)
 				=> AttrKind::Quote(quote! {#[serde(rename_all = #pattern)]}),
 			TypeAttr::Remote(external)
 				=> AttrKind::Quote(quote!{ #[serde(remote = #external)] }),
+			TypeAttr::Tag(tag)
+				=> AttrKind::Quote(quote!{ #[serde(tag = #tag)] }),
+			TypeAttr::Content(content)
+				=> AttrKind::Quote(quote!{ #[serde(content = #content)] }),
+			TypeAttr::Untagged
+				=> AttrKind::Quote(quote!{ #[serde(untagged)] }),
+			TypeAttr::Repr(ty)
+				=> AttrKind::Quote(quote!{ #[repr(#ty)] }),
+			// Applied to each optional field by the generators.
+			TypeAttr::SkipNone
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::NoAutoSerde
+				=> AttrKind::Quote(quote!()),
+			// Generated alongside the parent struct, see generators::builder
+			TypeAttr::Constructor
+				=> AttrKind::Quote(quote!()),
+			// Applied to the parent struct's fields while parsing, see Struct::zero_copy
+			TypeAttr::ZeroCopy
+				=> AttrKind::Quote(quote!()),
+			// Applied before generation, see parsers::naming
+			TypeAttr::Name(_)
+				=> AttrKind::Quote(quote!()),
+			// Applied within the generated to_string, see generators::query
+			TypeAttr::ArrayFormat(_)
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::Encode(_)
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::MaxDepth(_)
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::QueryBackend(_)
+				=> AttrKind::Quote(quote!()),
+			// Generated as a field of the parent struct, see generators::response
+			TypeAttr::CollectExtra
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::Envelope(_)
+				=> AttrKind::Quote(quote!()),
+			// Generated along with the parent struct, see generators::hal
+			TypeAttr::Hal(_)
+				=> AttrKind::Quote(quote!()),
+			// Selects the generated (de)serialization helpers, see generators::response
+			TypeAttr::ContentType(_)
+				=> AttrKind::Quote(quote!()),
+			TypeAttr::Ndjson
+				=> AttrKind::Quote(quote!()),
+			// Generated along with the parent struct, see generators::partial
+			TypeAttr::Partial
+				=> AttrKind::Quote(quote!()),
 			TypeAttr::Validate(val)
 				=> AttrKind::Command(AttrCommands::TypeValidate(val.clone())),
-			
-			_ => AttrKind::Quote(quote!())
+			TypeAttr::Log(log)
+				=> AttrKind::Command(AttrCommands::Log(log.clone())),
 		}
 	}
 }
 impl Parse for TypeAttr {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		let mut lookahead = crate::parsers::tools::Lookahead::new(&input);
-		return match input.parse::<Ident>()?.to_string().as_str() {
+		let mut lookahead = crate::parsers::tools::Lookahead::new(input);
+		// parse_any: 'async' is a keyword, and wouldn't parse as a regular Ident.
+		let ident = input.call(Ident::parse_any)?;
+		return match ident.to_string().as_str() {
 			"async" => {
 				return Ok(TypeAttr::Async);
 			},
@@ -183,7 +582,7 @@ impl Parse for TypeAttr {
 				if input.is_empty(){
 					return Err(SynError::new(input.span(), "TypeAttribute::Derive requires additional Identifiers"));
 				}
-				if !lookahead.new_buffer_and_peek(&input, syn::token::Paren) {
+				if !lookahead.new_buffer_and_peek(input, syn::token::Paren) {
 					return Err(SynError::new(
 						input.span(),
 						"TypeAttribute::Derive Identifiers should be placed within parenthesis"
@@ -213,6 +612,27 @@ impl Parse for TypeAttr {
 				}
 				return Ok(TypeAttr::Derive(derives));
 			}
+			"derives" => {
+				if !lookahead.new_buffer_and_peek(input, syn::token::Paren) {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Derives - Operations should be placed within parenthesis"
+					));
+				}
+				return Ok(TypeAttr::Derives(Derives::parse_derives(input)?));
+			}
+			"deny_unknown_fields" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::DenyUnknownFields - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::DenyUnknownFields);
+			}
+			"doc" => {
+				return Ok(TypeAttr::Doc(parse_doc(input)?));
+			}
 			"rename_all" => {
 				return Ok(TypeAttr::RenameAll(
 					input.parse::<Token![=]>()
@@ -245,6 +665,235 @@ impl Parse for TypeAttr {
 						))?
 				))
 			},
+			"tag" => {
+				return Ok(TypeAttr::Tag(
+					input.parse::<Token![=]>()
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"TypeAttribute::Tag - Identifier and Argument should be seperated by the '=' token"
+						))
+						.and_next(|_| {
+							input.parse::<LitStr>()
+						})
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"TypeAttribute::Tag - The Argument should be a literal string"
+						))?
+				));
+			}
+			"content" => {
+				return Ok(TypeAttr::Content(
+					input.parse::<Token![=]>()
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"TypeAttribute::Content - Identifier and Argument should be seperated by the '=' token"
+						))
+						.and_next(|_| {
+							input.parse::<LitStr>()
+						})
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"TypeAttribute::Content - The Argument should be a literal string"
+						))?
+				));
+			}
+			"untagged" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Untagged - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::Untagged);
+			}
+			"repr" => {
+				if !lookahead.new_buffer_and_peek(input, syn::token::Paren) {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Repr - The integer type should be placed within parenthesis"
+					));
+				}
+				let content;
+				parenthesized!(content in input);
+				let ty = content.parse::<Ident>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::Repr - Expected an integer type, i.e., u8"
+					))?;
+				let valid = [
+					"u8", "u16", "u32", "u64", "usize",
+					"i8", "i16", "i32", "i64", "isize",
+				];
+				if !valid.contains(&ty.to_string().as_str()) {
+					return Err(SynError::new(
+						ty.span(),
+						format!("TypeAttribute::Repr - \"{}\" isn't a supported integer type", ty)
+					));
+				}
+				return Ok(TypeAttr::Repr(ty));
+			}
+			"collect_extra" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::CollectExtra - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::CollectExtra);
+			}
+			"ndjson" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Ndjson - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::Ndjson);
+			}
+			"partial" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Partial - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::Partial);
+			}
+			"skip_none" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::SkipNone - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::SkipNone);
+			}
+			"no_auto_serde" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::NoAutoSerde - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::NoAutoSerde);
+			}
+			"constructor" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::Constructor - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::Constructor);
+			}
+			"name" => {
+				let name = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitStr>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::Name - Expected a string literal, i.e., #[name = \"UserResponse\"]"
+					))?;
+				if name.parse::<Ident>().is_err() {
+					return Err(SynError::new(
+						name.span(),
+						"TypeAttribute::Name - The name must be a valid identifier"
+					));
+				}
+				return Ok(TypeAttr::Name(name));
+			}
+			"zero_copy" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"TypeAttribute::ZeroCopy - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(TypeAttr::ZeroCopy);
+			}
+			"array_format" => {
+				let format = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitStr>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::ArrayFormat - Expected a string literal, i.e., #[array_format = \"comma\"]"
+					))?;
+				if !matches!(format.value().as_str(), "comma" | "brackets" | "repeat") {
+					return Err(SynError::new(
+						format.span(),
+						"TypeAttribute::ArrayFormat - Expected either \"comma\", \"brackets\" or \"repeat\""
+					));
+				}
+				return Ok(TypeAttr::ArrayFormat(format));
+			}
+			"max_depth" => {
+				let depth = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitInt>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::MaxDepth - Expected an integer literal, i.e., #[max_depth = 3]"
+					))?;
+				if depth.base10_parse::<usize>().map_or(true, |depth| depth == 0) {
+					return Err(SynError::new(
+						depth.span(),
+						"TypeAttribute::MaxDepth - The depth must be a positive integer"
+					));
+				}
+				return Ok(TypeAttr::MaxDepth(depth));
+			}
+			"query_backend" => {
+				let backend = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitStr>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::QueryBackend - Expected a string literal, i.e., #[query_backend = \"urlencoded\"]"
+					))?;
+				if !matches!(backend.value().as_str(), "serde_qs" | "urlencoded") {
+					return Err(SynError::new(
+						backend.span(),
+						"TypeAttribute::QueryBackend - Expected either \"serde_qs\" or \"urlencoded\""
+					));
+				}
+				return Ok(TypeAttr::QueryBackend(backend));
+			}
+			"content_type" => {
+				let content_type = input.parse::<Token![=]>()
+					.and_then(|_| input.parse::<LitStr>())
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"TypeAttribute::ContentType - Expected a string literal, i.e., #[content_type = \"xml\"]"
+					))?;
+				if !matches!(content_type.value().as_str(), "json" | "xml") {
+					return Err(SynError::new(
+						content_type.span(),
+						"TypeAttribute::ContentType - Expected either \"json\" or \"xml\""
+					));
+				}
+				return Ok(TypeAttr::ContentType(content_type));
+			}
+			"hal" => {
+				if input.is_empty() {
+					return Ok(TypeAttr::Hal(vec![]));
+				}
+				let content;
+				parenthesized!(content in input);
+				let follows = content.parse_terminated(HalFollow::parse, Token![,])?;
+				return Ok(TypeAttr::Hal(follows.into_iter().collect()));
+			}
+			"envelope" => {
+				if input.peek(Token![=]) {
+					input.parse::<Token![=]>()?;
+					let key = input.parse::<LitStr>()?;
+					return Ok(TypeAttr::Envelope(Envelope{ key, meta: None }));
+				}
+				let content;
+				parenthesized!(content in input);
+				return Ok(TypeAttr::Envelope(content.parse()?));
+			}
+			"encode" => {
+				let content;
+				parenthesized!(content in input);
+				return Ok(TypeAttr::Encode(content.parse()?));
+			}
 			"builder" => {
 				if !input.is_empty() {
 					return Err(SynError::new(
@@ -260,11 +909,11 @@ impl Parse for TypeAttr {
 				return Ok(TypeAttr::Validate(ValidateChain::parse(&actions)?));
 			}
 			"log" => {
-				return Ok(TypeAttr::Log(Log::parse_log(&input)?));
+				return Ok(TypeAttr::Log(Log::parse_log(input)?));
 			}
 			unknown => Err(SynError::new(
-				input.span(),
-				&format!("TypeAttribute: Unknown Identifier found: \"{}\"", unknown)
+				ident.span(),
+				format!("TypeAttribute: Unknown Identifier found: \"{}\"{}", unknown, did_you_mean(unknown, &TypeAttr::NAMES))
 			)),
 		};
 	}
@@ -284,26 +933,80 @@ impl Parse for TypeAttr {
 ///     But when one of those fields is private, but contains a setter method.
 ///     You can call upon that field using serde's **getter* attribute.
 ///     [MoreInfo]
+///   - **Alias([Vec]<[LitStr]>)**: Serde's **alias** attribute. Allows a field to be
+///     deserialized from one or more legacy names, i.e., from older API versions.
+///     Accepts either a single name, ``` #[alias = "userName"] ```, or several at once,
+///     ``` #[alias("userName", "user")] ```.
+///   - **Bound([Option]<[LitStr]>)**: Serde's **bound** attribute. Replaces the where clause
+///     serde infers for the parent field, ``` #[bound = "T: DeserializeOwned"] ```.
+///     A bare ``` #[bound] ``` clears the inferred bounds altogether.
+///   - **Doc([LitStr])**: Parsed from a ``` /// text ``` doc comment on the parent field or
+///     Enum Variant, which is passed through as ``` #[doc = ".."] ``` onto the generated one.
+///   - **With([LitStr])**: Serde's **with** attribute. Attaches a custom serde module,
+///     ``` #[with = "module::path"] ```, which provides both the serialize and
+///     deserialize functions for the parent field.
+///   - **NoAutoSerde**: Tells Restify to not inject any serde attributes into the parent
+///     optional field. ``` field: ?T ``` will then be serialized as an explicit null.
+///   - **Other**: Serde's **other** attribute. Marks the last unit variant of an enum as the
+///     catch-all for unknown discriminants, instead of failing the whole deserialization.
+///   - **Secret**: Wraps the parent field's type within **secrecy**'s
+///     ``` Secret<T> ```, or ``` SecretString ``` for a ``` String ```. Request structs
+///     serialize the field via ``` expose_secret ```, Response structs deserialize into it.
+///     Only supported within Request and Response structs.
+///   - **Sensitive**: Marks a field holding a secret, i.e., a password or a token. Restify will
+///     generate a manual ``` Debug ``` implementation for the parent struct that prints
+///     ``` *** ``` in place of the field's value, and redacts it from ``` #[log] ``` output.
+///   - **DateTime([DateTimeFormat])**: Generates the parent ``` String ``` field as a timestamp,
+///     i.e., ``` #[datetime(format = "%Y-%m-%dT%H:%M:%S", with = "chrono")] ```, see [DateTimeFormat].
+///   - **DefaultVariant**: Parsed from a bare ``` #[default] ``` on a unit Enum Variant. The
+///     parent Enum derives ``` Default ```, with this variant as its default value.
+///   - **Decimal**: Generates the parent ``` String ``` field as a ``` rust_decimal::Decimal ```,
+///     same as declaring it as ``` Money ```. (De)serialized as a string, so amounts never
+///     round-trip through an ``` f64 ```. Requires rust_decimal's ``` serde-with-str ``` feature.
+///   - **Patch**: Generates the parent field as an ``` Option<Option<T>> ```, (de)serialized via
+///     ``` serde_with::rust::double_option ```, so an absent field can be told apart from an
+///     explicit ``` null ```, i.e., for PATCH requests. ``` field: ??T ``` is its shorthand.
+///   - **Uuid**: Generates the parent ``` String ``` field as a ``` uuid::Uuid ```, same as
+///     declaring it as a ``` Uuid ```. Requires uuid's ``` serde ``` feature.
 ///
 #[derive(Clone)]
 pub enum ParamAttr {
+	Alias(Vec<LitStr>),
 	Borrow(Option<LitStr>),
 	Bound(Option<LitStr>),
+	DateTime(DateTimeFormat),
+	Decimal,
 	DeserializeWith(LitStr),
 	Default(Option<LitStr>),
+	DefaultVariant,
+	Doc(LitStr),
 	Flatten,
 	Getter(LitStr),
 	Log(Log),
+	NoAutoSerde,
+	Other,
+	Patch,
 	Rename(LitStr),
+	Secret,
+	Sensitive,
 	SerializeWith(LitStr),
 	Skip,
 	SkipIf(LitStr),
 	SkipDeserialize,
 	SkipSerialize,
+	Uuid,
 	Validate(ValidateChain<ParamAttr>),
 	With(LitStr),
 }
 impl ParamAttr {
+	/// The name of every Param Attribute, for suggestions on unknown names.
+	pub const NAMES: [&'static str; 25] = [
+		"alias", "borrow", "bound", "deserialize_with", "default", "doc", "flatten", "getter", "log",
+		"no_auto_serde", "other", "datetime", "secret", "sensitive", "decimal", "patch", "uuid",
+		"rename", "serialize_with", "skip", "skip_if", "skip_deserialize", "skip_serialize",
+		"validate", "with",
+	];
+	
 	/// Returns true is self is struct-specific.
 	///
 	/// # TODO:
@@ -313,17 +1016,28 @@ impl ParamAttr {
 	/// But, at this moment, there only exists one non-struct specific Attribute, 'rename'
 	pub fn struct_specific(&self) -> (bool, Span) {
 		return match self {
+			ParamAttr::Alias(a)           => (false, a.first().map_or(Span::call_site(), |a| a.span())),
 			ParamAttr::Borrow(Some(b))    => (true, b.span()),
 			ParamAttr::Borrow(_)          => (true, Span::call_site()),
 			ParamAttr::Bound(Some(clause)) => (true, clause.span()),
 			ParamAttr::Bound(_)           => (true, Span::call_site()),
+			ParamAttr::Decimal            => (true,  Span::call_site()),
 			ParamAttr::DeserializeWith(m) => (true,  m.span()),
 			ParamAttr::Default(Some(opt)) => (true,  opt.span()),
 			ParamAttr::Default(_)         => (true,  format!("{}", self).span()),
+			ParamAttr::DefaultVariant     => (false, Span::call_site()),
+			ParamAttr::Doc(doc)           => (false, doc.span()),
 			ParamAttr::Flatten            => (true,  Span::call_site()),
 			ParamAttr::Getter(method)     => (true, method.span()),
 			ParamAttr::Log(_)             => (false, Span::call_site()),
+			ParamAttr::NoAutoSerde        => (true,  Span::call_site()),
+			ParamAttr::Other              => (false, Span::call_site()),
+			ParamAttr::Patch              => (true,  Span::call_site()),
 			ParamAttr::Rename(p)          => (false, p.span()),
+			ParamAttr::DateTime(dt)       => (true,  dt.span()),
+			ParamAttr::Secret             => (true,  Span::call_site()),
+			ParamAttr::Sensitive          => (true,  Span::call_site()),
+			ParamAttr::Uuid               => (true,  Span::call_site()),
 			ParamAttr::SerializeWith(m)   => (true,  m.span()),
 			ParamAttr::Skip               => (true,  Span::call_site()),
 			ParamAttr::SkipIf(m)          => (true,  m.span()),
@@ -338,24 +1052,36 @@ impl ParamAttr {
 impl Attribute for ParamAttr {
 	fn expand(&self) -> AttrKind {
 		return match self {
+			ParamAttr::Alias(aliases)
+				=> AttrKind::Quote(quote!(#[serde( #( alias = #aliases ),* )])),
 			ParamAttr::Borrow(Some(lifetime_str))
 				=> AttrKind::Quote(quote!(#[serde(borrow = #lifetime_str)])),
 			ParamAttr::Borrow(_)
 			=> AttrKind::Quote(quote!(#[serde(borrow)])),
 			ParamAttr::Bound(Some(clause))
 			=> AttrKind::Quote(quote!(#[serde(bound = #clause)])),
+			// A bare #[bound] clears the bounds serde would otherwise infer for the field.
 			ParamAttr::Bound(_)
-			=> AttrKind::Quote(quote!(#[serde(bound)])),
+			=> AttrKind::Quote(quote!(#[serde(bound = "")])),
 			ParamAttr::Rename(name)
-				=> AttrKind::Quote(quote! {#[serde(reanme = #name)]}),
+				=> AttrKind::Quote(quote! {#[serde(rename = #name)]}),
+			// Handled by Restify's generators.
+			ParamAttr::Secret | ParamAttr::Sensitive | ParamAttr::DateTime(_) | ParamAttr::Decimal | ParamAttr::Patch | ParamAttr::Uuid
+				=> AttrKind::Quote(quote!()),
 			ParamAttr::Default(Some(def))
 				=> AttrKind::Quote(quote! {#[serde(default = #def)]}),
 			ParamAttr::Default(_)
 				=> AttrKind::Quote(quote! {#[serde(default)]}),
+			ParamAttr::DefaultVariant
+				=> AttrKind::Quote(quote! {#[default]}),
+			ParamAttr::Doc(doc)
+				=> AttrKind::Quote(quote! {#[doc = #doc]}),
 			ParamAttr::SkipIf(method)
 				=> AttrKind::Quote(quote! {#[serde(skip_serializing_if = #method)]}),
 			ParamAttr::Flatten
 				=> AttrKind::Quote(quote!{ #[serde(flatten)] }),
+			ParamAttr::Other
+				=> AttrKind::Quote(quote!{ #[serde(other)] }),
 			ParamAttr::Getter(method)
 				=> AttrKind::Quote(quote!{ #[serde(getter = #method)] }),
 			ParamAttr::Skip
@@ -368,15 +1094,53 @@ impl Attribute for ParamAttr {
 				=> AttrKind::Quote(quote!{ #[serde(serialize_with = #method)] }),
 			ParamAttr::DeserializeWith(method)
 			=> AttrKind::Quote(quote!{ #[serde(deserialize_with = #method)] }),
+			ParamAttr::With(module)
+				=> AttrKind::Quote(quote!{ #[serde(with = #module)] }),
 			ParamAttr::Validate(validate)
 				=> AttrKind::Command(AttrCommands::ParamValidate(validate.clone())),
+			ParamAttr::Log(log)
+				=> AttrKind::Command(AttrCommands::Log(log.clone())),
 			_ => AttrKind::Quote(quote!()),
 		}
 	}
 }
 impl Parse for ParamAttr {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		return match input.parse::<Ident>()?.to_string().as_str() {
+		let ident = input.parse::<Ident>()?;
+		return match ident.to_string().as_str() {
+			"alias" => {
+				let lookahead = Lookahead::new(input);
+				if !lookahead.peek(syn::token::Paren) {
+					return Ok(ParamAttr::Alias(vec![
+						input.parse::<Token![=]>()
+							.map_err(|syn| SynError::new(
+								syn.span(),
+								"ParamAttribute::Alias - Identifier and Argument should be seperated by the '=' token"
+							))
+							.and_next(|_| {
+								input.parse::<LitStr>()
+							})
+							.map_err(|syn| SynError::new(
+								syn.span(),
+								"ParamAttribute::Alias - The Argument should be a literal string"
+							))?
+					]));
+				}
+				let content;
+				parenthesized!(content in input);
+				let aliases = content.parse_terminated(|alias: ParseStream| alias.parse::<LitStr>(), Token![,])
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"ParamAttribute::Alias - Multiple aliases should be comma-delimited literal strings"
+					))?;
+				if aliases.is_empty() {
+					return Err(SynError::new(
+						content.span(),
+						"ParamAttribute::Alias - Requires at least one literal string"
+					));
+				}
+				return Ok(ParamAttr::Alias(aliases.into_iter().collect()));
+			},
 			"borrow" => {
 				if input.is_empty(){
 					return Ok(ParamAttr::Borrow(None));
@@ -394,36 +1158,33 @@ impl Parse for ParamAttr {
 				return Ok(ParamAttr::Borrow(Some(lifetime_str)));
 			},
 			"bound" => {
-				return Ok(ParamAttr::Bound(
-					// RestifyParser(&input)
-					// 	.and_parse::<Token![=]>()?
-					// 	.and_parse_opt::<Token![=]>()
-					todo!()
-				));
+				if input.is_empty(){
+					return Ok(ParamAttr::Bound(None));
+				}
+				input.parse::<Token![=]>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"Attribute::Bound: If a where clause is included, it must be seperated by a '=' token"
+					))?;
+				let clause = input.parse::<LitStr>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"Attribute::Bound: If a where clause is included; it must be a literal string"
+					))?;
+				return Ok(ParamAttr::Bound(Some(clause)));
 			},
 			"deserialize_with" => {
-				// RParsed::stream(&input)
-				// 	.b_parse::<Token![=], _, _>(
-				// 		|_| {
-				// 		},
-				// 		|syn| SynError::new(syn.span(), "")
-				// 	)?
-				// 	.b_parse::<LitStr, _, _>(
-				// 		|meth| {
-				// 			method = &meth;
-				// 		},
-				// 		|syn| SynError::new(syn.span(), "")
-				// 	)?;
-				let mut parser = RParsed::stream(&input);
-				parser.parse_backup::<Token![=], Token![,], _>(
-					|syn| {
-						Err(syn::Error::new(syn.span(), ""))
-					}
-				)?;
-				
-				return Ok(ParamAttr::DeserializeWith(
-					todo!()
-				));
+				input.parse::<Token![=]>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"ParamAttribute::DeserializeWith - Identifier and Argument should be seperated by the '=' token"
+					))?;
+				let function = input.parse::<LitStr>()
+					.map_err(|syn| SynError::new(
+						syn.span(),
+						"ParamAttribute::DeserializeWith - The Argument should be a literal string containing a function path"
+					))?;
+				return Ok(ParamAttr::DeserializeWith(function));
 			}
 			"default" => {
 				return Ok(ParamAttr::Default({
@@ -444,6 +1205,9 @@ impl Parse for ParamAttr {
 					}
 				}));
 			}
+			"doc" => {
+				return Ok(ParamAttr::Doc(parse_doc(input)?));
+			}
 			"flatten" => Ok(ParamAttr::Flatten),
 			"getter" => {
 				return Ok(ParamAttr::Getter(
@@ -462,8 +1226,79 @@ impl Parse for ParamAttr {
 				));
 			},
 			"log" => {
-				return Ok(ParamAttr::Log(Log::parse_log(&input)?));
+				return Ok(ParamAttr::Log(Log::parse_log(input)?));
 			},
+			"no_auto_serde" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::NoAutoSerde - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::NoAutoSerde);
+			}
+			"other" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Other - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Other);
+			}
+			"datetime" => {
+				if input.is_empty() {
+					return Ok(ParamAttr::DateTime(DateTimeFormat::default()));
+				}
+				let content;
+				parenthesized!(content in input);
+				return Ok(ParamAttr::DateTime(content.parse()?));
+			}
+			"secret" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Secret - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Secret);
+			}
+			"sensitive" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Sensitive - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Sensitive);
+			}
+			"decimal" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Decimal - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Decimal);
+			}
+			"patch" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Patch - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Patch);
+			}
+			"uuid" => {
+				if !input.is_empty() {
+					return Err(SynError::new(
+						input.span(),
+						"ParamAttribute::Uuid - This attribute doesn't take any arguments."
+					));
+				}
+				return Ok(ParamAttr::Uuid);
+			}
 			"rename" => {
 				return Ok(ParamAttr::Rename(
 					input.parse::<Token![=]>()
@@ -481,7 +1316,20 @@ impl Parse for ParamAttr {
 				));
 			}
 			"serialize_with" => {
-				todo!()
+				return Ok(ParamAttr::SerializeWith(
+					input.parse::<Token![=]>()
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"ParamAttribute::SerializeWith - Identifier and Argument should be seperated by the '=' token"
+						))
+						.and_next(|_| {
+							input.parse::<LitStr>()
+						})
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"ParamAttribute::SerializeWith - The Argument should be a literal string containing a function path"
+						))?
+				));
 			}
 			"skip" => Ok(ParamAttr::Skip),
 			"skip_if" => {
@@ -506,22 +1354,60 @@ impl Parse for ParamAttr {
 				let actions;
 				parenthesized!(actions in input);
 				let validate = ValidateChain::parse(&actions)?;
-				println!("VALIDATE: {:?}", validate);
+				log::trace!("ParamAttribute::Validate - {:?}", validate);
 				return Ok(ParamAttr::Validate(
 					validate
 				))
 			},
 			"with" => {
-				todo!()
+				return Ok(ParamAttr::With(
+					input.parse::<Token![=]>()
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"ParamAttribute::With - Identifier and Argument should be seperated by the '=' token"
+						))
+						.and_next(|_| {
+							input.parse::<LitStr>()
+						})
+						.map_err(|syn| SynError::new(
+							syn.span(),
+							"ParamAttribute::With - The Argument should be a literal string containing a module path"
+						))?
+				));
 			},
-			unknown => Err(SynError::new(input.span(), &format!("TypeAttribute: Unknown Identifier found: \"{}\"", unknown))),
+			unknown => Err(SynError::new(
+				ident.span(),
+				format!("ParamAttribute: Unknown Identifier found: \"{}\"{}", unknown, did_you_mean(unknown, &ParamAttr::NAMES))
+			)),
 		};
 	}
 }
+/// Parses the ``` = "text" ``` of a ``` #[doc = "text"] ``` attribute, which is what a
+/// ``` /// text ``` doc comment is turned into before it reaches Restify.
+fn parse_doc(input: ParseStream) -> syn::Result<LitStr> {
+	input.parse::<Token![=]>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			"Attribute::Doc - Identifier and Argument should be seperated by the '=' token"
+		))?;
+	return input.parse::<LitStr>()
+		.map_err(|syn| SynError::new(
+			syn.span(),
+			"Attribute::Doc - The Argument should be a literal string"
+		));
+}
+
 // ->> Other Implementations for TypeAttr & ParamAttr
 impl Display for ParamAttr {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		return match self {
+			ParamAttr::Alias(aliases)
+				=> write!(f, "#[serde({})]", aliases
+					.iter()
+					.map(|a| format!("alias = \"{}\"", a.value()))
+					.collect::<Vec<_>>()
+					.join(", ")
+				),
 			ParamAttr::Borrow(Some(b))
 				=> write!(f, "#[serde(borrow = \"{}\")]", b.value()),
 			ParamAttr::Borrow(_)
@@ -529,17 +1415,37 @@ impl Display for ParamAttr {
 			ParamAttr::Bound(Some(clause))
 				=> write!(f, "#[serde(bound = \"{}\")]", clause.value()),
 			ParamAttr::Bound(_)
-				=> write!(f, "#[serde(bound)]"),
+				=> write!(f, "#[serde(bound = \"\")]"),
 			ParamAttr::Rename(p)
 				=> write!(f, "#[serde(rename=\"{}\")]", p.value()),
+			ParamAttr::Secret
+				=> write!(f, "<RESTIFY: Secret = TRUE>"),
+			ParamAttr::DateTime(dt)
+				=> write!(f, "<RESTIFY: DateTime = with: {}, format: {:?}>", dt.backend, dt.format.as_ref().map(LitStr::value)),
+			ParamAttr::Sensitive
+				=> write!(f, "<RESTIFY: Sensitive = TRUE>"),
+			ParamAttr::Uuid
+				=> write!(f, "<RESTIFY: Uuid = TRUE>"),
+			ParamAttr::Decimal
+				=> write!(f, "<RESTIFY: Decimal = TRUE>"),
+			ParamAttr::Patch
+				=> write!(f, "<RESTIFY: Patch = TRUE>"),
 			ParamAttr::Default(Some(opt))
 				=> write!(f, "#[serde(default=\"{}\")]", opt.value()),
 			ParamAttr::Default(_)
 				=> write!(f, "#[serde(default)]"),
+			ParamAttr::DefaultVariant
+				=> write!(f, "#[default]"),
+			ParamAttr::Doc(doc)
+				=> write!(f, "#[doc = \"{}\"]", doc.value()),
 			ParamAttr::SkipIf(m)
 				=> write!(f, "#[serde(skip_serializing_if=\"{}\")]", m.value()),
 			ParamAttr::Flatten
 				=> write!(f, "#[serde(flatten)]"),
+			ParamAttr::Other
+				=> write!(f, "#[serde(other)]"),
+			ParamAttr::NoAutoSerde
+				=> write!(f, "<RESTIFY: No-Auto-Serde = TRUE>"),
 			ParamAttr::Getter(external)
 				=> write!(f, "#[serde(getter = \"{}\")]", external.value()),
 			ParamAttr::Skip
@@ -550,7 +1456,7 @@ impl Display for ParamAttr {
 			=> write!(f, "#[serde(skip_deserializing)]"),
 			ParamAttr::Log(log)
 				=> write!(f, "{}", log),
-			ParamAttr::Validate(val)
+			ParamAttr::Validate(_)
 				=> write!(f, "TODO"),
 			ParamAttr::SerializeWith(method)
 				=> write!(f, "#[serde(serialize_with = \"{}\")]", method.value()),
@@ -570,29 +1476,304 @@ impl Display for TypeAttr {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
 			TypeAttr::Async
-				=> write!(f, "#[async]\n"),
+				=> writeln!(f, "#[async]"),
 			TypeAttr::Derive(s)
-				=> write!(f,
-									"#[derive({})]\n",
+				=> writeln!(f,
+									"#[derive({})]",
 									s.iter()
 										.map(|d| d.to_string())
 										.collect::<Vec<_>>()
 										.join(",")
 				),
+			TypeAttr::Derives(derives)
+				=> writeln!(f, "<RESTIFY: Derives = {} operation(s)>", derives.ops.len()),
+			TypeAttr::DenyUnknownFields
+				=> writeln!(f, "#[serde(deny_unknown_fields)]"),
+			TypeAttr::Doc(doc)
+				=> writeln!(f, "#[doc = \"{}\"]", doc.value()),
 			TypeAttr::RenameAll(pattern)
-				=> write!(f, "#[serde(rename_all=\"{}\")]\n", pattern.value()),
+				=> writeln!(f, "#[serde(rename_all=\"{}\")]", pattern.value()),
 			TypeAttr::Remote(method)
 				=> write!(f, "#[serde(remote = \"{}\")]", method.value()),
+			TypeAttr::Tag(tag)
+				=> writeln!(f, "#[serde(tag = \"{}\")]", tag.value()),
+			TypeAttr::Content(content)
+				=> writeln!(f, "#[serde(content = \"{}\")]", content.value()),
+			TypeAttr::Untagged
+				=> writeln!(f, "#[serde(untagged)]"),
+			TypeAttr::Repr(ty)
+				=> writeln!(f, "#[repr({})]", ty),
+			TypeAttr::SkipNone
+				=> writeln!(f, "<RESTIFY: Skip-None = TRUE>"),
+			TypeAttr::NoAutoSerde
+				=> writeln!(f, "<RESTIFY: No-Auto-Serde = TRUE>"),
 			TypeAttr::Builder
-				=> write!(f, "<RESTIFY: Builder-Pattern = TRUE>\n"),
+				=> writeln!(f, "<RESTIFY: Builder-Pattern = TRUE>"),
+			TypeAttr::Constructor
+				=> writeln!(f, "<RESTIFY: Constructor = TRUE>"),
+			TypeAttr::ZeroCopy
+				=> writeln!(f, "<RESTIFY: Zero-Copy = TRUE>"),
+			TypeAttr::CollectExtra
+				=> writeln!(f, "<RESTIFY: Collect-Extra = TRUE>"),
+			TypeAttr::ContentType(content_type)
+				=> writeln!(f, "<RESTIFY: Content-Type = \"{}\">", content_type.value()),
+			TypeAttr::Ndjson
+				=> writeln!(f, "<RESTIFY: NDJSON = TRUE>"),
+			TypeAttr::Partial
+				=> writeln!(f, "<RESTIFY: Partial = TRUE>"),
+			TypeAttr::Hal(follows)
+				=> writeln!(f, "<RESTIFY: HAL = {}>", follows.iter()
+					.map(|follow| format!("{} = {}::{}", follow.rel, follow.endpoint, follow.method))
+					.collect::<Vec<_>>()
+					.join(", ")),
+			TypeAttr::Envelope(envelope)
+				=> writeln!(f, "<RESTIFY: Envelope = \"{}\", meta: {:?}>", envelope.key.value(), envelope.meta.as_ref().map(LitStr::value)),
+			TypeAttr::ArrayFormat(format)
+				=> writeln!(f, "<RESTIFY: Array-Format = \"{}\">", format.value()),
+			TypeAttr::MaxDepth(depth)
+				=> writeln!(f, "<RESTIFY: Max-Depth = {}>", depth),
+			TypeAttr::QueryBackend(backend)
+				=> writeln!(f, "<RESTIFY: Query-Backend = \"{}\">", backend.value()),
+			TypeAttr::Encode(encoding)
+				=> writeln!(f, "<RESTIFY: Encode = space: {}, strict: {}>", encoding.space_as_plus(), encoding.strict),
+			TypeAttr::Name(name)
+				=> writeln!(f, "<RESTIFY: Name = \"{}\">", name.value()),
 			TypeAttr::Validate(_)
-				=> write!(f, "VALIDATE: TODO\n"),
+				=> writeln!(f, "VALIDATE: TODO"),
 			TypeAttr::Log(log)
 				=> write!(f, "{}", log)
 		}
 	}
 }
 
+/// # Query Encoding
+/// The arguments of a Query struct's ``` #[encode(..)] ``` attribute.
+///
+/// # Parameters:
+///   - [Option]<[LitStr]> space: How spaces are encoded, either ``` "plus" ``` (``` a+b ```)
+///     or ``` "percent" ``` (``` a%20b ```). Defaults to ``` "plus" ```, or to ``` "percent" ```
+///     when **strict**.
+///   - [bool] strict: Strict RFC 3986 encoding, where every character besides the unreserved
+///     ``` A-Z a-z 0-9 - . _ ~ ``` is percent-encoded, including the brackets of nested keys.
+#[derive(Clone, Default)]
+pub struct QueryEncoding {
+	pub space: Option<LitStr>,
+	pub strict: bool,
+}
+impl QueryEncoding {
+	/// Returns true if spaces are encoded as ``` + ```
+	pub fn space_as_plus(&self) -> bool {
+		return match &self.space {
+			Some(space) => space.value() == "plus",
+			None => !self.strict,
+		};
+	}
+}
+impl Parse for QueryEncoding {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut encoding = QueryEncoding::default();
+		while !input.is_empty() {
+			let arg = input.parse::<Ident>()?;
+			match arg.to_string().as_str() {
+				"space" => {
+					input.parse::<Token![=]>()?;
+					let space = input.parse::<LitStr>()?;
+					if !matches!(space.value().as_str(), "plus" | "percent") {
+						return Err(SynError::new(
+							space.span(),
+							"TypeAttribute::Encode - space should either be \"plus\" or \"percent\""
+						));
+					}
+					encoding.space = Some(space);
+				}
+				"strict" => encoding.strict = true,
+				unknown => return Err(SynError::new(
+					arg.span(),
+					format!("TypeAttribute::Encode - Unknown argument \"{unknown}\", expected either space = \"..\" or strict")
+				)),
+			}
+			if !input.is_empty() {
+				input.parse::<Token![,]>()?;
+			}
+		}
+		return Ok(encoding);
+	}
+}
+
+/// # Envelope
+/// The arguments of a Response struct's ``` #[envelope(..)] ``` attribute.
+///
+/// # Parameters:
+///   - [LitStr] key: The key holding the payload, i.e., ``` "data" ```
+///   - [Option]<[LitStr]> meta: The sibling key to capture alongside the payload, i.e.,
+///     ``` meta = "meta" ```. Captured within a generated field of the same name.
+#[derive(Clone)]
+pub struct Envelope {
+	pub key: LitStr,
+	pub meta: Option<LitStr>,
+}
+impl Envelope {
+	/// Returns the identifier of the field capturing the **meta** key, if declared.
+	pub fn meta_field(&self) -> Option<Ident> {
+		return self.meta.as_ref().map(|meta| Ident::new(&snake_case(&[meta.value().as_str()], false), meta.span()));
+	}
+}
+impl Parse for Envelope {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let key = input.parse::<LitStr>()
+			.map_err(|syn| SynError::new(
+				syn.span(),
+				"TypeAttribute::Envelope - Expected the payload's key, i.e., #[envelope(\"data\", meta = \"meta\")]"
+			))?;
+		let mut meta = None;
+		while !input.is_empty() {
+			input.parse::<Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			let arg = input.parse::<Ident>()?;
+			if arg != "meta" {
+				return Err(SynError::new(
+					arg.span(),
+					format!("TypeAttribute::Envelope - Unknown argument \"{arg}\", expected meta = \"..\"")
+				));
+			}
+			input.parse::<Token![=]>()?;
+			let value = input.parse::<LitStr>()?;
+			if syn::parse_str::<Ident>(&snake_case(&[value.value().as_str()], false)).is_err() {
+				return Err(SynError::new(
+					value.span(),
+					"TypeAttribute::Envelope - The meta key must also be a valid field name"
+				));
+			}
+			meta = Some(value);
+		}
+		return Ok(Envelope{ key, meta });
+	}
+}
+
+/// # HAL Follow
+/// A single ``` rel = Endpoint::METHOD ``` argument of a Response struct's ``` #[hal(..)] ```
+/// attribute. The linked Endpoint and REST Method must be declared within the same `restify!`.
+///
+/// # Parameters:
+///   - [Ident] rel: The link relation, i.e., ``` author ``` for ``` _links.author.href ```
+///   - [Ident] endpoint: The name of the linked Endpoint.
+///   - [Ident] method: The linked REST Method, in its canonical uppercase form.
+#[derive(Clone)]
+pub struct HalFollow {
+	pub rel: Ident,
+	pub endpoint: Ident,
+	pub method: Ident,
+}
+impl HalFollow {
+	/// The identifier of the generated follow method, i.e., ``` follow_author ```
+	pub fn follow_fn(&self) -> Ident {
+		return Ident::new(&format!("follow_{}", snake_case(&[self.rel.to_string().as_str()], false)), self.rel.span());
+	}
+}
+impl Parse for HalFollow {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		// parse_any: 'self' is a keyword, and the most common HAL relation.
+		let rel = input.call(Ident::parse_any)?;
+		input.parse::<Token![=]>()?;
+		let endpoint = input.parse::<Ident>()?;
+		input.parse::<Token![::]>()?;
+		let method = input.parse::<Ident>()?;
+		let canonical = RestMethods::try_from(&method)
+			.map_err(|_| SynError::new(
+				method.span(),
+				format!("TypeAttribute::Hal - \"{method}\" isn't a REST Method, expected i.e., {rel} = {endpoint}::GET")
+			))?
+			.to_string();
+		let method = Ident::new(&canonical, method.span());
+		return Ok(HalFollow{ rel, endpoint, method });
+	}
+}
+
+/// # DateTime Format
+/// The arguments of a field's ``` #[datetime(..)] ``` attribute.
+///
+/// # Parameters:
+///   - [Option]<[LitStr]> format: The timestamp's format, i.e., ``` format = "%Y-%m-%d %H:%M:%S" ```
+///     for **chrono**, or ``` format = "[year]-[month]-[day] [hour]:[minute]:[second]" ``` for
+///     **time**. A format without an offset is read as UTC. Defaults to RFC 3339.
+///   - [DateTimeBackend] backend: The crate the timestamp is generated with, declared with
+///     ``` with = "chrono" ```, the default, or ``` with = "time" ```.
+#[derive(Clone, Default)]
+pub struct DateTimeFormat {
+	pub format: Option<LitStr>,
+	pub backend: DateTimeBackend,
+}
+impl DateTimeFormat {
+	pub fn span(&self) -> Span {
+		return self.format.as_ref().map_or(Span::call_site(), LitStr::span);
+	}
+	/// Returns true if **format** declares the timestamp's offset, otherwise it's read as UTC.
+	pub fn has_offset(&self) -> bool {
+		let Some(format) = &self.format else {
+			return true;
+		};
+		let format = format.value();
+		return match self.backend {
+			DateTimeBackend::Chrono => ["%z", "%:z", "%::z", "%:::z", "%#z", "%+"].iter().any(|z| format.contains(z)),
+			DateTimeBackend::Time => format.contains("[offset_"),
+		};
+	}
+}
+impl Parse for DateTimeFormat {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut datetime = DateTimeFormat::default();
+		while !input.is_empty() {
+			let arg = input.parse::<Ident>()?;
+			input.parse::<Token![=]>()?;
+			let value = input.parse::<LitStr>()?;
+			match arg.to_string().as_str() {
+				"format" => {
+					if value.value().trim().is_empty() {
+						return Err(SynError::new(
+							value.span(),
+							"ParamAttribute::DateTime - format can't be empty"
+						));
+					}
+					datetime.format = Some(value);
+				}
+				"with" => {
+					datetime.backend = match value.value().as_str() {
+						"chrono" => DateTimeBackend::Chrono,
+						"time"   => DateTimeBackend::Time,
+						_ => return Err(SynError::new(
+							value.span(),
+							"ParamAttribute::DateTime - with should either be \"chrono\" or \"time\""
+						)),
+					};
+				}
+				unknown => return Err(SynError::new(
+					arg.span(),
+					format!("ParamAttribute::DateTime - Unknown argument \"{unknown}\", expected either format = \"..\" or with = \"..\"")
+				)),
+			}
+			if !input.is_empty() {
+				input.parse::<Token![,]>()?;
+			}
+		}
+		return Ok(datetime);
+	}
+}
+
+/// # DateTime Backend
+/// The crate a ``` #[datetime(..)] ``` field is generated with.
+///   - **Chrono**: ``` chrono::DateTime<Utc> ```
+///   - **Time**: ``` time::OffsetDateTime ```
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq)]
+pub enum DateTimeBackend {
+	/// chrono
+	#[default]
+	Chrono,
+	/// time
+	Time,
+}
+
 impl Debug for TypeAttr {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}", self)
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
 use syn::{bracketed, Token};
 use crate::attributes::kinds::AttrKind;
@@ -10,6 +11,7 @@ mod attr_slice;
 mod compiled;
 mod command;
 mod commands;
+mod derives;
 
 /// # Attribute Trait:
 /// Bounded to [Parse], used for Implementing Rust Types to be used with [Attrs]
@@ -28,21 +30,23 @@ pub trait Attribute: Parse + Debug{
 /// Detects if the next Token in the provided ParseStream is the beginning on an Attribute or not.
 ///
 /// # Returns:
-///  - [syn::Result]<[Option]<[Attribute]>>
-///  - Ok(Some(A: [Attribute])): After successfully parsing an Attribute.
+///  - [syn::Result]<[Option]<([Attribute], [Span])>>
+///  - Ok(Some((A: [Attribute], span))): After successfully parsing an Attribute, along with the Span
+///    of its name, i.e., ``` rename ``` within ``` #[rename = ".."] ```
 ///  - Ok(None): Successfully detected that the next token is not the beginning of a new Attribute
 ///  - Err(syn::Error): Found that the next token is the beginning of a new Attribute, but failed to parse it.
 pub fn parse_attribute<A: Attribute>(
 	input: ParseStream
-) -> syn::Result<Option<A>> {
-	let lookahead = Lookahead::new(&input);
+) -> syn::Result<Option<(A, Span)>> {
+	let lookahead = Lookahead::new(input);
 	if !lookahead.peek(Token![#]) {
 		return Ok(None);
 	}
 	input.parse::<Token![#]>()?;
 	let content;
 	bracketed!(content in input);
-	return Ok(Some(content.parse::<A>()?));
+	let span = content.span();
+	return Ok(Some((content.parse::<A>()?, span)));
 }
 
 pub use kinds::{AttrCommands, TypeAttr, ParamAttr};
@@ -51,4 +55,6 @@ pub use compiled::CompiledAttrs;
 pub use kinds::*;
 pub use attrs::*;
 pub use attr_slice::*;
-pub use command::RunCommand;
\ No newline at end of file
+pub use command::RunCommand;
+pub use derives::{Derives, DeriveOp};
+pub use commands::{Log, ValidateAction, ValidateBackend};
\ No newline at end of file
@@ -6,14 +6,14 @@ use syn::{Ident, LitStr, parse_macro_input, Token};
 use syn::parse::{Parse, ParseStream};
 
 
-fn throw_error<P>(message: &str) -> syn::Result<P> {
-	return Err(syn::Error::new(Span::call_site(), message));
+fn throw_error<P>(span: Span, message: &str) -> syn::Result<P> {
+	return Err(syn::Error::new(span, message));
 }
 
 type Result<P> = syn::Result<P>;
-fn throw_error_if(fails: bool, message: &str) -> Result<()> {
+fn throw_error_if(fails: bool, span: Span, message: &str) -> Result<()> {
 	if fails {
-		return Err(syn::Error::new(Span::call_site(), message));
+		return Err(syn::Error::new(span, message));
 	}
 	return Ok(());
 }
@@ -56,7 +56,7 @@ impl DocString {
 		let base_parameters = &mut self.positional_parameters;
 		let kv_parameters = &mut self.named_parameters;
 		
-		throw_error_if(input.parse::<Token![,]>().is_err(),
+		throw_error_if(input.parse::<Token![,]>().is_err(), input.span(),
 			"Missing comma between input string and first identifier"
 		)?;
 		while !input.is_empty() {
@@ -66,7 +66,8 @@ impl DocString {
 				let value: Ident = input.parse()?;
 				if kv_parameters.insert(ident.clone(), value).is_some() {
 					return throw_error::<Self>(
-						&format!("Identifier key '{}' was already used", ident.to_string())
+						ident.span(),
+						&format!("Identifier key '{}' was already used", ident)
 					);
 				}
 			} else {
@@ -102,10 +103,12 @@ impl DocString {
 	///       - For "..{KEY}.."
 	///       - if named_parameters.contains_key(KEY)?
 	///       - REPLACE "..{KEY}.." -> "..{VAL}.."
-	///     After we swap the Key|Value within the input_str. We also add the VALUE into our
-	///     named_parameters, since that's basically what we're doing.
+	///         After we swap the Key|Value within the input_str. We also add the VALUE into our
+	///         named_parameters, since that's basically what we're doing.
 	fn parse_input_string(mut self) -> syn::Result<Self> {
 		let str_value = self.input_string.value();
+		// Errors within the input string point at the string literal itself.
+		let span = self.input_string.span();
 		let mut stream = String::with_capacity(str_value.len());
 		
 		let mut base_params = self.positional_parameters.clone();
@@ -123,8 +126,8 @@ impl DocString {
 						stream.push_str("{{");
 						continue;
 					}
-					throw_error_if(peek.is_none(), "Missing Closing '}'")?;
-					throw_error_if(in_brace, "Unexpected '{' within braced identifier")?;
+					throw_error_if(peek.is_none(), span, "Missing Closing '}'")?;
+					throw_error_if(in_brace, span, "Unexpected '{' within braced identifier")?;
 					in_brace = true;
 				}
 				'}' => {
@@ -134,11 +137,11 @@ impl DocString {
 						in_brace = false;
 						continue;
 					} else if !in_brace {
-						return throw_error("Unmatched '}' found");
+						return throw_error(span, "Unmatched '}' found");
 					}
 					in_brace = false;
 					if !cur_identifier.is_empty() {
-						let mut current_ident = Ident::new(&cur_identifier, Span::call_site());
+						let mut current_ident = Ident::new(&cur_identifier, span);
 						cur_identifier.clear();
 						
 						if self.named_parameters.contains_key(&current_ident) {
@@ -148,26 +151,26 @@ impl DocString {
 						if !self.positional_parameters.contains(&current_ident) {
 							self.positional_parameters.push(current_ident.clone());
 						}
-						stream.push_str(&format!("{{{}}}", current_ident.to_string()));
+						stream.push_str(&format!("{{{}}}", current_ident));
 					} else {
 						if let Some(parameter) = base_params.pop() {
 							stream.push_str(
 								&format!(
 									"{{{}}}",
-									parameter.to_string()
+									parameter
 								)
 							);
 							continue;
 						}
-						println!("STREAM: {stream}");
-						return throw_error("Empty Curly Braces found, but no Parameter to match it");
+						log::debug!("doc_str - {stream}");
+						return throw_error(span, "Empty Curly Braces found, but no Parameter to match it");
 					}
 				}
 				_ if in_brace => {
-					throw_error_if(cur_identifier.len() == 0 && ch.is_numeric(),
+					throw_error_if(cur_identifier.is_empty() && ch.is_numeric(), span,
 						"First Character of an identifier cannot be numeric."
 					)?;
-					throw_error_if(ch.is_whitespace() || (!ch.is_alphanumeric() && ch != '_'),
+					throw_error_if(ch.is_whitespace() || (!ch.is_alphanumeric() && ch != '_'), span,
 						"Invalid character found in identifier"
 					)?;
 					cur_identifier.push(ch);
@@ -175,7 +178,7 @@ impl DocString {
 				_ => stream.push(ch),
 			}
 		}
-		throw_error_if(!base_params.is_empty(), {
+		throw_error_if(!base_params.is_empty(), span, {
 			base_params.reverse();
 			let residual_parameters: String = base_params
 				.iter()
@@ -184,20 +187,20 @@ impl DocString {
 				.join(", ");
 			&format!("\"{}\" don't have matching empty braces", residual_parameters)
 		})?;
-		self.input_string = LitStr::new(&stream, Span::call_site());
+		self.input_string = LitStr::new(&stream, span);
 		return Ok(self);
 	}
 }
 
 impl Parse for DocString {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		return Ok(DocString{
+		return DocString{
 			input_string: input.parse()?,
 			positional_parameters: Vec::new(),
 			named_parameters: HashMap::new(),
 		}
 			.parse_identifiers(input)?
-			.parse_input_string()?);
+			.parse_input_string();
 	}
 }
 
@@ -0,0 +1,404 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use syn::{GenericParam, Generics, Visibility};
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::parsers::tools::{is_option_type, option_inner_type};
+
+use crate::parsers::options::RestOptions;
+/// Generates the typestate builder of a Type declared with `#[builder]`.
+///
+/// The builder is a separate struct, `<Type>Builder`, started with either
+/// `<Type>Builder::default()` or `<Type>::builder()`. No setters are generated on the
+/// Type itself.
+///
+/// Every required field is tracked by a generic of the builder, which is `()` until the
+/// field's setter is called, and the field's Type afterward. `build()` is only implemented
+/// once every generic holds its field's Type, so a half-initialized value fails to compile
+/// instead. Optional fields start out as `None`, and don't take part in the typestate.
+///
+/// Optional fields get two setters, ``` with_x(impl Into<T>) ```, which wraps the value
+/// in ``` Some ```, and ``` without_x() ```, which resets it to ``` None ```.
+///
+/// The struct's own generic parameters come first within the builder's generics, and are
+/// held by a ``` PhantomData ``` marker, since unset required fields don't use them.
+///
+/// ```ignore
+/// let request = Request::builder()
+///   .with_id(10)
+///   .with_note("..")
+///   .build();
+/// ```
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct.
+/// - `name`: The identifier of the struct.
+/// - `fields`: The struct's fields.
+///
+/// ## Returns
+/// `TokenStream2` containing the builder struct, its setters, and `build()`.
+/// When the struct is validated, `build()` returns `Result<T, ValidationErrors>`.
+pub fn gen_builder(
+	vis    : &Visibility,
+	name   : &Ident,
+	fields : &StructParameterSlice,
+) -> TokenStream2 {
+	let builder = format_ident!("{}Builder", name);
+	let field_names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+	
+	// The struct's own generics, carried over onto the builder.
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let params = generic_params(generics);
+	let args = generic_args(generics);
+	let (marker_field, marker_init, marker_moved) = match generics.params.is_empty() {
+		true  => (quote!(), quote!(), quote!()),
+		false => (
+			quote!( __restify_marker: ::core::marker::PhantomData<fn() -> #name #ty_generics>, ),
+			quote!( __restify_marker: ::core::marker::PhantomData, ),
+			quote!( __restify_marker: self.__restify_marker, ),
+		),
+	};
+	
+	// The builder's generics, one per required field.
+	let required = fields.iter().filter(|field| !is_optional(field)).collect::<Vec<_>>();
+	let states = required.iter().map(|field| state(field)).collect::<Vec<_>>();
+	let set_types = required.iter().map(|field| quote_field_type(field)).collect::<Vec<_>>();
+	
+	let builder_fields = fields.iter().map(|field| {
+		let field_name = &field.name;
+		if is_optional(field) {
+			let ty = quote_field_type(field);
+			return quote!( #field_name: #ty, );
+		}
+		let state = state(field);
+		return quote!( #field_name: #state, );
+	});
+	let empty_fields = fields.iter().map(|field| {
+		let field_name = &field.name;
+		if is_optional(field) {
+			return quote!( #field_name: ::core::option::Option::None, );
+		}
+		return quote!( #field_name: (), );
+	});
+	
+	let setters = fields.iter().map(|field| {
+		let field_name = &field.name;
+		let fn_name = format_ident!("with_{}", field_name);
+		let ty = quote_field_type(field);
+		if is_optional(field) {
+			let inner = quote_inner_type(field);
+			let without = format_ident!("without_{}", field_name);
+			return quote! {
+				#vis fn #fn_name(mut self, #field_name: impl ::core::convert::Into<#inner>) -> Self {
+					self.#field_name = ::core::option::Option::Some(#field_name.into());
+					return self;
+				}
+				#vis fn #without(mut self) -> Self {
+					self.#field_name = ::core::option::Option::None;
+					return self;
+				}
+			};
+		}
+		// Every other generic is carried over, while this field's becomes set.
+		let next = required.iter().map(|other| match other.name == field.name {
+			true  => quote_field_type(other),
+			false => { let state = state(other); quote!(#state) },
+		});
+		let moved = fields.iter()
+			.filter(|other| other.name != field.name)
+			.map(|other| &other.name);
+		return quote! {
+			#vis fn #fn_name(self, #field_name: #ty) -> #builder<#( #args, )* #( #next ),*> {
+				return #builder {
+					#field_name,
+					#( #moved: self.#moved, )*
+					#marker_moved
+				};
+			}
+		};
+	});
+	
+	// A #[log(..)] Type logs its built value, once it's been validated.
+	let (on_built, on_invalid) = fields.log()
+		.map(|log| log.quote_build_calls(fields))
+		.unwrap_or_default();
+	let build = if fields.is_validated() {
+		let shared = RestOptions::shared();
+		let validate = match on_invalid.is_empty() {
+			true  => quote!( built.validate()?; ),
+			false => quote! {
+				if let ::core::result::Result::Err(errors) = built.validate() {
+					#on_invalid
+					return ::core::result::Result::Err(errors);
+				}
+			},
+		};
+		quote! {
+			/// # GENERATED: Validated Build
+			/// Only available once every required field was set.
+			/// Returns the value only if it passes its ``` validate() ```
+			#vis fn build(self) -> ::core::result::Result<#name #ty_generics, #shared::ValidationErrors> {
+				let built = #name {
+					#( #field_names: self.#field_names, )*
+				};
+				#validate
+				#on_built
+				return ::core::result::Result::Ok(built);
+			}
+		}
+	} else if !on_built.is_empty() {
+		quote! {
+			/// # GENERATED: Build
+			/// Only available once every required field was set.
+			#vis fn build(self) -> #name #ty_generics {
+				let built = #name {
+					#( #field_names: self.#field_names, )*
+				};
+				#on_built
+				return built;
+			}
+		}
+	} else {
+		quote! {
+			/// # GENERATED: Build
+			/// Only available once every required field was set.
+			#vis fn build(self) -> #name #ty_generics {
+				return #name {
+					#( #field_names: self.#field_names, )*
+				};
+			}
+		}
+	};
+	
+	let support = RestOptions::support();
+	let output = quote! {
+		/// # GENERATED: Typestate Builder
+		/// Each generic tracks whether a required field was set yet.
+		#[allow(non_camel_case_types)]
+		#vis struct #builder<#( #params, )* #( #states = () ),*> #where_clause {
+			#( #builder_fields )*
+			#marker_field
+		}
+		
+		impl #impl_generics ::core::default::Default for #builder #ty_generics #where_clause {
+			/// Starts a builder with every field unset.
+			fn default() -> Self {
+				return #builder {
+					#( #empty_fields )*
+					#marker_init
+				};
+			}
+		}
+		
+		impl #impl_generics #name #ty_generics #where_clause {
+			#support::builder_fn!{ #vis #builder #ty_generics }
+		}
+		
+		#[allow(non_camel_case_types)]
+		impl<#( #params, )* #( #states ),*> #builder<#( #args, )* #( #states ),*> #where_clause {
+			#( #setters )*
+		}
+		
+		impl #impl_generics #builder<#( #args, )* #( #set_types ),*> #where_clause {
+			#build
+		}
+	};
+	output
+}
+
+/// Generates ``` fn new(..) -> Self ``` for a Type declared with `#[constructor]`.
+///
+/// Every required field is a parameter, in declaration order, and every optional
+/// field starts out as `None`. Unlike `build()`, `new()` doesn't run validation.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct.
+/// - `name`: The identifier of the struct.
+/// - `fields`: The struct's fields.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block with the `new()` method.
+pub fn gen_constructor(
+	vis    : &Visibility,
+	name   : &Ident,
+	fields : &StructParameterSlice,
+) -> TokenStream2 {
+	let params = fields.iter().filter(|field| !is_optional(field)).map(|field| {
+		let field_name = &field.name;
+		let ty = quote_field_type(field);
+		return quote!( #field_name: #ty );
+	});
+	let initializers = fields.iter().map(|field| {
+		let field_name = &field.name;
+		if is_optional(field) {
+			return quote!( #field_name: ::core::option::Option::None, );
+		}
+		return quote!( #field_name, );
+	});
+	
+	let (impl_generics, ty_generics, where_clause) = fields.generics().split_for_impl();
+	let output = quote! {
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// # GENERATED: Constructor
+			/// Takes every required field. Every optional field starts out as None.
+			#vis fn new(#( #params ),*) -> Self {
+				return #name {
+					#( #initializers )*
+				};
+			}
+		}
+	};
+	output
+}
+
+/// The struct's generic parameters, without their defaults, which aren't allowed on impls.
+fn generic_params(generics: &Generics) -> Vec<GenericParam> {
+	return generics.params.iter().cloned().map(|mut param| {
+		match &mut param {
+			GenericParam::Type(ty) => { ty.eq_token = None; ty.default = None; }
+			GenericParam::Const(c) => { c.eq_token = None; c.default = None; }
+			GenericParam::Lifetime(_) => {}
+		}
+		return param;
+	}).collect();
+}
+
+/// The struct's generic arguments, i.e., ``` 'a, T ``` for ``` <'a, T: Clone> ```
+fn generic_args(generics: &Generics) -> Vec<TokenStream2> {
+	return generics.params.iter().map(|param| match param {
+		GenericParam::Type(ty) => { let ident = &ty.ident; quote!(#ident) }
+		GenericParam::Const(c) => { let ident = &c.ident; quote!(#ident) }
+		GenericParam::Lifetime(l) => { let lifetime = &l.lifetime; quote!(#lifetime) }
+	}).collect();
+}
+
+/// Fields declared with '?', or with an explicit ``` Option<T> ``` Type, start out as None.
+/// A ``` #[secret] ``` field's explicit Option is wrapped within its Secret, so it doesn't.
+fn is_optional(field: &StructParameter) -> bool {
+	return field.optional || (is_option_type(&field.ty) && !field.is_secret());
+}
+
+/// The builder's generic tracking a required field.
+fn state(field: &StructParameter) -> Ident {
+	return format_ident!("__{}", field.name);
+}
+
+/// The ``` T ``` of an optional field's ``` Option<T> ```
+fn quote_inner_type(field: &StructParameter) -> TokenStream2 {
+	if field.optional {
+		return field.quote_type();
+	}
+	let ty = &field.ty;
+	return match option_inner_type(ty) {
+		Some(inner) => quote!(#inner),
+		None => quote!(#ty),
+	};
+}
+
+/// The field's Type within the built struct.
+fn quote_field_type(field: &StructParameter) -> TokenStream2 {
+	let ty = field.quote_type();
+	if field.optional {
+		return quote!( ::core::option::Option<#ty> );
+	}
+	return ty;
+}
+
+#[cfg(test)]
+mod builder_tests {
+	use quote::quote;
+	use crate::generators::generate;
+	
+	#[test] fn typestate_builder() {
+		let request = generate(r#"
+			#[builder]
+			struct Request {
+				id: u64,
+				name: String,
+				note: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			struct RequestBuilder<__id = (), __name = ()> {
+				id: __id,
+				name: __name,
+				note: ::core::option::Option<String>,
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(
+			fn with_id(self, id: u64) -> RequestBuilder<u64, __name>
+		).to_string()));
+		assert!(request.contains(&quote!(
+			fn with_note(mut self, note: impl ::core::convert::Into<String>) -> Self {
+				self.note = ::core::option::Option::Some(note.into());
+				return self;
+			}
+			fn without_note(mut self) -> Self {
+				self.note = ::core::option::Option::None;
+				return self;
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(impl RequestBuilder<u64, String>).to_string()));
+		assert!(request.contains(&quote!(fn build(self) -> Request).to_string()));
+		assert!(request.contains(&quote!(impl ::core::default::Default for RequestBuilder).to_string()));
+		
+		// Setters are only generated on the builder.
+		assert!(!generate("struct Header { id: u64 }").contains("with_id"));
+	}
+	
+	#[test] fn constructor() {
+		let request = generate(r#"
+			#[constructor]
+			struct Request {
+				id: u64,
+				note: ?String,
+				name: String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			fn new(id: u64, name: String) -> Self {
+				return Request {
+					id,
+					note: ::core::option::Option::None,
+					name,
+				};
+			}
+		).to_string()));
+		assert!(!generate("struct Request { id: u64 }").contains("fn new"));
+	}
+	
+	#[test] fn composed_commands() {
+		// Declaration order never changes what's generated.
+		let request = generate(r#"
+			#[builder]
+			#[validate(custom = "checks::request")]
+			#[log(info = "Requesting {id}", error = "Invalid request: {errors}")]
+			struct Request { id: u64 }
+		"#);
+		assert_eq!(request, generate(r#"
+			#[log(info = "Requesting {id}", error = "Invalid request: {errors}")]
+			#[validate(custom = "checks::request")]
+			#[builder]
+			struct Request { id: u64 }
+		"#));
+		
+		// build() validates first, logging any errors, and only logs a valid value.
+		let position = |code: proc_macro2::TokenStream| request.find(&code.to_string()).unwrap();
+		let validate = position(quote!(if let ::core::result::Result::Err(errors) = built.validate()));
+		let invalid = position(quote!(built.log_error(&errors);));
+		let logged = position(quote!(built.log_info();));
+		let built = position(quote!(return ::core::result::Result::Ok(built);));
+		assert!(validate < invalid && invalid < logged && logged < built);
+		
+		// Without a validate(), the built value is logged right away.
+		let unvalidated = generate(r#"#[builder] #[log(debug = "Built {id}")] struct Request { id: u64 }"#);
+		assert!(unvalidated.contains(&quote!(built.log_debug(); return built;).to_string()));
+		
+		// #[async] doesn't generate anything yet.
+		assert_eq!(
+			generate("#[async] struct Request { id: u64 }"),
+			generate("struct Request { id: u64 }"),
+		);
+	}
+}
@@ -0,0 +1,211 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use crate::attributes::{DateTimeBackend, DateTimeFormat};
+use crate::parsers::options::RestOptions;
+
+/// The Type a ``` #[datetime(..)] ``` field is generated as, i.e., ``` chrono::DateTime<Utc> ```
+pub fn datetime_type(datetime: &DateTimeFormat) -> TokenStream2 {
+	return match datetime.backend {
+		DateTimeBackend::Chrono => {
+			let chrono = RestOptions::chrono();
+			quote!( #chrono::DateTime<#chrono::Utc> )
+		}
+		DateTimeBackend::Time => {
+			let time = RestOptions::time();
+			quote!( #time::OffsetDateTime )
+		}
+	};
+}
+
+/// The serialization function generated for a ``` #[datetime(format = "..")] ``` field.
+fn serialize_fn(field: &Ident) -> Ident {
+	return format_ident!("__restify_serialize_{}", field);
+}
+
+/// The deserialization function generated for a ``` #[datetime(format = "..")] ``` field.
+fn deserialize_fn(field: &Ident) -> Ident {
+	return format_ident!("__restify_deserialize_{}", field);
+}
+
+/// Quotes the serde attribute a ``` #[datetime(..)] ``` field is (de)serialized with.
+/// Without a format, chrono's own RFC 3339 implementation is used as is, while time needs
+/// its ``` time::serde::rfc3339 ``` module. A format is (de)serialized through the functions
+/// generated for the owning struct by [gen_datetime_fns].
+pub fn datetime_serde_attr(
+	datetime : &DateTimeFormat,
+	owner    : &Ident,
+	field    : &Ident,
+	optional : bool,
+) -> TokenStream2 {
+	if datetime.format.is_some() {
+		let serialize = format!("{}::{}", owner, serialize_fn(field));
+		let deserialize = format!("{}::{}", owner, deserialize_fn(field));
+		return quote!( #[serde(serialize_with = #serialize, deserialize_with = #deserialize)] );
+	}
+	return match datetime.backend {
+		DateTimeBackend::Chrono => quote!(),
+		DateTimeBackend::Time => {
+			let time = RestOptions::time();
+			let time = quote!(#time).to_string().replace(' ', "");
+			let with = match optional {
+				true  => format!("{}::serde::rfc3339::option", time),
+				false => format!("{}::serde::rfc3339", time),
+			};
+			quote!( #[serde(with = #with)] )
+		}
+	};
+}
+
+/// Generates the functions a ``` #[datetime(format = "..")] ``` field is serialized and
+/// deserialized with, for the owning struct's ``` impl ``` block. A format without an offset
+/// is read as UTC. Nothing is generated without a format, see [datetime_serde_attr].
+///
+/// ## Parameters
+/// - `datetime`: The field's ``` #[datetime(..)] ``` arguments.
+/// - `field`: The identifier of the field.
+/// - `optional`: True if the field was declared optional, i.e., ``` created_at: ?String ```
+///
+/// ## Returns
+/// `TokenStream2` containing both functions.
+pub fn gen_datetime_fns(
+	datetime : &DateTimeFormat,
+	field    : &Ident,
+	optional : bool,
+) -> TokenStream2 {
+	let Some(format) = &datetime.format else {
+		return quote!();
+	};
+	let serde = RestOptions::serde();
+	let ty = datetime_type(datetime);
+	let serialize = serialize_fn(field);
+	let deserialize = deserialize_fn(field);
+	
+	// Both evaluate to a Result<_, String>, over a `value` in scope.
+	let (render, parse) = match datetime.backend {
+		DateTimeBackend::Chrono => {
+			let chrono = RestOptions::chrono();
+			let parse = match datetime.has_offset() {
+				true  => quote!( #chrono::DateTime::parse_from_str(&value, #format).map(|value| value.with_timezone(&#chrono::Utc)) ),
+				false => quote!( #chrono::NaiveDateTime::parse_from_str(&value, #format).map(|value| value.and_utc()) ),
+			};
+			(
+				quote!( ::core::result::Result::<_, ::std::string::String>::Ok(value.format(#format).to_string()) ),
+				quote!( #parse.map_err(|error| error.to_string()) ),
+			)
+		}
+		DateTimeBackend::Time => {
+			let time = RestOptions::time();
+			let parse = match datetime.has_offset() {
+				true  => quote!( #time::OffsetDateTime::parse(&value, &format) ),
+				false => quote!( #time::PrimitiveDateTime::parse(&value, &format).map(|value| value.assume_utc()) ),
+			};
+			(
+				quote!(
+					#time::format_description::parse_borrowed::<1>(#format)
+						.map_err(|error| error.to_string())
+						.and_then(|format| value.format(&format).map_err(|error| error.to_string()))
+				),
+				quote!(
+					#time::format_description::parse_borrowed::<1>(#format)
+						.map_err(|error| error.to_string())
+						.and_then(|format| #parse.map_err(|error| error.to_string()))
+				),
+			)
+		}
+	};
+	
+	if optional {
+		return quote! {
+			#[doc(hidden)]
+			#[allow(dead_code)]
+			fn #serialize<RestifySerializer>(
+				value: &::core::option::Option<#ty>,
+				serializer: RestifySerializer,
+			) -> ::core::result::Result<RestifySerializer::Ok, RestifySerializer::Error>
+			where
+				RestifySerializer: #serde::Serializer,
+			{
+				let ::core::option::Option::Some(value) = value else {
+					return serializer.serialize_none();
+				};
+				let rendered = #render.map_err(<RestifySerializer::Error as #serde::ser::Error>::custom)?;
+				return serializer.serialize_some(&rendered);
+			}
+			
+			#[doc(hidden)]
+			#[allow(dead_code)]
+			fn #deserialize<'de, RestifyDeserializer>(
+				deserializer: RestifyDeserializer,
+			) -> ::core::result::Result<::core::option::Option<#ty>, RestifyDeserializer::Error>
+			where
+				RestifyDeserializer: #serde::Deserializer<'de>,
+			{
+				let value = <::core::option::Option<::std::string::String> as #serde::Deserialize>::deserialize(deserializer)?;
+				return value
+					.map(|value| #parse)
+					.transpose()
+					.map_err(<RestifyDeserializer::Error as #serde::de::Error>::custom);
+			}
+		};
+	}
+	return quote! {
+		#[doc(hidden)]
+		#[allow(dead_code)]
+		fn #serialize<RestifySerializer>(
+			value: &#ty,
+			serializer: RestifySerializer,
+		) -> ::core::result::Result<RestifySerializer::Ok, RestifySerializer::Error>
+		where
+			RestifySerializer: #serde::Serializer,
+		{
+			let rendered = #render.map_err(<RestifySerializer::Error as #serde::ser::Error>::custom)?;
+			return serializer.serialize_str(&rendered);
+		}
+		
+		#[doc(hidden)]
+		#[allow(dead_code)]
+		fn #deserialize<'de, RestifyDeserializer>(
+			deserializer: RestifyDeserializer,
+		) -> ::core::result::Result<#ty, RestifyDeserializer::Error>
+		where
+			RestifyDeserializer: #serde::Deserializer<'de>,
+		{
+			let value = <::std::string::String as #serde::Deserialize>::deserialize(deserializer)?;
+			return #parse.map_err(<RestifyDeserializer::Error as #serde::de::Error>::custom);
+		}
+	};
+}
+
+#[cfg(test)]
+mod datetime_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn datetime_fields() {
+		let request = generate(r#"
+			struct Request {
+				#[datetime(format = "%Y-%m-%d %H:%M:%S")]
+				created_at: String,
+				#[datetime(with = "time")]
+				updated_at: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			#[serde(serialize_with = "Request::__restify_serialize_created_at", deserialize_with = "Request::__restify_deserialize_created_at")]
+			created_at: ::chrono::DateTime<::chrono::Utc>,
+		).to_string()));
+		assert!(request.contains(&quote!(::chrono::NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S")).to_string()));
+		assert!(request.contains(&quote!(#[serde(with = "::time::serde::rfc3339::option")]).to_string()));
+		assert!(request.contains(&quote!(updated_at: ::core::option::Option<::time::OffsetDateTime>,).to_string()));
+		for invalid in [
+			"struct Request { #[datetime] created_at: u64 }",
+			"struct Request { #[datetime(with = \"jiff\")] created_at: String }",
+			"struct Request { #[datetime] #[secret] created_at: String }",
+			"struct Header { #[datetime] if_modified_since: String }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
@@ -0,0 +1,185 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{LitStr, Visibility};
+use crate::attributes::{AttrSlice, ParamAttr, TypeAttr};
+use crate::parsers::rest_enum::{EnumParameter, EnumsSlice};
+use crate::parsers::options::RestOptions;
+
+/// Returns true if ``` as_str() ``` and ``` TryFrom<&str> ``` are generated for an Enum, see
+/// [gen_enum_str]. Only Enums whose every variant is a unit variant are (de)serialized as a
+/// plain string, unless they're either untagged or ``` #[repr(..)] ``` Enums.
+pub fn has_enum_str(attrs: AttrSlice<TypeAttr>, enums: EnumsSlice) -> bool {
+	let plain = !attrs.iter().any(|a| matches!(a, TypeAttr::Repr(_) | TypeAttr::Untagged));
+	return plain
+		&& enums.len() > 0
+		&& enums.iter().all(|enumeration| matches!(enumeration.param, EnumParameter::Variant));
+}
+
+/// Generates ``` as_str() ``` and ``` TryFrom<&str> ``` for a unit-only Enum, see [has_enum_str],
+/// so its wire strings are available without a serde_json round trip.
+///
+/// ## Design Rationale
+/// - Each variant's wire string is the one serde would use: its ``` #[rename = ".."] ```, or its
+///   name with the Enum's ``` #[rename_all = ".."] ``` rule applied.
+/// - ``` TryFrom ``` also accepts every ``` #[alias(..)] ``` of a variant, and falls back onto
+///   the ``` #[other] ``` variant, if declared, same as serde's Deserialize. Otherwise an unknown
+///   string fails with the shared ``` UnknownVariant ```, see [gen_unknown_variant].
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the Enum (`pub`, `pub(crate)`, etc.).
+/// - `name`: The identifier of the Enum.
+/// - `attrs`: The Enum's Type Attributes, for its ``` #[rename_all = ".."] ``` rule.
+/// - `enums`: The Enum's variants.
+///
+/// ## Returns
+/// `TokenStream2` containing the ``` as_str() ``` impl block and the ``` TryFrom<&str> ``` impl.
+pub fn gen_enum_str(
+	vis   : &Visibility,
+	name  : &Ident,
+	attrs : AttrSlice<TypeAttr>,
+	enums : EnumsSlice,
+) -> TokenStream2 {
+	let rename_all = attrs.iter().find_map(|a| match a {
+		TypeAttr::RenameAll(rule) => Some(rule.clone()),
+		_ => None,
+	});
+	let shared = RestOptions::shared();
+	let mut as_str = Vec::new();
+	let mut try_from = Vec::new();
+	let mut fallback = quote!( ::core::result::Result::Err(#shared::UnknownVariant { value: value.to_string() }) );
+	for enumeration in enums.iter() {
+		let ident = &enumeration.ident;
+		let wire = variant_wire_str(ident, enumeration.attributes.iter(), rename_all.as_ref());
+		as_str.push(quote!( #name::#ident => #wire, ));
+		
+		let attributes = &enumeration.attributes;
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Other)) {
+			fallback = quote!( ::core::result::Result::Ok(#name::#ident) );
+		}
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Skip | ParamAttr::SkipDeserialize)) {
+			continue;
+		}
+		let aliases = attributes.iter().filter_map(|a| match a {
+			ParamAttr::Alias(aliases) => Some(aliases.iter()),
+			_ => None,
+		}).flatten();
+		try_from.push(quote!( #wire #( | #aliases )* => ::core::result::Result::Ok(#name::#ident), ));
+	}
+	
+	return quote! {
+		impl #name {
+			/// # GENERATED Enum::as_str
+			/// as_str returns the string this variant is (de)serialized as.
+			#vis fn as_str(&self) -> &'static str {
+				match self {
+					#( #as_str )*
+				}
+			}
+		}
+		
+		impl ::core::convert::TryFrom<&str> for #name {
+			type Error = #shared::UnknownVariant;
+			
+			fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+				match value {
+					#( #try_from )*
+					_ => #fallback,
+				}
+			}
+		}
+	};
+}
+
+/// Generates the error returned by every generated ``` TryFrom<&str> ``` of an Enum, for a
+/// string that doesn't match any of its variants.
+///
+/// ## Returns
+/// `TokenStream2` containing the `UnknownVariant` definition.
+pub fn gen_unknown_variant() -> TokenStream2 {
+	let output = quote! {
+		/// # GENERATED: UnknownVariant
+		/// A string that doesn't match any variant of an Enum.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+		pub struct UnknownVariant {
+			pub value: ::std::string::String,
+		}
+		
+		impl ::core::fmt::Display for UnknownVariant {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				::core::write!(f, "unknown variant `{}`", self.value)
+			}
+		}
+		
+		impl ::std::error::Error for UnknownVariant {}
+	};
+	output
+}
+
+/// The string a unit variant is (de)serialized as: its ``` #[rename = ".."] ```, or its name
+/// with the parent Enum's ``` #[rename_all = ".."] ``` rule applied, following serde's rules
+/// for variant names.
+fn variant_wire_str(ident: &Ident, attrs: AttrSlice<ParamAttr>, rename_all: Option<&LitStr>) -> String {
+	let rename = attrs.iter().find_map(|a| match a {
+		ParamAttr::Rename(rename) => Some(rename.value()),
+		_ => None,
+	});
+	if let Some(rename) = rename {
+		return rename;
+	}
+	let variant = ident.to_string();
+	let Some(rule) = rename_all else {
+		return variant;
+	};
+	let snake = || {
+		let mut snake = String::new();
+		for (i, c) in variant.char_indices() {
+			if i > 0 && c.is_uppercase() {
+				snake.push('_');
+			}
+			snake.push(c.to_ascii_lowercase());
+		}
+		return snake;
+	};
+	return match rule.value().as_str() {
+		"lowercase" => variant.to_ascii_lowercase(),
+		"UPPERCASE" => variant.to_ascii_uppercase(),
+		"camelCase" => {
+			let mut chars = variant.chars();
+			match chars.next() {
+				Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+				None => variant,
+			}
+		}
+		"snake_case" => snake(),
+		"SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+		"kebab-case" => snake().replace('_', "-"),
+		"SCREAMING-KEBAB-CASE" => snake().replace('_', "-").to_ascii_uppercase(),
+		_ => variant,
+	};
+}
+
+#[cfg(test)]
+mod enum_str_tests {
+	use quote::quote;
+	use crate::generators::generate;
+	
+	#[test] fn enum_str_helpers() {
+		let status = generate(r#"
+			#[rename_all = "snake_case"]
+			enum Status {
+				InProgress,
+				#[rename = "done"]
+				#[alias("finished", "complete")]
+				Completed,
+			}
+		"#);
+		assert!(status.contains(&quote!(Status::InProgress => "in_progress",).to_string()));
+		assert!(status.contains(&quote!("done" | "finished" | "complete" => ::core::result::Result::Ok(Status::Completed),).to_string()));
+		assert!(status.contains(&quote!(_ => ::core::result::Result::Err(__restify::shared::UnknownVariant { value: value.to_string() }),).to_string()));
+		let other = generate("enum Kind { Admin, #[other] Unknown }");
+		assert!(other.contains(&quote!(_ => ::core::result::Result::Ok(Kind::Unknown),).to_string()));
+		assert!(!generate("enum Event { Created(u64), Deleted }").contains("fn as_str"));
+		assert!(!generate("#[untagged] enum Value { Empty }").contains("fn as_str"));
+	}
+}
@@ -0,0 +1,122 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse_quote;
+use syn::Visibility;
+use crate::attributes::{CompiledAttrs, TypeAttr};
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::generators::tools::{quote_struct_body, RestType};
+
+/// The field names an Error struct's ``` Display ``` writes, in order of preference, see [gen_error].
+const MESSAGE_FIELDS: [&str; 5] = ["message", "error", "detail", "title", "description"];
+
+/// Constructs an error body struct for REST API endpoints within the `restify!` macro.
+///
+/// This function generates a Rust struct for the body a server responds with when a call
+/// fails. It implements `serde::Deserialize`, along with `std::error::Error` and `Display`,
+/// so the decoded body can be used as the Err side of a generated call, with `from_json`
+/// and `from_slice` constructors, the same as a Response struct's.
+///
+/// ## Design Rationale
+/// - Most APIs describe a failure within a single field. ``` Display ``` writes the first
+///   declared field named either ``` message ```, ``` error ```, ``` detail ```, ``` title ```
+///   or ``` description ```, falling back onto the struct's name when none was declared, or
+///   when the optional field is missing from the body.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
+/// - `compiled_attrs`: The struct's compiled Type Attributes, for its derives and serde attributes.
+/// - `name`: The identifier of the struct.
+/// - `fields`: A slice of `StructParameter` defining the structure of the error body.
+///
+/// ## Returns
+/// Produces a `TokenStream2` containing the Rust code for the error struct, and its
+/// `Display`, `Error` and constructor impls.
+pub fn gen_error(
+	vis            : &Visibility,
+	compiled_attrs : CompiledAttrs<TypeAttr>,
+	name           : &Ident,
+	fields         : StructParameterSlice,
+) -> TokenStream2 {
+	let error_fields = fields.quote_deserialize(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Deserializable);
+	let body = quote_struct_body(&error_fields);
+	
+	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let support = RestOptions::support();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, #serde::Deserialize));
+	
+	let fallback = name.to_string();
+	let message = MESSAGE_FIELDS.iter()
+		.find_map(|message| fields.iter().find(|field| field.name == message));
+	let display = match message {
+		Some(field) if field.optional => {
+			let message = &field.name;
+			quote! {
+				match &self.#message {
+					::core::option::Option::Some(message) => ::core::write!(f, "{}", message),
+					::core::option::Option::None => ::core::write!(f, #fallback),
+				}
+			}
+		}
+		Some(field) => {
+			let message = &field.name;
+			quote!( ::core::write!(f, "{}", self.#message) )
+		}
+		None => quote!( ::core::write!(f, #fallback) ),
+	};
+	
+	let output = quote! {
+		#[doc = "Error Variant"]
+		#derives
+		#serde_crate
+		#( #quotes )*
+		#vis struct #name #body
+		#assertions
+		impl #name {
+			#support::json_constructors!{ #vis Self }
+		}
+		
+		impl ::core::fmt::Display for #name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				#display
+			}
+		}
+		
+		impl ::std::error::Error for #name {}
+	};
+	output
+}
+
+#[cfg(test)]
+mod error_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::method;
+	
+	#[test] fn error_structs() {
+		let error = generate("struct ApiError<Error> { code: u16, message: String }");
+		assert!(error.contains(&quote!(#[derive(::core::fmt::Debug, ::core::clone::Clone, ::serde::Deserialize)]).to_string()));
+		assert!(error.contains(&quote!(impl ::std::error::Error for ApiError {}).to_string()));
+		assert!(error.contains(&quote!(::core::write!(f, "{}", self.message)).to_string()));
+		assert!(error.contains(&quote!(impl ApiError { __restify::json_constructors!{ Self } }).to_string()));
+		let unnamed = generate("struct Error { code: u16, detail: ?String }");
+		assert!(unnamed.contains(&quote!(::core::option::Option::None => ::core::write!(f, "Error"),).to_string()));
+		assert!(generate("struct Error { code: u16 }").contains(&quote!(::core::write!(f, "Error")).to_string()));
+		let method = Ident::new("UserGET", Span::call_site());
+		let decoder = method::gen_error_decoder(&Visibility::Inherited, &method, &Ident::new("ApiError", Span::call_site()), None).to_string();
+		assert!(decoder.contains(&quote!(fn decode_error(body: &[u8]) -> ::serde_json::Result<ApiError>).to_string()));
+		for invalid in [
+			"#[skip_none] struct Error { message: ?String }",
+			"struct Error { #[secret] token: String }",
+			"struct ApiError<T><Error> { detail: T }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::parse_quote;
+use syn::Visibility;
+use crate::attributes::{CompiledAttrs, TypeAttr};
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::generators::tools::{quote_struct_body, RestType};
+
+/// The Content-Type of a Form body.
+pub const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+/// Constructs a form struct, i.e., ``` struct Login<Form> { .. } ```, for REST API requests
+/// whose body is sent as ``` application/x-www-form-urlencoded ```, like OAuth token requests
+/// or HTML form submissions.
+///
+/// ## Design Rationale
+/// - A Form is encoded like a Query, but sent as the request body rather than appended onto
+///   the URL, and unlike a Request, it's never encoded as JSON. It's (de)serialized with
+///   serde_urlencoded, so a Form only supports flat structs, the same as a Query declared with
+///   ``` #[query_backend = "urlencoded"] ```.
+/// - Both serde directions are generated, so server-side code can parse the same bodies the
+///   client sends, through ``` from_form ```.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
+/// - `compiled_attrs`: The struct's compiled Type Attributes, for its derives and serde attributes.
+/// - `name`: The identifier of the struct.
+/// - `fields`: A slice of `StructParameter` defining the form's fields.
+///
+/// ## Returns
+/// `TokenStream2` containing the Form struct, along with its ``` CONTENT_TYPE ```,
+/// ``` to_form ``` and ``` from_form ```.
+pub fn gen_form(
+	vis            : &Visibility,
+	compiled_attrs : CompiledAttrs<TypeAttr>,
+	name           : &Ident,
+	fields         : StructParameterSlice,
+) -> TokenStream2 {
+	let form_fields = fields.quote_full_serde(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Both);
+	let body = quote_struct_body(&form_fields);
+	
+	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let serde_urlencoded = RestOptions::serde_urlencoded();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, #serde::Serialize, #serde::Deserialize));
+	
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	let output = quote! {
+		#[doc = "Form Variant"]
+		#derives
+		#serde_crate
+		#( #quotes )*
+		#vis struct #name #generics #where_clause #body
+		#assertions
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// The Content-Type of a Form body.
+			#vis const CONTENT_TYPE: &'static str = #FORM_CONTENT_TYPE;
+			
+			/// # GENERATED Form::to_form
+			/// to_form serializes your Form struct into an ``` application/x-www-form-urlencoded ```
+			/// request body.
+			///
+			/// # Returns:
+			///   - Ok(form) when successful
+			///   - Err(serde_urlencoded::ser::Error) when it's not
+			#vis fn to_form(&self) -> ::core::result::Result<::std::string::String, #serde_urlencoded::ser::Error> {
+				#serde_urlencoded::to_string(self)
+			}
+			
+			/// # GENERATED Form::from_form
+			/// from_form parses an ``` application/x-www-form-urlencoded ``` body, as rendered by
+			/// ``` to_form ```, back into your Form struct.
+			///
+			/// # Returns:
+			///   - Ok(form) when successful
+			///   - Err(serde_urlencoded::de::Error) when it's not
+			#vis fn from_form(form: &str) -> ::core::result::Result<Self, #serde_urlencoded::de::Error>
+			where Self: #serde::de::DeserializeOwned
+			{
+				#serde_urlencoded::from_str(form)
+			}
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod form_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::method;
+	
+	#[test] fn form_structs() {
+		let form = generate("struct Login<Form> { username: String, remember: ?bool }");
+		assert!(form.contains(&quote!(const CONTENT_TYPE: &'static str = "application/x-www-form-urlencoded";).to_string()));
+		assert!(form.contains(&quote!(::serde_urlencoded::to_string(self)).to_string()));
+		assert!(form.contains(&quote!(::serde_urlencoded::from_str(form)).to_string()));
+		for invalid in [
+			"struct Form { ids: Vec<u32> }",
+			"struct Form { filter: { status: String } }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+		
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[Auth: {
+				POST "/oauth/token" => { struct Form { grant_type: String } }
+			}]
+		"#).unwrap();
+		let method = &rest.endpoints[0].methods[0];
+		let symbols = crate::parsers::symbols::Symbols::build(rest.components.as_ref(), &rest.endpoints).unwrap();
+		let name = Ident::new("AuthPOST", Span::call_site());
+		let config = method::gen_method_config(&Visibility::Inherited, &name, &method.method, &method.uri, method.attributes.iter(), symbols.form_of(method).is_some()).to_string();
+		assert!(config.contains(&quote!(const CONTENT_TYPE: &'static str = "application/x-www-form-urlencoded";).to_string()));
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[Auth: {
+				POST "/oauth/token" => { struct Form { grant_type: String } struct Request { scope: String } }
+			}]
+		"#).is_err());
+	}
+}
@@ -0,0 +1,178 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{parse_quote, Generics, Visibility};
+use crate::attributes::HalFollow;
+use crate::generators::path::path_struct_name;
+use crate::parsers::naming::method_struct_name;
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::StructParameter;
+
+/// Generates the HAL link types shared by every ``` #[hal] ``` Response struct.
+///
+/// ## Returns
+/// `TokenStream2` containing the `HalLink`, `HalRelation` and `HalLinks` definitions.
+pub fn gen_hal_links() -> TokenStream2 {
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let output = quote! {
+		/// # GENERATED: HalLink
+		/// A single HAL link object, i.e., ``` { "href": "/api/user/1" } ```.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, #serde::Serialize, #serde::Deserialize)]
+		#serde_crate
+		pub struct HalLink {
+			pub href: ::std::string::String,
+			#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+			pub templated: bool,
+			#[serde(default, skip_serializing_if = "Option::is_none")]
+			pub title: ::core::option::Option<::std::string::String>,
+			#[serde(default, skip_serializing_if = "Option::is_none")]
+			pub name: ::core::option::Option<::std::string::String>,
+		}
+		
+		/// # GENERATED: HalRelation
+		/// The link, or links, of a single HAL link relation.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, #serde::Serialize, #serde::Deserialize)]
+		#serde_crate
+		#[serde(untagged)]
+		pub enum HalRelation {
+			One(HalLink),
+			Many(::std::vec::Vec<HalLink>),
+		}
+		
+		/// # GENERATED: HalLinks
+		/// A HAL ``` _links ``` object, keyed by link relation.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::default::Default, #serde::Serialize, #serde::Deserialize)]
+		#serde_crate
+		#[serde(transparent)]
+		pub struct HalLinks(pub ::std::collections::HashMap<::std::string::String, HalRelation>);
+		
+		impl HalLinks {
+			/// Returns every link of the provided relation.
+			pub fn links(&self, rel: &str) -> &[HalLink] {
+				return match self.0.get(rel) {
+					::core::option::Option::Some(HalRelation::One(link)) => ::core::slice::from_ref(link),
+					::core::option::Option::Some(HalRelation::Many(links)) => links.as_slice(),
+					::core::option::Option::None => &[],
+				};
+			}
+			
+			/// Returns the href of the provided relation's first link.
+			pub fn link(&self, rel: &str) -> ::core::option::Option<&str> {
+				return self.links(rel).first().map(|link| link.href.as_str());
+			}
+			
+			/// Returns the path of the provided relation's first link, without its scheme,
+			/// authority, query or fragment, i.e., ``` /api/user/1 ```
+			pub fn link_path(&self, rel: &str) -> ::core::option::Option<&str> {
+				let href = self.link(rel)?;
+				let path = match href.split_once("://") {
+					::core::option::Option::Some((_, rest)) => &rest[rest.find('/').unwrap_or(rest.len())..],
+					::core::option::Option::None => href,
+				};
+				return path.split(['?', '#']).next();
+			}
+		}
+	};
+	output
+}
+
+/// The field generated by ``` #[hal] ```, holding the Response's ``` _links ``` object.
+/// Optional, since HAL doesn't require every resource to declare links.
+pub fn hal_links_field() -> StructParameter {
+	let shared = RestOptions::shared();
+	return parse_quote!(
+		#[rename = "_links"]
+		links: ?#shared::HalLinks
+	);
+}
+
+/// Generates the link helpers of a ``` #[hal] ``` Response struct: ``` link(rel) ```, along
+/// with a follow method for every declared ``` rel = Endpoint::METHOD ```, which parses the
+/// linked href into that REST Method's Path struct.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the Response struct.
+/// - `name`: The identifier of the Response struct.
+/// - `generics`: The generic parameters of the Response struct.
+/// - `follows`: The declared ``` rel = Endpoint::METHOD ``` arguments.
+///
+/// ## Returns
+/// `TokenStream2` containing the Response's link helpers.
+pub fn gen_hal_follows(
+	vis      : &Visibility,
+	name     : &Ident,
+	generics : &Generics,
+	follows  : &[HalFollow],
+) -> TokenStream2 {
+	let shared = RestOptions::shared();
+	let follows = follows.iter().map(|follow| {
+		let rel = follow.rel.to_string();
+		let follow_fn = follow.follow_fn();
+		let method_name = RestOptions::with(|options| method_struct_name(options, &follow.endpoint, &follow.method));
+		let path_name = path_struct_name(&method_name);
+		let doc = format!(" Parses the \"{rel}\" link into a {path_name}, None when the link is absent.");
+		quote! {
+			#[doc = #doc]
+			#vis fn #follow_fn(&self) -> ::core::option::Option<::core::result::Result<#path_name, #shared::PathParseError>> {
+				return self.links.as_ref()?.link_path(#rel).map(str::parse);
+			}
+		}
+	});
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let output = quote! {
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// # GENERATED Response::link
+			/// Returns the href of the provided HAL link relation, i.e., ``` link("next") ```
+			#vis fn link(&self, rel: &str) -> ::core::option::Option<&str> {
+				return self.links.as_ref()?.link(rel);
+			}
+			#( #follows )*
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod hal_tests {
+	use quote::quote;
+	use syn::Visibility;
+	use crate::parsers::struct_parameter::StructParameterSlice;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::gen_endpoint_structs;
+	
+	#[test] fn hal_links() {
+		let response = generate("#[hal] struct Response { id: u32 }");
+		assert!(response.contains(&quote!(#[serde(rename = "_links")]).to_string()));
+		assert!(response.contains(&quote!(fn link(&self, rel: &str) -> ::core::option::Option<&str>).to_string()));
+		let parse = |follow: &str| syn::parse_str::<crate::parsers::RestEndpoints>(&format!(r#"
+			[User: {{
+				GET "/api/user/{{id}}" => {{ struct Response {{ name: String }} }}
+				POST "/api/user" => {{ struct Request {{ name: String }} }}
+			}}]
+			[Post: {{
+				GET "/api/post/{{id}}" => {{
+					#[hal({follow})]
+					struct Response {{ title: String }}
+				}}
+			}}]
+		"#));
+		let rest = parse("author = User::get").unwrap();
+		let EndpointDataType::Struct(post) = &rest.endpoints[1].methods[0].data_types[0] else { panic!() };
+		let follows = gen_endpoint_structs(
+			&Visibility::Inherited,
+			post.attributes.iter(),
+			&post.name,
+			&post.rest_variant,
+			&post.name,
+			StructParameterSlice::from(&post.parameters),
+		).to_string();
+		assert!(follows.contains(&quote!(
+			fn follow_author(&self) -> ::core::option::Option<::core::result::Result<UserGETPath, __restify::shared::PathParseError>>
+		).to_string()));
+		assert!(parse("author = Author::GET").is_err());
+		assert!(parse("author = User::POST").is_err());
+		assert!(syn::parse_str::<EndpointDataType>("#[hal] struct Request { id: u32 }").is_err());
+	}
+}
@@ -1,26 +1,35 @@
 use proc_macro2::TokenStream as TokenStream2;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use quote::quote;
+use syn::parse::Parser;
+use syn::{parse_quote, Attribute};
 use syn::Visibility;
-use crate::attributes::{AttrSlice, CompiledAttrs, TypeAttr};
-use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::attributes::{CompiledAttrs, TypeAttr};
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::parsers::tools::{is_option_type, option_inner_type};
+use crate::generators::tools::quote_struct_body;
 use crate::utils::doc_str::DocString;
+use crate::parsers::options::RestOptions;
 
 /// Generates a header struct as part of the `restify!` macro.
 ///
 /// This function creates a Rust struct specifically designed for managing HTTP headers
-/// within REST API interactions. It automatically implements `serde::Deserialize` to
-/// facilitate parsing header data from incoming HTTP requests.
+/// within REST API interactions. Headers aren't sent as a serialized body, so rather than
+/// deriving serde, the struct converts to and from an **http** ``` HeaderMap ```:
+///   - ``` TryFrom<&HeaderMap> ``` parses every header with its Type's ``` FromStr ```.
+///   - ``` to_header_map() ``` renders every header with its Type's ``` Display ```.
 ///
 /// ## Design Rationale
-/// - Headers in HTTP requests are critical for controlling and understanding both the
-///   request and response contexts. This function ensures that header structures are
-///   robustly defined and easily manageable through serialized data structures.
+/// - Each field maps onto a single header, named by its ``` #[rename = ".."] ```, or by its
+///   name in kebab-case. Optional fields may be missing, as may fields with a declared
+///   default value, i.e., ``` page_size: u32 = 10 ```. Every other missing header is
+///   reported as a `HeaderError`, generated once per `restify!` invocation by
+///   [gen_header_error].
+/// - Values of ``` #[sensitive] ``` fields are marked as sensitive within the HeaderMap.
 ///
 /// ## Parameters
 /// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
-/// - `rename_all`: A `TokenStream2` that specifies renaming conventions to apply to fields
-///   using serde's rename attributes, aiding in the alignment with HTTP header conventions.
+/// - `compiled_attrs`: The struct's compiled Type Attributes. serde attributes are dropped.
 /// - `name`: The identifier of the struct.
 /// - `fields`: A collection of fields representing the HTTP headers, typically parsed
 ///   from a slice of `StructParameter`.
@@ -34,27 +43,270 @@ pub fn gen_header(
 	name           : &Ident,
 	fields         : StructParameterSlice,
 ) -> TokenStream2 {
-	let header_fields = fields.quote_serialize(vis);
-	let header_builders = fields.quote_builder_fn(vis);
-	let quotes = compiled_attrs.quotes_ref();
+	let shared = RestOptions::shared();
+	let header_fields = fields.iter().map(|field| {
+		let field_name = &field.name;
+		let field_type = quote_field_type(field);
+		let quotes = without_serde(field.attributes.compile().quotes_ref());
+		return quote!(
+			#( #quotes )*
+			#vis #field_name: #field_type,
+		);
+	}).collect::<Vec<_>>();
+	let body = quote_struct_body(&header_fields);
+	let quotes = without_serde(compiled_attrs.quotes_ref());
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone));
 	
 	//TODO: iterate over Command Attributes.
 	
 	let _doc = DocString::create()
-		.with_doc(format!("# {}", name.to_string()))
+		.with_doc(format!("# {}", name))
 		.merge(fields.doc_string())
 		.build();
 	
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	let from_headers = fields.iter().map(|field| {
+		let field_name = &field.name;
+		let header = field.header_name();
+		let ty = quote_inner_type(field);
+		let missing = match (&field.default, is_optional(field)) {
+			(_, true) => quote!( value ),
+			(Some(default), false) => quote!( value.unwrap_or_else(|| #default) ),
+			(None, false) => quote!(
+				value.ok_or_else(|| #shared::HeaderError {
+					header: #header,
+					message: ::std::string::String::from("missing"),
+				})?
+			),
+		};
+		return quote! {
+			#field_name: {
+				let value = match headers.get(#header) {
+					::core::option::Option::Some(value) => ::core::option::Option::Some(
+						value.to_str()
+							.map_err(|error| #shared::HeaderError { header: #header, message: error.to_string() })?
+							.parse::<#ty>()
+							.map_err(|error| #shared::HeaderError { header: #header, message: error.to_string() })?
+					),
+					::core::option::Option::None => ::core::option::Option::None,
+				};
+				#missing
+			},
+		};
+	});
+	
+	let to_headers = fields.iter().map(|field| {
+		let field_name = &field.name;
+		let header = field.header_name();
+		let value = quote!( ::http::header::HeaderValue::try_from(value.to_string())? );
+		let value = if field.is_sensitive() {
+			quote! {
+				let mut value = #value;
+				value.set_sensitive(true);
+			}
+		} else { quote!( let value = #value; ) };
+		let insert = quote! {
+			#value
+			headers.insert(::http::header::HeaderName::from_static(#header), value);
+		};
+		if is_optional(field) {
+			return quote! {
+				if let ::core::option::Option::Some(value) = &self.#field_name {
+					#insert
+				}
+			};
+		}
+		return quote! {
+			let value = &self.#field_name;
+			#insert
+		};
+	});
+	
 	let output = quote! {
-		#[derive(std::fmt::Debug, Clone, serde::Serialize)]
+		#derives
 		#( #quotes )*
-		#vis struct #name {
-			#( #header_fields )*
+		#vis struct #name #generics #where_clause #body
+		
+		impl #impl_generics ::core::convert::TryFrom<&::http::header::HeaderMap> for #name #ty_generics #where_clause {
+			type Error = #shared::HeaderError;
+			fn try_from(headers: &::http::header::HeaderMap) -> ::core::result::Result<Self, Self::Error> {
+				::core::result::Result::Ok(Self {
+					#( #from_headers )*
+				})
+			}
 		}
 		
-		impl #name {
-			#( #header_builders )*
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// # GENERATED: Header::to_header_map
+			/// Renders every header into a new HeaderMap, skipping optional headers that are None.
+			///
+			/// # Returns:
+			///   - Ok(headers) when successful
+			///   - Err(InvalidHeaderValue) when a value can't be sent as a header
+			#vis fn to_header_map(&self) -> ::core::result::Result<::http::header::HeaderMap, ::http::header::InvalidHeaderValue> {
+				let mut headers = ::http::header::HeaderMap::new();
+				#( #to_headers )*
+				::core::result::Result::Ok(headers)
+			}
+		}
+	};
+	output
+}
+
+/// Generates the error returned by every generated Header struct's ``` TryFrom<&HeaderMap> ```
+/// implementation.
+///
+/// ## Returns
+/// `TokenStream2` containing the `HeaderError` definition.
+pub fn gen_header_error() -> TokenStream2 {
+	let output = quote! {
+		/// # GENERATED: HeaderError
+		/// A header that is missing, or whose value failed to parse.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+		pub struct HeaderError {
+			/// The name of the header.
+			pub header: &'static str,
+			/// Why the header failed to parse.
+			pub message: ::std::string::String,
+		}
+		
+		impl ::core::fmt::Display for HeaderError {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				::core::write!(f, "header \"{}\": {}", self.header, self.message)
+			}
+		}
+		
+		impl ::std::error::Error for HeaderError {}
+	};
+	output
+}
+
+/// Generates the ``` headers ``` module, holding a constant for every header name declared
+/// across the `restify!` invocation, i.e., ``` pub const X_REQUEST_ID: &str = "x-request-id"; ```,
+/// so header names aren't duplicated as strings within user code.
+///
+/// ## Parameters
+/// - `header_names`: The name of every declared header, see [StructParameter::header_name].
+///   Repeated names only generate a single constant.
+///
+/// ## Returns
+/// `TokenStream2` containing the `headers` module.
+pub fn gen_header_names(header_names: &[String]) -> TokenStream2 {
+	let mut consts: Vec<(Ident, &String)> = Vec::new();
+	for header in header_names.iter() {
+		let mut name = header.to_ascii_uppercase()
+			.chars()
+			.map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+			.collect::<String>();
+		if name.starts_with(|c: char| c.is_ascii_digit()) {
+			name.insert(0, '_');
+		}
+		let name = Ident::new(&name, Span::call_site());
+		if !consts.iter().any(|(existing, _)| *existing == name) {
+			consts.push((name, header));
+		}
+	}
+	let (names, headers): (Vec<_>, Vec<_>) = consts.into_iter().unzip();
+	
+	let output = quote! {
+		/// # GENERATED: Header Names
+		/// The name of every header declared within this API.
+		pub mod headers {
+			#( pub const #names: &str = #headers; )*
 		}
 	};
-	output.into()
+	output
+}
+
+/// Drops every ``` #[serde(..)] ``` attribute, since Header structs don't derive serde.
+fn without_serde(quotes: &[TokenStream2]) -> Vec<TokenStream2> {
+	return quotes.iter()
+		.filter(|quote| {
+			let Ok(attrs) = Attribute::parse_outer.parse2((*quote).clone()) else {
+				return true;
+			};
+			return !attrs.iter().any(|attr| attr.path().is_ident("serde"));
+		})
+		.cloned()
+		.collect();
+}
+
+/// Fields declared with '?', or with an explicit ``` Option<T> ``` Type, may be missing.
+fn is_optional(field: &StructParameter) -> bool {
+	return field.optional || is_option_type(&field.ty);
+}
+
+/// The Type a header's value is parsed into, without its optional wrapper.
+fn quote_inner_type(field: &StructParameter) -> TokenStream2 {
+	if field.optional {
+		return field.quote_type();
+	}
+	let ty = &field.ty;
+	return match option_inner_type(ty) {
+		Some(inner) => quote!(#inner),
+		None => quote!(#ty),
+	};
+}
+
+/// The field's Type within the Header struct.
+fn quote_field_type(field: &StructParameter) -> TokenStream2 {
+	let ty = field.quote_type();
+	if field.optional {
+		return quote!( ::core::option::Option<#ty> );
+	}
+	return ty;
+}
+
+#[cfg(test)]
+mod header_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::header;
+	
+	#[test] fn header_map_conversions() {
+		let header = generate(r#"
+			struct Header {
+				#[rename = "X-Api-Key"]
+				#[sensitive]
+				api_key: String,
+				request_id: ?u64,
+				page_size: u32 = 10,
+			}
+		"#);
+		assert!(!header.contains("Serialize"));
+		assert!(!header.contains("serde"));
+		assert!(header.contains(&quote!(impl ::core::convert::TryFrom<&::http::header::HeaderMap> for Header).to_string()));
+		assert!(header.contains(&quote!(let value = match headers.get("x-api-key")).to_string()));
+		assert!(header.contains(&quote!(value.unwrap_or_else(|| 10)).to_string()));
+		assert!(header.contains(&quote!(.parse::<u64>()).to_string()));
+		assert!(header.contains(&quote!(value.set_sensitive(true);).to_string()));
+		assert!(header.contains(&quote!(
+			if let ::core::option::Option::Some(value) = &self.request_id
+		).to_string()));
+		assert!(header.contains(&quote!(::http::header::HeaderName::from_static("page-size")).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[rename_all = "kebab-case"] struct Header { id: u64 }"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"struct Header { #[rename = "x api"] id: u64 }"#).is_err());
+	}
+	
+	#[test] fn header_name_constants() {
+		let names = ["x-request-id", "authorization", "x-request-id", "2fa-token"].map(String::from);
+		let module = header::gen_header_names(&names).to_string();
+		assert!(module.contains(&quote!(
+			pub mod headers {
+				pub const X_REQUEST_ID: &str = "x-request-id";
+				pub const AUTHORIZATION: &str = "authorization";
+				pub const _2FA_TOKEN: &str = "2fa-token";
+			}
+		).to_string()));
+	}
+	
+	#[test] fn header_error_display() {
+		// Generated code names every macro through its full path, so a user's own write! can't shadow it.
+		let error = header::gen_header_error().to_string();
+		assert!(error.contains(&quote!(::core::write!(f, "header \"{}\": {}", self.header, self.message)).to_string()));
+	}
 }
@@ -0,0 +1,316 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{parse_quote, Attribute, Item, LitStr, Visibility};
+use crate::attributes::{AttrSlice, MethodAttr};
+use crate::generators::form::FORM_CONTENT_TYPE;
+use crate::parsers::endpoint_method::BodyEncoding;
+use crate::parsers::options::RestOptions;
+
+/// Generates the metadata constants of a REST Method: its ``` PATH ``` and ``` METHOD ```,
+/// so integrations can reference the contract without re-typing strings. Along with the
+/// configuration declared with its ``` #[timeout = ".."] ```, ``` #[retry = ..] ``` and
+/// ``` #[content_type = ".."] ``` Method Attributes. A REST Method sending a Form body
+/// defaults its ``` CONTENT_TYPE ``` to ``` application/x-www-form-urlencoded ```.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `name`: The identifier of the generated REST Method struct.
+/// - `method`: The REST Method, i.e., GET, POST, etc.
+/// - `uri`: The REST Method's URI template.
+/// - `attributes`: The REST Method's attributes.
+/// - `form`: True when the REST Method sends a Form body.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block for the REST Method struct.
+pub fn gen_method_config(
+	vis        : &Visibility,
+	name       : &Ident,
+	method     : &Ident,
+	uri        : &LitStr,
+	attributes : AttrSlice<MethodAttr>,
+	form       : bool,
+) -> TokenStream2 {
+	let method = method.to_string();
+	let declared_content_type = attributes.iter().any(|a| matches!(a, MethodAttr::ContentType(_)));
+	let form_content_type = if form && !declared_content_type {
+		quote! {
+			/// # GENERATED: Content Type
+			/// The content type of this REST Method's Form body.
+			#vis const CONTENT_TYPE: &'static str = #FORM_CONTENT_TYPE;
+		}
+	} else { quote!() };
+	let consts = attributes.filter_map(|attribute| match attribute {
+		MethodAttr::Timeout(millis) => Some(quote! {
+			/// # GENERATED: Timeout
+			/// How long this REST Method's client call may take.
+			#vis const TIMEOUT: ::core::time::Duration = ::core::time::Duration::from_millis(#millis);
+		}),
+		MethodAttr::Retry(retries) => Some(quote! {
+			/// # GENERATED: Retry
+			/// How many times a failed client call of this REST Method is retried.
+			#vis const RETRIES: u32 = #retries;
+		}),
+		MethodAttr::ContentType(content_type) => Some(quote! {
+			/// # GENERATED: Content Type
+			/// The content type of this REST Method's request body.
+			#vis const CONTENT_TYPE: &'static str = #content_type;
+		}),
+		_ => None,
+	}).collect::<Vec<_>>();
+	
+	let output = quote! {
+		impl #name {
+			/// # GENERATED: Path
+			/// This REST Method's URI template.
+			#vis const PATH: &'static str = #uri;
+			/// # GENERATED: Method
+			/// This REST Method, i.e., ``` "GET" ```
+			#vis const METHOD: &'static str = #method;
+			#( #consts )*
+			#form_content_type
+		}
+	};
+	output
+}
+
+/// Generates the error returned by every generated ``` decode_response ```.
+///
+/// ## Returns
+/// `TokenStream2` containing the `DecodeError` definition.
+pub fn gen_decode_error() -> TokenStream2 {
+	let output = quote! {
+		/// # GENERATED: DecodeError
+		/// A response body that couldn't be decoded, either because its Content-Type isn't
+		/// accepted by the REST Method, or because the body doesn't match the Response.
+		#[derive(::core::fmt::Debug)]
+		pub struct DecodeError {
+			/// The response's Content-Type header.
+			pub content_type: ::std::string::String,
+			/// The decoder's error, None when the Content-Type isn't accepted.
+			pub source: ::core::option::Option<::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync>>,
+		}
+		
+		impl ::core::fmt::Display for DecodeError {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				match &self.source {
+					::core::option::Option::Some(source) => ::core::write!(f, "failed to decode a \"{}\" response body: {}", self.content_type, source),
+					::core::option::Option::None => ::core::write!(f, "the response content type \"{}\" isn't accepted", self.content_type),
+				}
+			}
+		}
+		
+		impl ::std::error::Error for DecodeError {
+			fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+				return self.source.as_ref().map(|source| source.as_ref() as &(dyn ::std::error::Error + 'static));
+			}
+		}
+	};
+	output
+}
+
+/// Generates the content negotiation of a REST Method declaring ``` accepts: [..] ```: its
+/// ``` ACCEPT ``` header value, listing every accepted encoding in order of preference, and
+/// ``` decode_response ```, which picks the decoder matching the response's Content-Type.
+/// A response without a Content-Type is decoded with the first accepted encoding.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `name`: The identifier of the generated REST Method struct.
+/// - `accepts`: The accepted encodings, in order of preference.
+/// - `response`: The identifier of the Response struct the body decodes into.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block for the REST Method struct.
+pub fn gen_content_negotiation(
+	vis      : &Visibility,
+	name     : &Ident,
+	accepts  : &[BodyEncoding],
+	response : &Ident,
+) -> TokenStream2 {
+	if accepts.is_empty() {
+		return quote!();
+	}
+	let accept = accepts.iter()
+		.map(BodyEncoding::media_type)
+		.collect::<Vec<_>>()
+		.join(", ");
+	let default = accepts[0].media_type();
+	let shared = RestOptions::shared();
+	let decoders = accepts.iter().map(|encoding| {
+		let (matches, decode) = match encoding {
+			BodyEncoding::Json => {
+				let serde_json = RestOptions::serde_json();
+				(
+					quote!( media_type == "application/json" || media_type.ends_with("+json") ),
+					quote!( #serde_json::from_slice::<#response>(body) ),
+				)
+			}
+			BodyEncoding::MsgPack => {
+				let rmp_serde = RestOptions::rmp_serde();
+				(
+					quote!( ::core::matches!(media_type.as_str(), "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack") ),
+					quote!( #rmp_serde::from_slice::<#response>(body) ),
+				)
+			}
+			BodyEncoding::Xml => {
+				let quick_xml = RestOptions::quick_xml();
+				(
+					quote!( ::core::matches!(media_type.as_str(), "application/xml" | "text/xml") || media_type.ends_with("+xml") ),
+					quote!( #quick_xml::de::from_reader::<_, #response>(body) ),
+				)
+			}
+		};
+		quote! {
+			if #matches {
+				return #decode.map_err(|error| #shared::DecodeError {
+					content_type: content_type.to_string(),
+					source: ::core::option::Option::Some(::std::boxed::Box::new(error)),
+				});
+			}
+		}
+	});
+	
+	let output = quote! {
+		impl #name {
+			/// # GENERATED: Accept
+			/// The Accept header value of this REST Method, in order of preference.
+			#vis const ACCEPT: &'static str = #accept;
+			
+			/// # GENERATED: decode_response
+			/// Decodes a response body with the decoder matching the response's Content-Type
+			/// header, i.e., ``` "application/json; charset=utf-8" ```.
+			///
+			/// # Returns:
+			///   - Ok(response) when successful
+			///   - Err(DecodeError) when the Content-Type isn't accepted, or the body doesn't decode
+			#vis fn decode_response(content_type: &str, body: &[u8]) -> ::core::result::Result<#response, #shared::DecodeError> {
+				let media_type = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+				let media_type = if media_type.is_empty() { #default.to_string() } else { media_type };
+				#( #decoders )*
+				return ::core::result::Result::Err(#shared::DecodeError {
+					content_type: content_type.to_string(),
+					source: ::core::option::Option::None,
+				});
+			}
+		}
+	};
+	output
+}
+
+/// Generates ``` decode_error ``` for a REST Method declaring an Error struct, which decodes
+/// a failed response's body into it. When the REST Method also declares a Response struct
+/// without generics, ``` decode ``` picks between the two by the response's status, so the
+/// Error struct is the Err side of the REST Method's call.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `name`: The identifier of the generated REST Method struct.
+/// - `error`: The identifier of the Error struct the body decodes into.
+/// - `response`: The identifier of the REST Method's Response struct, if it can be decoded into.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block for the REST Method struct.
+pub fn gen_error_decoder(
+	vis      : &Visibility,
+	name     : &Ident,
+	error    : &Ident,
+	response : Option<&Ident>,
+) -> TokenStream2 {
+	let serde_json = RestOptions::serde_json();
+	let decode = response.map(|response| quote! {
+		/// # GENERATED: decode
+		/// Decodes the JSON body of a response by its status: a 2xx status into this REST
+		/// Method's Response struct, and any other status into its Error struct.
+		///
+		/// # Returns:
+		///   - Ok(Ok(response)) when the call succeeded
+		///   - Ok(Err(error)) when the call failed, with the server's error body
+		///   - Err(serde_json::Error) when the body doesn't match the struct its status decodes into
+		#vis fn decode(status: u16, body: &[u8]) -> #serde_json::Result<::core::result::Result<#response, #error>> {
+			if (200..300).contains(&status) {
+				return #serde_json::from_slice::<#response>(body).map(::core::result::Result::Ok);
+			}
+			return #error::from_slice(body).map(::core::result::Result::Err);
+		}
+	});
+	let output = quote! {
+		impl #name {
+			/// # GENERATED: decode_error
+			/// Decodes the JSON body of a failed response, i.e., a 4xx or 5xx status, into
+			/// this REST Method's Error struct.
+			///
+			/// # Returns:
+			///   - Ok(error) when successful
+			///   - Err(serde_json::Error) when the body doesn't match the Error struct
+			#vis fn decode_error(body: &[u8]) -> #serde_json::Result<#error> {
+				return #error::from_slice(body);
+			}
+			#decode
+		}
+	};
+	output
+}
+
+/// Attaches ``` #[cfg(feature = "..")] ``` to every item generated for a REST Method
+/// declared with ``` #[feature = ".."] ```, so the REST Method, along with every Type
+/// declared within it, only compiles when the crate feature is enabled.
+pub fn gen_feature_gate(feature: &LitStr, generated: TokenStream2) -> TokenStream2 {
+	let Ok(mut file) = syn::parse2::<syn::File>(generated.clone()) else {
+		return generated;
+	};
+	let cfg: Attribute = parse_quote!( #[cfg(feature = #feature)] );
+	for item in file.items.iter_mut() {
+		let attrs = match item {
+			Item::Const(item)  => &mut item.attrs,
+			Item::Enum(item)   => &mut item.attrs,
+			Item::Fn(item)     => &mut item.attrs,
+			Item::Impl(item)   => &mut item.attrs,
+			Item::Mod(item)    => &mut item.attrs,
+			Item::Static(item) => &mut item.attrs,
+			Item::Struct(item) => &mut item.attrs,
+			Item::Trait(item)  => &mut item.attrs,
+			Item::Type(item)   => &mut item.attrs,
+			Item::Use(item)    => &mut item.attrs,
+			_ => continue,
+		};
+		attrs.insert(0, cfg.clone());
+	}
+	return quote!( #file );
+}
+
+#[cfg(test)]
+mod method_tests {
+	use quote::quote;
+	use syn::Visibility;
+	
+	#[test] fn content_negotiation() {
+		use crate::generators::method::gen_content_negotiation;
+		use crate::parsers::endpoint_method::BodyEncoding;
+		let parse = |body: &str| syn::parse_str::<crate::parsers::RestEndpoints>(&format!(r#"
+			[User: {{
+				GET "/api/user" => {{ {body} }}
+			}}]
+		"#));
+		let rest = parse("accepts: [msgpack, json] struct Response { name: String }").unwrap();
+		assert_eq!(rest.endpoints[0].methods[0].accepts, vec![BodyEncoding::MsgPack, BodyEncoding::Json]);
+		let negotiation = gen_content_negotiation(
+			&Visibility::Inherited,
+			&quote::format_ident!("UserGET"),
+			&rest.endpoints[0].methods[0].accepts,
+			&quote::format_ident!("Response"),
+		).to_string();
+		assert!(negotiation.contains(&quote!(const ACCEPT: &'static str = "application/msgpack, application/json";).to_string()));
+		assert!(negotiation.contains(&quote!(::rmp_serde::from_slice::<Response>(body)).to_string()));
+		assert!(!negotiation.contains("quick_xml"));
+		for invalid in [
+			"accepts: [json, json] struct Response { name: String }",
+			"accepts: [yaml] struct Response { name: String }",
+			"accepts: [] struct Response { name: String }",
+			"accepts: [json] struct Request { name: String }",
+			"accepts: [json] struct Response<'a> { name: &'a str }",
+		] {
+			assert!(parse(invalid).is_err(), "{invalid}");
+		}
+	}
+}
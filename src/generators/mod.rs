@@ -1,20 +1,40 @@
-use crate::parsers::struct_parameter::StructParameterSlice;
-use crate::attributes::{AttrSlice, CompiledAttrs, ParamAttr, RunCommand, TypeAttr};
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::attributes::{AttrCommands, AttrSlice, CompiledAttrs, Envelope, RunCommand, TypeAttr, ValidateBackend};
 use crate::parsers::rest_enum::EnumsSlice;
+use crate::parsers::options::RestOptions;
+use crate::utils::RestVariant;
 use proc_macro2::TokenStream as TokenStream2;
-use proc_macro2::{Ident, Span};
+use proc_macro2::Ident;
 use quote::quote;
-use syn::Visibility;
-use query::gen_query;
+use syn::{parse_quote, Visibility};
+use query::{gen_query, QueryConfig};
 use header::gen_header;
 use request::gen_request;
-use response::gen_response;
+use response::{collect_extra_field, envelope_meta_field, gen_response, ResponseConfig};
 use reqres::gen_reqres;
+use error::gen_error;
+use form::gen_form;
+use enum_str::{gen_enum_str, has_enum_str};
 pub mod query;
+pub mod raw;
 pub mod header;
 pub mod request;
 pub mod response;
 pub mod reqres;
+pub mod error;
+pub mod form;
+pub mod builder;
+pub mod datetime;
+pub mod enum_str;
+pub mod hal;
+pub mod proto;
+pub mod method;
+pub mod partial;
+pub mod path;
+pub mod routes;
+pub mod support;
+pub mod trace;
+pub mod validate;
 pub mod tools;
 
 /// Generates a Rust Enum based on the provided parameters.
@@ -24,18 +44,43 @@ pub fn gen_endpoint_enums(
 	name  : &Ident,
 	enums : EnumsSlice,
 ) -> TokenStream2 {
-	let enum_fields = enums.quote_fields();
+	// #[repr(..)] Enums are (de)serialized as their discriminant via serde_repr.
+	let is_repr = attrs.iter().any(|a| matches!(a, TypeAttr::Repr(_)));
+	let no_auto_serde = attrs.iter().any(|a| matches!(a, TypeAttr::NoAutoSerde));
+	let enums = enums.with_no_auto_serde(no_auto_serde);
+	let enum_str = if has_enum_str(attrs.iter(), enums.iter()) {
+		gen_enum_str(vis, name, attrs.iter(), enums.iter())
+	} else { quote!() };
+	// A variant marked with #[default] is the Enum's derived Default value.
+	let default = enums.has_default_variant()
+		.then(|| quote!( ::core::default::Default ));
 	let compiled_attrs: CompiledAttrs<TypeAttr> = attrs.into();
 	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let (enum_fields, derives, serde_crate) = if is_repr {(
+		enums.quote_repr_fields(),
+		compiled_attrs.quote_derives(parse_quote!(
+			::core::fmt::Debug, ::serde_repr::Serialize_repr, ::serde_repr::Deserialize_repr, #default
+		)),
+		quote!(),
+	)} else {(
+		enums.quote_fields(),
+		compiled_attrs.quote_derives(parse_quote!(
+			::core::fmt::Debug, #serde::Serialize, #serde::Deserialize, #default
+		)),
+		RestOptions::quote_serde_crate(),
+	)};
 	
 	let output = quote! {
-		#[derive(std::fmt::Debug, serde::Serialize, serde::Deserialize)]
+		#derives
+		#serde_crate
 		#( #quotes )*
 		#vis enum #name {
 			#( #enum_fields )*
 		}
+		#enum_str
 	};
-	output.into()
+	output
 }
 
 pub fn gen_endpoint_structs(
@@ -46,35 +91,327 @@ pub fn gen_endpoint_structs(
 	name    : &Ident,
 	fields: StructParameterSlice,
 ) -> TokenStream2 {
-	let rest_variant = if let Some(variant) = variant {
-		variant
-	} else {
-		ident
+	let rest_variant = match RestVariant::try_from(variant.as_ref().unwrap_or(ident)) {
+		Ok(rest_variant) => rest_variant,
+		Err(err) => return err.to_compile_error(),
+	};
+	let skip_none = attrs.iter().any(|a| matches!(a, TypeAttr::SkipNone));
+	// Mirrors the declared fields only, before any generated field is appended.
+	let partial = if attrs.iter().any(|a| matches!(a, TypeAttr::Partial)) {
+		partial::gen_partial(vis, attrs.iter(), name, &fields)
+	} else { quote!() };
+	let query_config = QueryConfig::from_attrs(attrs.iter());
+	let no_auto_serde = attrs.iter().any(|a| matches!(a, TypeAttr::NoAutoSerde));
+	// A struct without any fields is generated as a unit struct, without any field machinery.
+	let unit = fields.is_empty();
+	let constructor = !unit && attrs.iter().any(|a| matches!(a, TypeAttr::Constructor));
+	let response_config = ResponseConfig::from_attrs(attrs.iter());
+	let xml = attrs.iter().any(|a| matches!(a, TypeAttr::ContentType(content_type) if content_type.value() == "xml"));
+	// Fields generated by Restify itself, appended after the declared fields.
+	let extra_field = attrs.iter()
+		.any(|a| matches!(a, TypeAttr::CollectExtra))
+		.then(collect_extra_field);
+	let hal = attrs.iter().find_map(|a| match a {
+		TypeAttr::Hal(follows) => Some(follows.clone()),
+		_ => None,
+	});
+	let links_field = hal.as_ref().map(|_| hal::hal_links_field());
+	let meta_field = response_config.envelope.as_ref()
+		.and_then(Envelope::meta_field)
+		.map(|meta| envelope_meta_field(&meta));
+	let collected: Vec<StructParameter>;
+	let fields = if extra_field.is_some() || meta_field.is_some() || links_field.is_some() {
+		collected = fields.iter().cloned().chain(extra_field).chain(meta_field).chain(links_field).collect();
+		StructParameterSlice::from(&collected).with_generics(fields.generics())
+	} else { fields };
+	let fields = fields
+		.with_skip_none(skip_none)
+		.with_no_auto_serde(no_auto_serde)
+		.with_default_owner(name)
+		.with_datetime_owner(name);
+	let mut compiled_attrs: CompiledAttrs<TypeAttr> = attrs.into();
+	// #[sensitive] and #[secret] fields are redacted by a manual Debug implementation, since
+	// secrecy only implements Debug for a Secret<T> whose T opts in, i.e., not a Secret<u32>.
+	let debug_impl = if (fields.has_sensitive() || fields.has_secret()) && compiled_attrs.take_derive(parse_quote!(Debug)) {
+		fields.quote_debug_impl(name)
+	} else { quote!() };
+	// #[default] fields are honored by a manual Default implementation.
+	let default_impl = if fields.has_default() {
+		compiled_attrs.take_derive(parse_quote!(Default));
+		let default_fns = fields.quote_default_fns(name);
+		let default_impl = fields.quote_default_impl(name);
+		quote!( #default_fns #default_impl )
+	} else { quote!() };
+	let datetime_fns = fields.quote_datetime_fns(name);
+	// secrecy's Secret<T> implements neither Clone nor PartialEq.
+	if fields.has_secret() {
+		compiled_attrs.take_derive(parse_quote!(Clone));
+		compiled_attrs.take_derive(parse_quote!(PartialEq));
+	}
+	
+	// Validation runs first in the composition order.
+	let rules = compiled_attrs.commands_ref().iter()
+		.filter_map(|cmd| match cmd {
+			AttrCommands::TypeValidate(chain) => Some(chain.actions.iter()),
+			_ => None,
+		})
+		.flatten()
+		.cloned()
+		.collect::<Vec<_>>();
+	let backend = ValidateBackend::from_actions(&rules);
+	let validated = backend == ValidateBackend::Restify && (fields.has_validate() || !rules.is_empty());
+	let log = compiled_attrs.commands_ref().iter().find_map(|cmd| match cmd {
+		AttrCommands::Log(log) => Some(log.clone()),
+		_ => None,
+	});
+	let fields = fields
+		.with_validate_backend(&backend, name)
+		.with_validated(validated)
+		.with_log(log);
+	let validate = match backend {
+		ValidateBackend::Restify if validated => validate::gen_validate(vis, name, &fields, &rules),
+		ValidateBackend::Restify => quote!(),
+		_ => {
+			compiled_attrs.quotes.push(backend.quote_derive());
+			compiled_attrs.quotes.extend(rules.iter().map(|rule| rule.quote_backend_attr(&backend)));
+			fields.quote_backend_regexes()
+		}
+	};
+	
+	let constructor = if constructor {
+		builder::gen_constructor(vis, name, &fields)
+	} else { quote!() };
+	
+	let hal = match &hal {
+		Some(follows) => hal::gen_hal_follows(vis, name, fields.generics(), follows),
+		None => quote!(),
 	};
-	let compiled_attrs: CompiledAttrs<TypeAttr> = attrs.into();
-	let quotes = compiled_attrs.quotes_ref();
 	
+	// Commands are already sorted by CommandStage, so the generated output
+	// follows Restify's composition order.
 	let commands = compiled_attrs.commands.iter().map(|cmd|{
 		match cmd.run_cmd() {
-			RunCommand::Builder(cmd) => {
-				cmd((&vis, &name, &fields))
+			RunCommand::Builder(_) if unit => quote!(),
+			RunCommand::Builder(cmd) | RunCommand::Log(cmd) => {
+				cmd((vis, name, &fields))
 			}
+			RunCommand::Pending(_) | RunCommand::Collected(_) => quote!(),
 		}
 	}).collect::<Vec<TokenStream2>>();
 	
-	let var_ty_n_impl = match rest_variant.to_string().as_str() {
-		"Header"   => gen_header(&vis, compiled_attrs, &name, fields),
-		"Request"  => gen_request(&vis, compiled_attrs, &name, fields),
-		"Response" => gen_response(&vis, compiled_attrs, &name, fields),
-		"Reqres"   => gen_reqres(&vis, compiled_attrs, &name, fields),
-		"Query"    => gen_query(&vis, compiled_attrs, &name, fields),
-		_ => {
-			panic!("Unknown REST Variant Detected: \"{}\"", ident.to_string().as_str())
-		}
+	let var_ty_n_impl = match rest_variant {
+		RestVariant::Header   => gen_header(vis, compiled_attrs, name, fields),
+		RestVariant::Request  => gen_request(vis, compiled_attrs, xml, name, fields),
+		RestVariant::Response => gen_response(vis, compiled_attrs, response_config, name, fields),
+		RestVariant::ReqRes   => gen_reqres(vis, compiled_attrs, xml, name, fields),
+		RestVariant::Error    => gen_error(vis, compiled_attrs, name, fields),
+		RestVariant::Form     => gen_form(vis, compiled_attrs, name, fields),
+		RestVariant::Query    => gen_query(vis, compiled_attrs, query_config, name, fields),
+		RestVariant::Raw      => raw::gen_raw(vis, compiled_attrs, name),
 	};
 	
 	
 	quote!(
 		#var_ty_n_impl
-	).into()
+		#debug_impl
+		#default_impl
+		#datetime_fns
+		#validate
+		#constructor
+		#hal
+		#partial
+		#( #commands )*
+	)
+}
+
+/// Parses a single restify Data Type, and returns its generated code as a String.
+/// A Struct's inline nested structs are generated after it. Shared by every generator's tests.
+#[cfg(test)]
+pub(crate) fn generate(dsl: &str) -> String {
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::parsers::scalars;
+	let vis = Visibility::Inherited;
+	let mut data_type = syn::parse_str::<EndpointDataType>(dsl).unwrap();
+	scalars::resolve(&mut data_type, &scalars::scalar_types());
+	return match data_type {
+		EndpointDataType::Struct(st) => std::iter::once(&st)
+			.chain(st.nested_structs())
+			.map(|st| gen_endpoint_structs(
+				&vis,
+				st.attributes.iter(),
+				&st.name,
+				&st.rest_variant,
+				&st.name,
+				StructParameterSlice::from(&st.parameters).with_generics(&st.generics),
+			))
+			.collect(),
+		EndpointDataType::Enum(en) => gen_endpoint_enums(
+			&vis,
+			en.attributes.iter(),
+			&en.name,
+			(&en.enums).into(),
+		),
+		EndpointDataType::Proto(proto) => proto::gen_proto(&vis, &proto),
+		EndpointDataType::Reference(_) => unreachable!("References aren't generated"),
+	}.to_string();
+}
+
+#[cfg(test)]
+mod generator_tests {
+	use super::*;
+	use proc_macro2::Span;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	
+	#[test] fn doc_comments() {
+		let response = generate(r#"
+			/// A registered user.
+			struct Response {
+				/// The user's unique id.
+				id: u64,
+				name: String,
+			}
+		"#);
+		assert!(response.contains(&quote!(#[doc = " A registered user."] struct Response).to_string()));
+		assert!(response.contains(&quote!(#[doc = " The user's unique id."] id: u64,).to_string()));
+		
+		let status = generate(r#"
+			enum Status {
+				/// Still being processed.
+				Pending,
+				/// Failed with a reason.
+				Failed(String),
+			}
+		"#);
+		assert!(status.contains(&quote!(#[doc = " Still being processed."] Pending,).to_string()));
+		assert!(status.contains(&quote!(#[doc = " Failed with a reason."] Failed(String),).to_string()));
+	}
+	
+	#[test] fn inline_nested_structs() {
+		let response = generate(r#"
+			struct User<Response> {
+				id: u64,
+				home_address: {
+					street: String,
+					geo: ?{ lat: f64, lng: f64 },
+				},
+			}
+		"#);
+		assert!(response.contains(&quote!(home_address: UserHomeAddress,).to_string()));
+		assert!(response.contains(&quote!(struct UserHomeAddress).to_string()));
+		assert!(response.contains(&quote!(geo: ::core::option::Option<UserHomeAddressGeo>,).to_string()));
+		assert!(response.contains(&quote!(struct UserHomeAddressGeo { lat: f64, lng: f64, }).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			enum Event {
+				Moved { address: { street: String } },
+			}
+		"#).is_err());
+	}
+	
+	#[test] fn generic_types() {
+		let page = generate(r#"
+			#[builder]
+			struct Page<T><Response> where T: Clone {
+				items: Vec<T>,
+				total: u64,
+			}
+		"#);
+		assert!(page.contains(&quote!(struct Page<T> where T: Clone { items: Vec<T>, total: u64, }).to_string()));
+		assert!(page.contains(&quote!(
+			struct PageBuilder<T, __items = (), __total = ()> where T: Clone
+		).to_string()));
+		assert!(page.contains(&quote!(
+			impl<T> PageBuilder<T, Vec<T>, u64> where T: Clone
+		).to_string()));
+		assert!(page.contains(&quote!(fn build(self) -> Page<T>).to_string()));
+		
+		let response = generate("struct Response<T> { data: T }");
+		assert!(response.contains(&quote!(struct Response<T> { data: T, }).to_string()));
+		let page = generate("struct Page<Response> { total: u64 }");
+		assert!(page.contains(&quote!(struct Page { total: u64, }).to_string()));
+	}
+	
+	#[test] fn lifetimes() {
+		let user = generate(r#"
+			struct User<Response> {
+				name: &'a str,
+				bio: Cow<'a, str>,
+				nickname: ?&'a str,
+				address: {
+					city: &'b str,
+				},
+			}
+		"#);
+		assert!(user.contains(&quote!(struct User<'a, 'b>).to_string()));
+		assert!(user.contains(&quote!(#[serde(borrow)] bio: Cow<'a, str>,).to_string()));
+		assert!(user.contains(&quote!(#[serde(borrow)] address: UserAddress<'b>,).to_string()));
+		assert!(user.contains(&quote!(struct UserAddress<'b> { city: &'b str, }).to_string()));
+		assert!(!user.contains(&quote!(#[serde(borrow)] name).to_string()));
+		
+		let declared = generate("struct Page<'a><Response> { title: &'a str }");
+		assert!(declared.contains(&quote!(struct Page<'a> { title: &'a str, }).to_string()));
+	}
+	
+	#[test] fn zero_copy() {
+		let user = generate(r#"
+			#[zero_copy]
+			struct User<Response> {
+				name: String,
+				id: u64,
+				address: {
+					city: String,
+				},
+			}
+		"#);
+		assert!(user.contains(&quote!(struct User<'a>).to_string()));
+		assert!(user.contains(&quote!(#[serde(borrow)] name: ::std::borrow::Cow<'a, str>, id: u64,).to_string()));
+		assert!(user.contains(&quote!(#[serde(borrow)] address: UserAddress<'a>,).to_string()));
+		assert!(user.contains(&quote!(struct UserAddress<'a> { #[serde(borrow)] city: ::std::borrow::Cow<'a, str>, }).to_string()));
+		
+		let request = syn::parse_str::<EndpointDataType>("#[zero_copy] struct User<Request> { name: String }");
+		assert!(request.is_err());
+	}
+	
+	#[test] fn unit_structs() {
+		let ack = generate("#[builder] struct Ack<Response>;");
+		assert!(ack.contains(&quote!(struct Ack;).to_string()));
+		assert!(!ack.contains("AckBuilder"));
+		let empty = generate("struct Empty<Request> {}");
+		assert!(empty.contains(&quote!(struct Empty;).to_string()));
+		
+		let generic = syn::parse_str::<EndpointDataType>("struct Ack<T><Response>;");
+		assert!(generic.is_err());
+	}
+	
+	#[test] fn unknown_rest_variant() {
+		let name = Ident::new("User", Span::call_site());
+		let variant = Some(Ident::new("Payload", Span::call_site()));
+		let generated = gen_endpoint_structs(
+			&Visibility::Inherited,
+			AttrSlice::new(&[]),
+			&name,
+			&variant,
+			&name,
+			StructParameterSlice::from(&vec![]),
+		).to_string();
+		assert!(generated.contains("compile_error"));
+		assert!(generated.contains("Payload"));
+	}
+	
+	#[test] fn enum_default_variant() {
+		let status = generate("enum Status { Active, #[default] Pending, }");
+		assert!(status.contains(&quote!(#[derive(::core::fmt::Debug, ::serde::Serialize, ::serde::Deserialize, ::core::default::Default)]).to_string()));
+		assert!(status.contains(&quote!(#[default] Pending,).to_string()));
+		let repr = generate("#[repr(u8)] enum Level { #[default] Low, High }");
+		assert!(repr.contains(&quote!(::serde_repr::Deserialize_repr, ::core::default::Default).to_string()));
+		assert!(repr.contains(&quote!(#[default] Low = 0,).to_string()));
+		assert!(!generate("enum Status { Active, Pending }").contains("Default"));
+		for invalid in [
+			"enum Status { #[default] Active, #[default] Pending }",
+			"enum Event { #[default] Created(u64), Deleted }",
+			"enum Event { #[default] Created { id: u64 }, Deleted }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
 }
@@ -0,0 +1,145 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Visibility};
+use crate::attributes::{AttrSlice, CompiledAttrs, ParamAttr, TypeAttr};
+use crate::generators::tools::quote_struct_body;
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+
+/// The identifier of a struct's generated ``` #[partial] ``` mirror, i.e., ``` UserRequestPatch ```
+pub fn partial_struct_name(name: &Ident) -> Ident {
+	return format_ident!("{}Patch", name);
+}
+
+/// Generates the ``` #[partial] ``` mirror of a struct, i.e., ``` UserRequestPatch ```, where every
+/// field is optional, along with ``` apply() ```, which overwrites a base struct with every field
+/// set within the patch. The common companion Type of a PATCH request.
+///
+/// ## Design Rationale
+/// - A required field ``` T ``` is mirrored as ``` Option<T> ```, skipped while serializing when
+///   it's ``` None ```.
+/// - An optional field ``` ?T ``` is mirrored as a ``` #[patch] ``` field, ``` Option<Option<T>> ```,
+///   so a patch can clear it as well. Unless it's either a ``` #[datetime] ``` or a ``` #[decimal] ```
+///   field, whose serde attributes only handle a single Option, in which case it's mirrored as is.
+/// - Field defaults and ``` #[skip_if] ``` are dropped, since they apply to the unwrapped Type.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
+/// - `attrs`: The struct's Type Attributes, for its derives and ``` #[rename_all] ``` rule.
+/// - `name`: The identifier of the struct being mirrored.
+/// - `fields`: The struct's declared fields.
+///
+/// ## Returns
+/// `TokenStream2` containing the mirror struct and its ``` apply() ``` impl block.
+pub fn gen_partial(
+	vis    : &Visibility,
+	attrs  : AttrSlice<TypeAttr>,
+	name   : &Ident,
+	fields : &StructParameterSlice,
+) -> TokenStream2 {
+	let patch = partial_struct_name(name);
+	let mirrored = fields.iter().map(mirror_field).collect::<Vec<_>>();
+	let mirrored_fields = StructParameterSlice::from(&mirrored)
+		.with_generics(fields.generics())
+		.with_datetime_owner(&patch);
+	let body = quote_struct_body(&mirrored_fields.quote_full_serde(vis));
+	let datetime_fns = mirrored_fields.quote_datetime_fns(&patch);
+	
+	let rename_all = attrs.iter().filter_map(|a| match a {
+		TypeAttr::RenameAll(rule) => Some(quote!( #[serde(rename_all = #rule)] )),
+		_ => None,
+	}).collect::<Vec<_>>();
+	let compiled_attrs: CompiledAttrs<TypeAttr> = attrs.into();
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(
+		::core::fmt::Debug,
+		::core::clone::Clone,
+		::core::default::Default,
+		#serde::Serialize,
+		#serde::Deserialize
+	));
+	let doc = format!("The ``` #[partial] ``` mirror of [{}], where every field is optional.", name);
+	
+	let applied = fields.iter().zip(mirrored.iter()).map(|(field, mirror)| {
+		let name = &field.name;
+		// Optional fields mirrored as is can only be set, not cleared.
+		let value = match field.optional && !mirror.is_patch() {
+			true  => quote!( ::core::option::Option::Some(::core::clone::Clone::clone(value)) ),
+			false => quote!( ::core::clone::Clone::clone(value) ),
+		};
+		quote! {
+			if let ::core::option::Option::Some(value) = &self.#name {
+				base.#name = #value;
+			}
+		}
+	});
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	let output = quote! {
+		#[doc = #doc]
+		#derives
+		#serde_crate
+		#( #rename_all )*
+		#vis struct #patch #generics #where_clause #body
+		
+		impl #impl_generics #patch #ty_generics #where_clause {
+			/// # GENERATED Partial::apply
+			/// apply overwrites every field of base that's set within this patch, leaving
+			/// the rest untouched.
+			#vis fn apply(&self, base: &mut #name #ty_generics) {
+				#( #applied )*
+			}
+		}
+		#datetime_fns
+	};
+	output
+}
+
+/// Mirrors a single field for [gen_partial].
+fn mirror_field(field: &StructParameter) -> StructParameter {
+	let mut mirrored = field.clone();
+	mirrored.default = None;
+	mirrored.attributes.0.retain(|a| !matches!(a, ParamAttr::Default(_) | ParamAttr::SkipIf(_) | ParamAttr::Validate(_)));
+	let custom_serde = field.datetime().is_some() || field.is_decimal();
+	if field.optional && !field.is_patch() && !custom_serde {
+		let ty = &field.ty;
+		mirrored.ty = parse_quote!(::core::option::Option<#ty>);
+		mirrored.attributes.0.push(ParamAttr::Patch);
+	}
+	mirrored.optional = true;
+	return mirrored;
+}
+
+#[cfg(test)]
+mod partial_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn partial_structs() {
+		let request = generate(r#"
+			#[partial]
+			#[rename_all = "camelCase"]
+			struct Request {
+				display_name: String,
+				bio: ?String,
+				age: u8 = 18,
+			}
+		"#);
+		assert!(request.contains(&quote!(#[serde(rename_all = "camelCase")] struct RequestPatch).to_string()));
+		assert!(request.contains(&quote!(display_name: ::core::option::Option<String>,).to_string()));
+		assert!(request.contains(&quote!(bio: ::core::option::Option<::core::option::Option<String> >,).to_string()));
+		assert!(request.contains(&quote!(fn apply(&self, base: &mut Request)).to_string()));
+		assert!(request.contains(&quote!(base.age = ::core::clone::Clone::clone(value);).to_string()));
+		for invalid in [
+			"#[partial] struct Query { page: u32 }",
+			"#[partial] struct Request { #[secret] password: String }",
+			"#[partial] enum Status { Active }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use syn::{LitStr, Visibility};
+use crate::parsers::endpoint_method::UriTemplate;
+use crate::parsers::options::RestOptions;
+
+/// Generates the error returned by every generated Path struct's `FromStr` implementation.
+///
+/// ## Returns
+/// `TokenStream2` containing the `PathParseError` definition.
+pub fn gen_path_error() -> TokenStream2 {
+	let output = quote! {
+		/// # GENERATED: PathParseError
+		/// A concrete path that doesn't match a REST Method's URI.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+		pub struct PathParseError {
+			/// The path that failed to parse.
+			pub path: ::std::string::String,
+			/// The URI the path was parsed against.
+			pub uri: &'static str,
+		}
+		
+		impl ::core::fmt::Display for PathParseError {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				::core::write!(f, "\"{}\" does not match the URI \"{}\"", self.path, self.uri)
+			}
+		}
+		
+		impl ::std::error::Error for PathParseError {}
+	};
+	output
+}
+
+/// Generates the Path struct of a REST Method, holding the path parameters declared within
+/// its URI, i.e., ``` UserGETPath { id } ``` for ``` "/api/user/{id}" ```. `Display` renders
+/// the concrete path and `FromStr` parses a concrete path back into its parameters, so
+/// routes can round-trip. Generates nothing when the URI has no path parameters.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `name`: The identifier of the generated REST Method struct.
+/// - `uri`: The REST Method's URI.
+/// - `template`: The path parameters parsed from **uri**.
+///
+/// ## Returns
+/// `TokenStream2` containing the Path struct with its `Display` and `FromStr` implementations.
+pub fn gen_path_params(
+	vis      : &Visibility,
+	name     : &Ident,
+	uri      : &LitStr,
+	template : &UriTemplate,
+) -> TokenStream2 {
+	if template.is_empty() {
+		return quote!();
+	}
+	let path_name = path_struct_name(name);
+	let shared = RestOptions::shared();
+	let params = &template.params;
+	let (first, literals) = template.literals.split_first()
+		.expect("a UriTemplate always holds one more literal than parameters");
+	
+	// Each parameter runs until the literal that follows it, or until the end of the path.
+	let parse_params = params.iter().zip(literals.iter()).map(|(param, next)| {
+		let split = if next.is_empty() {
+			quote!( let (#param, __rest) = (__rest, ""); )
+		} else {
+			quote!( let (#param, __rest) = __rest.split_once(#next).ok_or_else(__error)?; )
+		};
+		quote! {
+			#split
+			if #param.is_empty() || #param.contains('/') {
+				return ::core::result::Result::Err(__error());
+			}
+		}
+	});
+	
+	let output = quote! {
+		/// # GENERATED: Path
+		/// The path parameters of this REST Method's URI.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+		#vis struct #path_name {
+			#( #vis #params: ::std::string::String, )*
+		}
+		
+		impl ::core::fmt::Display for #path_name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				f.write_str(#first)?;
+				#(
+					f.write_str(&self.#params)?;
+					f.write_str(#literals)?;
+				)*
+				::core::result::Result::Ok(())
+			}
+		}
+		
+		impl ::core::str::FromStr for #path_name {
+			type Err = #shared::PathParseError;
+			fn from_str(__path: &str) -> ::core::result::Result<Self, Self::Err> {
+				let __error = || #shared::PathParseError {
+					path: __path.to_string(),
+					uri: #uri,
+				};
+				let __rest = __path.strip_prefix(#first).ok_or_else(__error)?;
+				#( #parse_params )*
+				if !__rest.is_empty() {
+					return ::core::result::Result::Err(__error());
+				}
+				::core::result::Result::Ok(#path_name {
+					#( #params: #params.to_string(), )*
+				})
+			}
+		}
+	};
+	output
+}
+
+/// The identifier of a REST Method's generated Path struct, i.e., ``` UserGETPath ```
+pub fn path_struct_name(name: &Ident) -> Ident {
+	return format_ident!("{}Path", name);
+}
+
+#[cfg(test)]
+mod path_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::generators::path;
+	
+	#[test] fn path_params() {
+		let method = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			GET "/api/user/{id}/posts/{post}.json?page=1" => {}
+		"#).unwrap();
+		assert_eq!(method.path.literals, vec!["/api/user/", "/posts/", ".json"]);
+		assert_eq!(method.path.params.iter().map(|p| p.to_string()).collect::<Vec<_>>(), vec!["id", "post"]);
+		
+		let name = Ident::new("UserGET", Span::call_site());
+		let path = path::gen_path_params(&Visibility::Inherited, &name, &method.uri, &method.path).to_string();
+		assert!(path.contains(&quote!(struct UserGETPath { id: ::std::string::String, post: ::std::string::String, }).to_string()));
+		assert!(path.contains(&quote!(let (post, __rest) = __rest.split_once(".json").ok_or_else(__error)?;).to_string()));
+		assert!(path.contains(&quote!(impl ::core::str::FromStr for UserGETPath).to_string()));
+		
+		let unparameterized = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"GET "/api/user" => {}"#).unwrap();
+		assert!(path::gen_path_params(&Visibility::Inherited, &name, &unparameterized.uri, &unparameterized.path).is_empty());
+		for invalid in [r#"GET "/api/{1d}" => {}"#, r#"GET "/api/{a}{b}" => {}"#, r#"GET "/api/{id" => {}"#, r#"GET "/api/{id}/{id}" => {}"#] {
+			assert!(syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(invalid).is_err());
+		}
+	}
+}
@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::Visibility;
+use crate::parsers::options::RestOptions;
+use crate::parsers::rest_proto::Proto;
+use crate::utils::RestVariant;
+
+/// Generates the newtype of a ``` proto Response = pb::UserReply; ``` declaration, wrapping
+/// the prost-generated message. Its body is encoded and decoded through ``` prost::Message ```,
+/// instead of serde:
+///   - Request and ReqRes variants generate ``` encode_to_vec ```.
+///   - Response and ReqRes variants generate ``` from_slice ```.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the newtype.
+/// - `proto`: The parsed Proto declaration.
+///
+/// ## Returns
+/// `TokenStream2` containing the newtype, its ``` impl ``` block, and its conversions from and
+/// into the wrapped message.
+pub fn gen_proto(vis: &Visibility, proto: &Proto) -> TokenStream2 {
+	let prost = RestOptions::prost();
+	let name = &proto.name;
+	let message = &proto.message;
+	let variant = RestVariant::try_from(proto.variant()).ok();
+	
+	let encode = if matches!(variant, Some(RestVariant::Request | RestVariant::ReqRes)) {
+		quote! {
+			/// # GENERATED Proto::encode_to_vec
+			/// Encodes the wrapped message into a Protobuf request body.
+			#vis fn encode_to_vec(&self) -> ::std::vec::Vec<u8> {
+				return #prost::Message::encode_to_vec(&self.0);
+			}
+		}
+	} else { quote!() };
+	let decode = if matches!(variant, Some(RestVariant::Response | RestVariant::ReqRes)) {
+		quote! {
+			/// # GENERATED Proto::from_slice
+			/// Decodes a Protobuf response body into the wrapped message.
+			///
+			/// # Returns:
+			///   - Ok(response) when successful
+			///   - Err(prost::DecodeError) when it's not
+			#vis fn from_slice(bytes: &[u8]) -> ::core::result::Result<Self, #prost::DecodeError> {
+				return <#message as #prost::Message>::decode(bytes).map(Self);
+			}
+		}
+	} else { quote!() };
+	let doc = format!("Protobuf {} Variant", proto.variant());
+	
+	let output = quote! {
+		#[doc = #doc]
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::default::Default, ::core::cmp::PartialEq)]
+		#vis struct #name(#vis #message);
+		impl #name {
+			/// The Content-Type of a Protobuf body.
+			#vis const CONTENT_TYPE: &'static str = "application/x-protobuf";
+			
+			#encode
+			#decode
+			
+			/// Unwraps the prost-generated message.
+			#vis fn into_inner(self) -> #message {
+				return self.0;
+			}
+		}
+		impl ::core::convert::From<#message> for #name {
+			fn from(message: #message) -> Self {
+				return Self(message);
+			}
+		}
+		impl ::core::ops::Deref for #name {
+			type Target = #message;
+			fn deref(&self) -> &Self::Target {
+				return &self.0;
+			}
+		}
+		impl ::core::ops::DerefMut for #name {
+			fn deref_mut(&mut self) -> &mut Self::Target {
+				return &mut self.0;
+			}
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod proto_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn proto_bodies() {
+		let response = generate("proto Response = pb::UserReply;");
+		assert!(response.contains(&quote!(struct Response(pb::UserReply);).to_string()));
+		assert!(response.contains(&quote!(<pb::UserReply as ::prost::Message>::decode(bytes).map(Self)).to_string()));
+		assert!(!response.contains("encode_to_vec"));
+		let request = generate("proto CreateUser<Request> = pb::CreateUser;");
+		assert!(request.contains(&quote!(::prost::Message::encode_to_vec(&self.0)).to_string()));
+		assert!(!request.contains("from_slice"));
+		for invalid in [
+			"proto Query = pb::Page;",
+			"proto Response<T> = pb::UserReply;",
+			"#[rename_all = \"camelCase\"] proto Response = pb::UserReply;",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
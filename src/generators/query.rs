@@ -1,48 +1,419 @@
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro2::Ident;
 use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_quote, LitInt, LitStr};
 use syn::Visibility;
-use crate::attributes::{AttrSlice, CompiledAttrs, TypeAttr};
-use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::attributes::{AttrSlice, CompiledAttrs, ParamAttr, QueryEncoding, TypeAttr};
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::parsers::tools::{is_sequence_type, option_inner_type};
+use crate::generators::tools::{quote_struct_body, RestType};
 use crate::utils::doc_str::DocString;
 
+/// # Query Config
+/// How a Query struct's generated ``` to_string ``` renders the query, taken from the
+/// struct's Type Attributes before they're compiled.
+///
+/// # Parameters:
+///   - [Option]<[LitStr]> array_format: See [TypeAttr::ArrayFormat]
+///   - [Option]<[LitStr]> rename_all: See [TypeAttr::RenameAll]. Needed to know the
+///     serialized key of each sequence field.
+///   - [Option]<[QueryEncoding]> encoding: See [TypeAttr::Encode]
+///   - [Option]<[LitInt]> max_depth: See [TypeAttr::MaxDepth]
+///   - [bool] urlencoded: True when declared with ``` #[query_backend = "urlencoded"] ```,
+///     see [TypeAttr::QueryBackend]
+#[derive(Default)]
+pub struct QueryConfig {
+	pub array_format: Option<LitStr>,
+	pub rename_all: Option<LitStr>,
+	pub encoding: Option<QueryEncoding>,
+	pub max_depth: Option<LitInt>,
+	pub urlencoded: bool,
+}
+impl QueryConfig {
+	pub fn from_attrs(attrs: AttrSlice<TypeAttr>) -> Self {
+		let mut config = QueryConfig::default();
+		for attr in attrs {
+			match attr {
+				TypeAttr::ArrayFormat(format) => config.array_format = Some(format.clone()),
+				TypeAttr::RenameAll(rule) => config.rename_all = Some(rule.clone()),
+				TypeAttr::Encode(encoding) => config.encoding = Some(encoding.clone()),
+				TypeAttr::MaxDepth(depth) => config.max_depth = Some(depth.clone()),
+				TypeAttr::QueryBackend(backend) => config.urlencoded = backend.value() == "urlencoded",
+				_ => {}
+			}
+		}
+		return config;
+	}
+}
+
 pub fn gen_query(
 	vis            : &Visibility,
 	compiled_attrs : CompiledAttrs<TypeAttr>,
+	config         : QueryConfig,
 	name           : &Ident,
 	fields         : StructParameterSlice,
 ) -> TokenStream2 {
-	let query_fields = fields.quote_serialize(vis);
-	let query_builders = fields.quote_builder_fn(vis);
+	let query_fields = fields.quote_full_serde(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Both);
+	let body = quote_struct_body(&query_fields);
 	
 	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, #serde::Serialize, #serde::Deserialize));
 	//TODO: iterate over Command Attributes.
 	
 	let _doc = DocString::create()
-		.with_doc(format!("# {}", name.to_string()))
+		.with_doc(format!("# {}", name))
 		.merge(fields.doc_string()).build();
 	
 	
+	let array_format = quote_array_format(config.array_format.as_ref());
+	let from_array_format = quote_from_array_format(&config, &fields);
+	let encoding = quote_encoding(&config);
+	let (serialize, ser_error, deserialize, de_error) = if config.urlencoded {
+		let serde_urlencoded = RestOptions::serde_urlencoded();
+		(
+			quote!( #serde_urlencoded::to_string(&self) ),
+			quote!( #serde_urlencoded::ser::Error ),
+			quote!( #serde_urlencoded::from_str(&query) ),
+			quote!( #serde_urlencoded::de::Error ),
+		)
+	} else {
+		let serde_qs = RestOptions::serde_qs();
+		// serde_qs' non-strict mode also parses percent-encoded brackets, i.e., ``` filter%5Bstatus%5D ```,
+		// as sent by browsers or by a strict #[encode(..)]
+		let max_depth = match &config.max_depth {
+			Some(depth) => quote!( #depth ),
+			None => quote!( 5 ),
+		};
+		(
+			quote!( #serde_qs::to_string(&self) ),
+			quote!( #serde_qs::Error ),
+			quote!( #serde_qs::Config::new(#max_depth, false).deserialize_str(&query) ),
+			quote!( #serde_qs::Error ),
+		)
+	};
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let support = RestOptions::support();
+	
 	let output = quote!{
-		#[derive(std::fmt::Debug, Clone, PartialEq, serde::Serialize)]
+		#derives
+		#serde_crate
 		#( #quotes )*
-		#vis struct #name {
-			#( #query_fields )*
-		}
-		impl #name {
-			#( #query_builders )*
-		 
+		#vis struct #name #generics #where_clause #body
+		#assertions
+		impl #impl_generics #name #ty_generics #where_clause {
  			/// # GENERATED Query::to_string
-		  /// to_string uses serde_qs to serialize your Query struct parameters into
-		  /// a Queryable string to include at the end of your URL.
+		  /// to_string uses serde_qs, or serde_urlencoded, to serialize your Query struct
+		  /// parameters into a Queryable string to include at the end of your URL.
 		  ///
 		  /// # Returns:
 		  ///   - Ok(query_str) when successful
-		  ///   - Err(serde_qs::Error) when it's not
-			#vis fn to_string(&self) -> core::result::Result<String, serde_qs::Error> {
-				serde_qs::to_string(&self)
+		  ///   - Err(serde_qs::Error), or serde_urlencoded's error, when it's not
+			#vis fn to_string(&self) -> ::core::result::Result<::std::string::String, #ser_error> {
+				let query = #serialize?;
+				#array_format
+				#encoding
+				::core::result::Result::Ok(query)
+			}
+			
+			/// # GENERATED Query::from_query_str
+			/// from_query_str parses a query string, as rendered by ``` to_string ```, back into
+			/// your Query struct. So server-side code and tests can parse the same query
+			/// strings the client builds.
+			///
+			/// # Returns:
+			///   - Ok(query) when successful
+			///   - Err(serde_qs::Error), or serde_urlencoded's error, when it's not
+			#vis fn from_query_str(query: &str) -> ::core::result::Result<Self, #de_error>
+			where Self: #serde::de::DeserializeOwned
+			{
+				let query = query.strip_prefix('?').unwrap_or(query);
+				#from_array_format
+				#deserialize
+			}
+			
+			/// # GENERATED Query::to_pairs
+			/// to_pairs serializes your Query struct parameters into decoded key/value pairs,
+			/// for clients that take the query as pairs rather than a pre-rendered string,
+			/// i.e., reqwest's ``` RequestBuilder::query ```. Nested keys keep their brackets,
+			/// i.e., ``` ("ids[0]", "1") ```.
+			///
+			/// # Returns:
+			///   - Ok(pairs) when successful
+			///   - Err(serde_qs::Error), or serde_urlencoded's error, when it's not
+			#vis fn to_pairs(&self) -> ::core::result::Result<::std::vec::Vec<(::std::string::String, ::std::string::String)>, #ser_error> {
+				let query = self.to_string()?;
+				::core::result::Result::Ok(#support::query_pairs(&query))
+			}
+		}
+	};
+	return output;
+}
+
+/// Rewrites serde_qs' indexed sequence keys, i.e., ``` ids[0]=1&ids[1]=2 ```, into the
+/// declared [TypeAttr::ArrayFormat]. Nothing is rewritten without one.
+fn quote_array_format(format: Option<&LitStr>) -> TokenStream2 {
+	let Some(format) = format else {
+		return quote!();
+	};
+	let push_element = match format.value().as_str() {
+		"brackets" => quote!( pairs.push((::std::format!("{base}[]"), value.to_string(), true)); ),
+		"repeat" => quote!( pairs.push((base, value.to_string(), true)); ),
+		// "comma": Consecutive elements of the same sequence are joined into a single pair.
+		_ => quote! {
+			match pairs.last_mut() {
+				::core::option::Option::Some((last, values, true)) if *last == base => {
+					values.push(',');
+					values.push_str(value);
+				}
+				_ => pairs.push((base, value.to_string(), true)),
+			}
+		},
+	};
+	return quote! {
+		let query = {
+			// (key, value, is a sequence element)
+			let mut pairs: ::std::vec::Vec<(::std::string::String, ::std::string::String, bool)> = ::std::vec::Vec::new();
+			for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+				let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+				let key = key.replace("%5B", "[").replace("%5D", "]");
+				let element = key.strip_suffix(']')
+					.and_then(|key| key.rsplit_once('['))
+					.filter(|(_, index)| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+					.map(|(base, _)| base.to_string());
+				match element {
+					::core::option::Option::Some(base) => { #push_element }
+					::core::option::Option::None => pairs.push((key, value.to_string(), false)),
+				}
+			}
+			pairs.iter()
+				.map(|(key, value, _)| ::std::format!("{key}={value}"))
+				.collect::<::std::vec::Vec<_>>()
+				.join("&")
+		};
+	};
+}
+
+/// Re-encodes serde_qs' rendered query with the declared [TypeAttr::Encode]. serde_qs already
+/// encodes spaces as ``` + ```, and leaves brackets and commas as is, so nothing is re-encoded
+/// without either ``` space = "percent" ``` or ``` strict ```.
+fn quote_encoding(config: &QueryConfig) -> TokenStream2 {
+	let Some(encoding) = &config.encoding else {
+		return quote!();
+	};
+	if encoding.space_as_plus() && !encoding.strict {
+		return quote!();
+	}
+	let space = if encoding.space_as_plus() { "+" } else { "%20" };
+	// The separators of a "comma" array format aren't part of any value.
+	let comma = match &config.array_format {
+		Some(format) if format.value() == "comma" => quote!( ',' => encoded.push(c), ),
+		_ => quote!(),
+	};
+	let reserved = if encoding.strict {
+		quote!(
+			c => {
+				let mut bytes = [0; 4];
+				for byte in c.encode_utf8(&mut bytes).bytes() {
+					encoded.push_str(&::std::format!("%{byte:02X}"));
+				}
+			}
+		)
+	} else {
+		quote!( c => encoded.push(c), )
+	};
+	return quote! {
+		let query = {
+			let mut encoded = ::std::string::String::with_capacity(query.len());
+			for c in query.chars() {
+				match c {
+					// serde_qs encodes a literal '+' as %2B, so a '+' is always a space.
+					'+' => encoded.push_str(#space),
+					// Already encoded, or separating the query's pairs.
+					'%' | '&' | '=' => encoded.push(c),
+					#comma
+					c if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~') => encoded.push(c),
+					#reserved
+				}
+			}
+			encoded
+		};
+	};
+}
+
+/// Rewrites a query rendered with a ``` "comma" ``` or ``` "repeat" ``` [TypeAttr::ArrayFormat]
+/// back into serde_qs' indexed sequence keys. serde_qs already parses ``` "brackets" ```.
+/// Only the Query struct's sequence fields are rewritten, i.e., ``` ids=1,2 ``` into
+/// ``` ids[0]=1&ids[1]=2 ```, since a comma may just as well be part of a regular value.
+fn quote_from_array_format(config: &QueryConfig, fields: &StructParameterSlice) -> TokenStream2 {
+	let Some(format) = &config.array_format else {
+		return quote!( let query = query.to_string(); );
+	};
+	let split = match format.value().as_str() {
+		"comma" => quote!( value.split(',') ),
+		"repeat" => quote!( ::core::iter::once(value) ),
+		_ => return quote!( let query = query.to_string(); ),
+	};
+	let sequences = fields.iter()
+		.filter(|field| is_sequence_type(option_inner_type(&field.ty).unwrap_or(&field.ty)))
+		.map(|field| serialized_key(field, config.rename_all.as_ref()))
+		.collect::<Vec<_>>();
+	return quote! {
+		let query = {
+			const SEQUENCES: &[&str] = &[ #( #sequences ),* ];
+			let mut indices: ::std::vec::Vec<(&str, usize)> = ::std::vec::Vec::new();
+			let mut pairs: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+			for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+				let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+				if !SEQUENCES.contains(&key) {
+					pairs.push(pair.to_string());
+					continue;
+				}
+				for value in #split {
+					let index = match indices.iter_mut().find(|(sequence, _)| *sequence == key) {
+						::core::option::Option::Some((_, index)) => { *index += 1; *index }
+						::core::option::Option::None => { indices.push((key, 0)); 0 }
+					};
+					pairs.push(::std::format!("{key}[{index}]={value}"));
+				}
+			}
+			pairs.join("&")
+		};
+	};
+}
+
+/// The key a field is serialized with: its ``` #[rename = ".."] ```, or its name with the
+/// parent struct's ``` #[rename_all = ".."] ``` rule applied.
+fn serialized_key(field: &StructParameter, rename_all: Option<&LitStr>) -> String {
+	let rename = field.attributes.iter().find_map(|a| match a {
+		ParamAttr::Rename(rename) => Some(rename.value()),
+		_ => None,
+	});
+	if let Some(rename) = rename {
+		return rename;
+	}
+	let name = field.name.unraw().to_string();
+	let Some(rule) = rename_all else {
+		return name;
+	};
+	let pascal = || name.split('_')
+		.map(|word| {
+			let mut chars = word.chars();
+			return match chars.next() {
+				Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+				None => String::new(),
+			};
+		})
+		.collect::<String>();
+	return match rule.value().as_str() {
+		"lowercase" => name.to_ascii_lowercase(),
+		"UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+		"PascalCase" => pascal(),
+		"camelCase" => {
+			let pascal = pascal();
+			let mut chars = pascal.chars();
+			match chars.next() {
+				Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+				None => pascal,
 			}
 		}
+		"kebab-case" => name.replace('_', "-"),
+		"SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_ascii_uppercase(),
+		_ => name,
 	};
-	return output.into();
+}
+
+#[cfg(test)]
+mod query_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn query_to_pairs() {
+		let query = generate("struct Query { page: u32, ids: Vec<u64> }");
+		assert!(query.contains(&quote!(
+			fn to_pairs(&self) -> ::core::result::Result<::std::vec::Vec<(::std::string::String, ::std::string::String)>, ::serde_qs::Error>
+		).to_string()));
+		assert!(query.contains(&quote!(let query = self.to_string()?;).to_string()));
+	}
+	
+	#[test] fn query_array_format() {
+		let indexed = generate("struct Query { ids: Vec<u64> }");
+		assert!(!indexed.contains("rsplit_once"));
+		let brackets = generate(r#"#[array_format = "brackets"] struct Query { ids: Vec<u64> }"#);
+		assert!(brackets.contains(&quote!(pairs.push((::std::format!("{base}[]"), value.to_string(), true));).to_string()));
+		let comma = generate(r#"#[array_format = "comma"] struct Query { ids: Vec<u64> }"#);
+		assert!(comma.contains(&quote!(values.push(',');).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[array_format = "pipes"] struct Query { ids: Vec<u64> }"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[array_format = "comma"] struct Request { ids: Vec<u64> }"#).is_err());
+	}
+	
+	#[test] fn query_from_query_str() {
+		let indexed = generate("struct Query { page: u32, ids: Vec<u64> }");
+		assert!(indexed.contains("Deserialize"));
+		assert!(indexed.contains(&quote!(
+			fn from_query_str(query: &str) -> ::core::result::Result<Self, ::serde_qs::Error>
+			where Self: ::serde::de::DeserializeOwned
+		).to_string()));
+		assert!(!indexed.contains("SEQUENCES"));
+		
+		let comma = generate(r#"
+			#[array_format = "comma"]
+			#[rename_all = "camelCase"]
+			struct Query { page: u32, user_ids: Vec<u64>, #[rename = "tag"] tags: ?Vec<String>, pair: [u8; 2] }
+		"#);
+		assert!(comma.contains(&quote!(const SEQUENCES: &[&str] = &["userIds", "tag", "pair"];).to_string()));
+		assert!(comma.contains(&quote!(for value in value.split(',')).to_string()));
+	}
+	
+	#[test] fn query_encoding() {
+		let plus = generate(r#"#[encode(space = "plus")] struct Query { q: String }"#);
+		assert!(!plus.contains(&quote!(let mut encoded = ::std::string::String::with_capacity(query.len());).to_string()));
+		let percent = generate(r#"#[encode(space = "percent")] struct Query { q: String }"#);
+		assert!(percent.contains(&quote!('+' => encoded.push_str("%20"),).to_string()));
+		assert!(percent.contains(&quote!(c => encoded.push(c),).to_string()));
+		let strict = generate(r#"#[array_format = "comma"] #[encode(strict, space = "plus")] struct Query { ids: Vec<u64> }"#);
+		assert!(strict.contains(&quote!('+' => encoded.push_str("+"),).to_string()));
+		assert!(strict.contains(&quote!(',' => encoded.push(c),).to_string()));
+		assert!(strict.contains(&quote!(encoded.push_str(&::std::format!("%{byte:02X}"));).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[encode(space = "tab")] struct Query { q: String }"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[encode(strict)] struct Response { q: String }"#).is_err());
+	}
+	
+	#[test] fn nested_query_structs() {
+		let query = generate(r#"
+			#[max_depth = 3]
+			struct Query { page: u32, filter: { status: ?String, tags: Vec<String> } }
+		"#);
+		assert!(query.contains(&quote!(filter: QueryFilter,).to_string()));
+		assert!(query.contains(&quote!(::serde_qs::Config::new(3, false).deserialize_str(&query)).to_string()));
+		assert!(generate("struct Query { page: u32 }").contains(&quote!(::serde_qs::Config::new(5, false)).to_string()));
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[max_depth = 0] struct Query { page: u32 }"#).is_err());
+	}
+	
+	#[test] fn query_urlencoded_backend() {
+		let query = generate(r#"
+			#[query_backend = "urlencoded"]
+			struct Query { page: u32, search: ?String }
+		"#);
+		assert!(query.contains(&quote!(::serde_urlencoded::to_string(&self)?).to_string()));
+		assert!(query.contains(&quote!(::serde_urlencoded::from_str(&query)).to_string()));
+		assert!(!query.contains(&quote!(::serde_qs::).to_string()));
+		for flat_only in [
+			r#"#[query_backend = "urlencoded"] struct Query { ids: Vec<u32> }"#,
+			r#"#[query_backend = "urlencoded"] struct Query { filter: { status: String } }"#,
+			r#"#[query_backend = "urlencoded"] #[max_depth = 2] struct Query { page: u32 }"#,
+			r#"#[query_backend = "urlencoded"] struct Request { page: u32 }"#,
+			r#"#[query_backend = "form"] struct Query { page: u32 }"#,
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(flat_only).is_err(), "{flat_only}");
+		}
+	}
 }
@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::parse_quote;
+use syn::Visibility;
+use crate::attributes::{CompiledAttrs, TypeAttr};
+
+/// Generates a Raw struct, i.e., ``` struct Avatar<Raw>; ```, for endpoints sending or returning
+/// binary blobs, like images or PDFs, where serde (de)serialization makes no sense.
+///
+/// ## Design Rationale
+/// - The body is passed through untouched, as a ``` Vec<u8> ```, along with the Content-Type it
+///   was sent or received with. Raw structs are always declared as unit structs, since both
+///   fields are generated by Restify.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
+/// - `compiled_attrs`: The struct's compiled attributes, for its derives and doc comments.
+/// - `name`: The identifier of the struct.
+///
+/// ## Returns
+/// `TokenStream2` containing the Raw struct, its ``` impl ``` block, and its conversions into
+/// the raw body.
+pub fn gen_raw(
+	vis            : &Visibility,
+	compiled_attrs : CompiledAttrs<TypeAttr>,
+	name           : &Ident,
+) -> TokenStream2 {
+	let quotes = compiled_attrs.quotes_ref();
+	let derives = compiled_attrs.quote_derives(parse_quote!(
+		::core::fmt::Debug,
+		::core::clone::Clone,
+		::core::default::Default,
+		::core::cmp::PartialEq,
+		::core::cmp::Eq
+	));
+	
+	let output = quote! {
+		#[doc = "Raw Variant"]
+		#derives
+		#( #quotes )*
+		#vis struct #name {
+			/// The Content-Type the body was sent or received with, i.e., ``` "image/png" ```.
+			#vis content_type: ::std::string::String,
+			/// The body, passed through untouched.
+			#vis body: ::std::vec::Vec<u8>,
+		}
+		impl #name {
+			/// # GENERATED Raw::new
+			/// Wraps a raw body, along with its Content-Type.
+			#vis fn new(
+				content_type: impl ::core::convert::Into<::std::string::String>,
+				body: impl ::core::convert::Into<::std::vec::Vec<u8>>,
+			) -> Self {
+				return Self {
+					content_type: content_type.into(),
+					body: body.into(),
+				};
+			}
+			
+			/// Unwraps the raw body.
+			#vis fn into_body(self) -> ::std::vec::Vec<u8> {
+				return self.body;
+			}
+		}
+		impl ::core::convert::AsRef<[u8]> for #name {
+			fn as_ref(&self) -> &[u8] {
+				return &self.body;
+			}
+		}
+		impl ::core::convert::From<#name> for ::std::vec::Vec<u8> {
+			fn from(raw: #name) -> Self {
+				return raw.body;
+			}
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod raw_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn raw_bodies() {
+		let raw = generate("#[derive(Hash)] struct Avatar<Raw>;");
+		assert!(raw.contains(&quote!(content_type: ::std::string::String,).to_string()));
+		assert!(raw.contains(&quote!(body: ::std::vec::Vec<u8>,).to_string()));
+		assert!(raw.contains(&quote!(impl ::core::convert::AsRef<[u8]> for Avatar).to_string()));
+		for invalid in [
+			"struct Raw { body: Vec<u8> }",
+			"#[rename_all = \"camelCase\"] struct Raw;",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
@@ -1,15 +1,18 @@
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
+use syn::parse_quote;
 use syn::Visibility;
-use crate::attributes::{AttrSlice, CompiledAttrs, TypeAttr};
+use crate::attributes::{CompiledAttrs, TypeAttr};
+use crate::parsers::options::RestOptions;
 use crate::parsers::struct_parameter::StructParameterSlice;
-use crate::utils::doc_str::DocString;
+use crate::generators::tools::{quote_struct_body, RestType};
 
 /// Creates a unified struct for both requests and responses in RESTful APIs using the `restify!` macro.
 ///
 /// This function is designed to handle scenarios where the same data structure is used for both
 /// sending requests and receiving responses, implementing both `serde::Serialize` and
-/// `serde::Deserialize` for maximum flexibility.
+/// `serde::Deserialize` for maximum flexibility. Alongside its serde derives, it generates the
+/// constructors of a Response struct, `from_json` and `from_slice`.
 ///
 /// ## Design Rationale
 /// - In many REST API patterns, especially in CRUD operations, the same data model may be used
@@ -18,8 +21,9 @@ use crate::utils::doc_str::DocString;
 ///
 /// ## Parameters
 /// - `vis`: The visibility of the struct, determining its accessibility (`pub`, `pub(crate)`, etc.).
-/// - `rename_all`: A `TokenStream2` to specify field renaming conventions based on serde's attributes,
-///   aligning with typical JSON or XML naming conventions.
+/// - `compiled_attrs`: The struct's compiled Type Attributes, for its derives and serde attributes.
+/// - `xml`: True when declared with ``` #[content_type = "xml"] ```, which generates `to_xml` and
+///   `from_xml` through quick-xml, instead of `from_json` and `from_slice`.
 /// - `name`: The name of the struct, used as the identifier in the generated Rust code.
 /// - `fields`: The collection of fields that define the data structure, parsed from `StructParameterSlice`.
 ///
@@ -29,30 +33,61 @@ use crate::utils::doc_str::DocString;
 pub fn gen_reqres(
 	vis            : &Visibility,
 	compiled_attrs : CompiledAttrs<TypeAttr>,
+	xml            : bool,
 	name           : &Ident,
 	fields         : StructParameterSlice,
 ) -> TokenStream2 {
-	//TODO: Create a query_ser_der or some shit since reqres will implement both.
 	let reqres_fields = fields.quote_full_serde(vis);
-	let reqres_builders = fields.quote_builder_fn(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Both);
+	let body = quote_struct_body(&reqres_fields);
 	
 	let quotes = compiled_attrs.quotes_ref();
-	//TODO: iterate over Command Attributes.
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, #serde::Serialize, #serde::Deserialize));
 	
-	let _doc = DocString::create()
-		.with_doc(format!("# {}", name.to_string()))
-		.merge(fields.doc_string())
-		.build();
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let support = RestOptions::support();
+	let constructors = if xml {
+		quote! {
+			#support::xml_serializer!{ #vis }
+			#support::xml_constructors!{ #vis Self }
+		}
+	} else {
+		quote!( #support::json_constructors!{ #vis Self } )
+	};
 	
 	let output = quote! {
-		#[derive(std::fmt::Debug, Clone, serde::Serialize, serde::Deserialize)]
+		#[doc = "ReqRes Variant"]
+		#derives
+		#serde_crate
 		#( #quotes )*
-		#vis struct #name {
-			#( #reqres_fields )*
-		}
-		impl #name {
-			#( #reqres_builders )*
+		#vis struct #name #generics #where_clause #body
+		#assertions
+		impl #impl_generics #name #ty_generics #where_clause {
+			#constructors
 		}
 	};
-	output.into()
+	output
+}
+
+#[cfg(test)]
+mod reqres_tests {
+	use quote::quote;
+	use crate::generators::generate;
+	
+	#[test] fn reqres_structs() {
+		let reqres = generate("struct User<ReqRes> { id: u64, name: ?String }");
+		assert!(reqres.contains(&quote!(::serde::Serialize, ::serde::Deserialize).to_string()));
+		assert!(reqres.contains(&quote!(
+			#[serde(default)]
+			#[serde(skip_serializing_if = "::core::option::Option::is_none")]
+			name: ::core::option::Option<String>,
+		).to_string()));
+		assert!(reqres.contains(&quote!(__restify::json_constructors!{ Self }).to_string()));
+		let xml = generate(r#"#[content_type = "xml"] struct User<ReqRes> { id: u64 }"#);
+		assert!(xml.contains(&quote!(__restify::xml_serializer!{} __restify::xml_constructors!{ Self }).to_string()));
+		assert!(!xml.contains("json_constructors"));
+	}
 }
@@ -1,9 +1,12 @@
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro2::Ident;
 use quote::quote;
+use syn::parse_quote;
 use syn::Visibility;
-use crate::attributes::{AttrCommands, CompiledAttrs, RunCommand, TypeAttr};
+use crate::attributes::{CompiledAttrs, TypeAttr};
+use crate::parsers::options::RestOptions;
 use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::generators::tools::{quote_struct_body, RestType};
 use crate::utils::doc_str::DocString;
 
 /// Constructs a request struct as part of the `restify!` macro.
@@ -38,25 +41,61 @@ use crate::utils::doc_str::DocString;
 pub fn gen_request(
 	vis            : &Visibility,
 	compiled_attrs : CompiledAttrs<TypeAttr>,
+	xml            : bool,
 	name           : &Ident,
 	fields         : StructParameterSlice,
 ) -> TokenStream2 {
 	let request_fields = fields.quote_serialize(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Serializable);
+	let body = quote_struct_body(&request_fields);
 	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, #serde::Serialize));
 	//TODO: iterate over Command Attributes.
 	
 	let _doc = DocString::create()
-		.with_doc(format!("# {}", name.to_string()))
+		.with_doc(format!("# {}", name))
 		.merge(fields.doc_string())
 		.build();
 	
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let to_xml = if xml {
+		let support = RestOptions::support();
+		quote! {
+			impl #impl_generics #name #ty_generics #where_clause {
+				#support::xml_serializer!{ #vis }
+			}
+		}
+	} else { quote!() };
+	
 	let output = quote! {
 		#[doc = "Request Variant"]
-		#[derive(std::fmt::Debug, Clone, serde::Serialize)]
+		#derives
+		#serde_crate
 		#( #quotes )*
-		#vis struct #name {
-			#( #request_fields )*
-		}
+		#vis struct #name #generics #where_clause #body
+		#assertions
+		#to_xml
 	};
-	output.into()
+	output
+}
+
+#[cfg(test)]
+mod request_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn xml_bodies() {
+		let request = generate(r#"#[content_type = "xml"] struct Request { id: u32 }"#);
+		assert!(request.contains(&quote!(__restify::xml_serializer!{}).to_string()));
+		let response = generate(r#"#[content_type = "xml"] struct Response { id: u32 }"#);
+		assert!(response.contains(&quote!(__restify::xml_constructors!{ Self }).to_string()));
+		assert!(!response.contains("json_constructors"));
+		assert!(!generate("struct Request { id: u32 }").contains("xml_serializer"));
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[content_type = "xml"] struct Query { id: u32 }"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"#[content_type = "yaml"] struct Request { id: u32 }"#).is_err());
+	}
 }
@@ -1,16 +1,49 @@
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::parse_quote;
 use syn::Visibility;
-use crate::attributes::{AttrSlice, CompiledAttrs, TypeAttr};
-use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::attributes::{AttrSlice, CompiledAttrs, Envelope, TypeAttr};
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::generators::tools::{quote_struct_body, RestType};
 use crate::utils::doc_str::DocString;
 
 
+/// # Response Config
+/// The Response-specific attributes of a Response struct, which change how its body is
+/// deserialized.
+///
+/// # Parameters:
+///   - [Option]<[Envelope]> envelope: See [TypeAttr::Envelope]
+///   - [bool] xml: True when declared with ``` #[content_type = "xml"] ```, see [TypeAttr::ContentType]
+///   - [bool] ndjson: True when declared with ``` #[ndjson] ```, see [TypeAttr::Ndjson]
+#[derive(Default)]
+pub struct ResponseConfig {
+	pub envelope: Option<Envelope>,
+	pub xml: bool,
+	pub ndjson: bool,
+}
+impl ResponseConfig {
+	pub fn from_attrs(attrs: AttrSlice<TypeAttr>) -> Self {
+		let mut config = ResponseConfig::default();
+		for attr in attrs {
+			match attr {
+				TypeAttr::Envelope(envelope) => config.envelope = Some(envelope.clone()),
+				TypeAttr::ContentType(content_type) => config.xml = content_type.value() == "xml",
+				TypeAttr::Ndjson => config.ndjson = true,
+				_ => {}
+			}
+		}
+		return config;
+	}
+}
+
 /// Constructs a response struct for REST API endpoints within the `restify!` macro.
 ///
 /// This function generates a Rust struct tailored for handling responses in RESTful services.
 /// It supports automatic implementation of `serde::Deserialize` to seamlessly convert HTTP
-/// response data into strongly typed Rust structures.
+/// response data into strongly typed Rust structures, along with `from_json` and `from_slice`
+/// constructors for callers that already hold the response body.
 ///
 /// ## Design Rationale
 /// - Effective management of API responses enhances reliability and type-safety across
@@ -21,6 +54,10 @@ use crate::utils::doc_str::DocString;
 /// - `vis`: The visibility specifier of the struct (`pub`, `pub(crate)`, etc.).
 /// - `rename_all`: A `TokenStream2` used to apply renaming rules to fields as per serde's
 ///   renaming attributes, ensuring consistency with JSON or XML response formats.
+/// - `config`: The Response's [ResponseConfig]. An envelope generates a `{Name}Envelope` struct,
+///   unwrapped by the generated constructors. ``` #[content_type = "xml"] ``` generates
+///   `from_xml` and `from_slice` through quick-xml, instead of `from_json` and `from_slice`.
+///   ``` #[ndjson] ``` generates `from_ndjson` and `from_ndjson_stream` as well.
 /// - `name`: The identifier of the struct.
 /// - `fields`: A slice of `StructParameter` defining the structure of the response data.
 ///
@@ -30,32 +67,359 @@ use crate::utils::doc_str::DocString;
 pub fn gen_response(
 	vis            : &Visibility,
 	compiled_attrs : CompiledAttrs<TypeAttr>,
+	config         : ResponseConfig,
 	name           : &Ident,
 	fields         : StructParameterSlice,
 ) -> TokenStream2 {
 	let response_fields = fields.quote_deserialize(vis);
-	let response_builders = fields.quote_builder_fn(vis);
+	let assertions = fields.quote_serde_assertions(RestType::Deserializable);
+	let body = quote_struct_body(&response_fields);
 	
 	let quotes = compiled_attrs.quotes_ref();
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let derives = compiled_attrs.quote_derives(parse_quote!(::core::fmt::Debug, ::core::clone::Clone, #serde::Deserialize));
 	//TODO: iterate over Command Attributes.
 	
 	let _doc = DocString::create()
-		.with_doc(format!("# {}", name.to_string()))
+		.with_doc(format!("# {}", name))
 		.merge(fields.doc_string())
 		.build();
 	
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	
+	// Without an envelope, the response body deserializes straight into Self.
+	let (envelope, deserialized, unwrap) = match &config.envelope {
+		Some(envelope) => {
+			let envelope_name = envelope_struct_name(name);
+			(
+				gen_envelope(vis, envelope, name, &envelope_name, &fields),
+				quote!( #envelope_name #ty_generics ),
+				quote!( .map(#envelope_name::into_inner) ),
+			)
+		}
+		None => (quote!(), quote!(Self), quote!()),
+	};
+	let support = RestOptions::support();
+	let unwrap_path = config.envelope.as_ref().map(|_| {
+		let envelope_name = envelope_struct_name(name);
+		quote!( => #envelope_name::into_inner )
+	});
+	let constructors = if config.xml {
+		quote!( #support::xml_constructors!{ #vis #deserialized #unwrap_path } )
+	} else {
+		quote!( #support::json_constructors!{ #vis #deserialized #unwrap_path } )
+	};
+	
+	let ndjson = if config.ndjson {
+		gen_ndjson_decoders(vis, &deserialized, &unwrap)
+	} else { quote!() };
+	
 	let output = quote! {
 		#[doc = "Response Variant"]
-		#[derive(std::fmt::Debug, Clone, serde::Deserialize)]
+		#derives
+		#serde_crate
 		#( #quotes )*
-		#vis struct #name {
-			#( #response_fields )*
+		#vis struct #name #generics #where_clause #body
+		#assertions
+		impl #impl_generics #name #ty_generics #where_clause {
+			#constructors
+			#ndjson
+		}
+		#envelope
+	};
+	output
+}
+
+/// Generates the NDJSON decoders of a ``` #[ndjson] ``` Response struct: ``` from_ndjson ```,
+/// over a body already held in memory, and ``` from_ndjson_stream ```, over a body streamed in
+/// chunks. Both decode one line at a time, skipping blank lines, and unwrap every line's
+/// #[envelope], if declared.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the Response struct.
+/// - `deserialized`: The Type every line deserializes into, either Self or its envelope.
+/// - `unwrap`: Unwraps the envelope of a deserialized line, if declared.
+///
+/// ## Returns
+/// `TokenStream2` containing both methods, for the Response struct's ``` impl ``` block.
+fn gen_ndjson_decoders(
+	vis          : &Visibility,
+	deserialized : &TokenStream2,
+	unwrap       : &TokenStream2,
+) -> TokenStream2 {
+	let serde = RestOptions::serde();
+	let serde_json = RestOptions::serde_json();
+	let futures = RestOptions::futures();
+	let shared = RestOptions::shared();
+	
+	let output = quote! {
+		/// # GENERATED Response::from_ndjson
+		/// from_ndjson decodes a newline-delimited JSON body, one Response per line.
+		/// Blank lines are skipped.
+		#vis fn from_ndjson(body: &str) -> impl ::core::iter::Iterator<Item = #serde_json::Result<Self>> + '_
+		where #deserialized: #serde::de::DeserializeOwned
+		{
+			return body.lines()
+				.filter(|line| !line.trim().is_empty())
+				.map(|line| #serde_json::from_str::<#deserialized>(line)#unwrap);
 		}
 		
-		impl #name {
-			#( #response_builders )*
+		/// # GENERATED Response::from_ndjson_stream
+		/// from_ndjson_stream decodes a newline-delimited JSON body streamed in chunks, i.e.,
+		/// an HTTP client's ``` bytes_stream() ```, yielding a Response for every complete line.
+		/// The body isn't polled any further once it fails.
+		///
+		/// # Yields:
+		///   - Ok(response) for every decoded line
+		///   - Err(NdjsonError::Body(error)) when the body fails
+		///   - Err(NdjsonError::Line { line, source }) when a line doesn't decode
+		#vis fn from_ndjson_stream<__S, __B, __E>(body: __S) -> impl #futures::Stream<Item = ::core::result::Result<Self, #shared::NdjsonError<__E>>>
+		where
+			__S: #futures::Stream<Item = ::core::result::Result<__B, __E>>,
+			__B: ::core::convert::AsRef<[u8]>,
+			#deserialized: #serde::de::DeserializeOwned,
+		{
+			struct NdjsonState<__S> {
+				body: ::core::pin::Pin<::std::boxed::Box<__S>>,
+				buffer: ::std::vec::Vec<u8>,
+				line: usize,
+				done: bool,
+			}
+			let state = NdjsonState {
+				body: ::std::boxed::Box::pin(body),
+				buffer: ::std::vec::Vec::new(),
+				line: 0,
+				done: false,
+			};
+			return #futures::stream::unfold(state, |mut state| async move {
+				loop {
+					let end = state.buffer.iter().position(|byte| *byte == b'\n');
+					if end.is_none() && !state.done {
+						match #futures::StreamExt::next(&mut state.body).await {
+							::core::option::Option::Some(::core::result::Result::Ok(chunk)) => state.buffer.extend_from_slice(chunk.as_ref()),
+							::core::option::Option::Some(::core::result::Result::Err(error)) => {
+								state.done = true;
+								state.buffer.clear();
+								return ::core::option::Option::Some((::core::result::Result::Err(#shared::NdjsonError::Body(error)), state));
+							}
+							::core::option::Option::None => state.done = true,
+						}
+						continue;
+					}
+					// The last line doesn't need a trailing newline.
+					let line = match end {
+						::core::option::Option::Some(end) => state.buffer.drain(..=end).collect::<::std::vec::Vec<u8>>(),
+						::core::option::Option::None => ::core::mem::take(&mut state.buffer),
+					};
+					state.line += 1;
+					if line.trim_ascii().is_empty() {
+						if end.is_none() {
+							return ::core::option::Option::None;
+						}
+						continue;
+					}
+					let line_number = state.line;
+					let response = #serde_json::from_slice::<#deserialized>(line.trim_ascii())#unwrap
+						.map_err(|source| #shared::NdjsonError::Line { line: line_number, source });
+					return ::core::option::Option::Some((response, state));
+				}
+			});
 		}
 	};
-	output.into()
+	output
 }
 
+/// Generates the error yielded by every generated ``` from_ndjson_stream ```.
+///
+/// ## Returns
+/// `TokenStream2` containing the `NdjsonError` definition.
+pub fn gen_ndjson_error() -> TokenStream2 {
+	let serde_json = RestOptions::serde_json();
+	let output = quote! {
+		/// # GENERATED: NdjsonError
+		/// A newline-delimited JSON body that couldn't be decoded.
+		#[derive(::core::fmt::Debug)]
+		pub enum NdjsonError<E> {
+			/// The streamed body failed.
+			Body(E),
+			/// A line didn't decode, counting from 1.
+			Line {
+				line: usize,
+				source: #serde_json::Error,
+			},
+		}
+		
+		impl<E: ::core::fmt::Display> ::core::fmt::Display for NdjsonError<E> {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				match self {
+					NdjsonError::Body(error) => ::core::write!(f, "failed to read the NDJSON body: {}", error),
+					NdjsonError::Line { line, source } => ::core::write!(f, "failed to decode NDJSON line {}: {}", line, source),
+				}
+			}
+		}
+		
+		impl<E: ::std::error::Error + 'static> ::std::error::Error for NdjsonError<E> {
+			fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+				return match self {
+					NdjsonError::Body(error) => ::core::option::Option::Some(error),
+					NdjsonError::Line { source, .. } => ::core::option::Option::Some(source),
+				};
+			}
+		}
+	};
+	output
+}
+
+/// The field generated by ``` #[collect_extra] ```, holding every field of the response body
+/// the parent Response struct doesn't declare.
+pub fn collect_extra_field() -> StructParameter {
+	let serde_json = RestOptions::serde_json();
+	return parse_quote!(
+		#[flatten]
+		extra: ::std::collections::HashMap<::std::string::String, #serde_json::Value>
+	);
+}
+
+/// The field generated by ``` #[envelope(.., meta = "..")] ```, holding the envelope's meta key.
+/// Filled in while unwrapping the envelope, rather than by the payload itself.
+pub fn envelope_meta_field(meta: &Ident) -> StructParameter {
+	let serde_json = RestOptions::serde_json();
+	return parse_quote!(
+		#[skip_deserialize]
+		#meta: ?#serde_json::Value
+	);
+}
+
+/// Generates the envelope wrapping a Response struct's payload, i.e., ``` UserResponseEnvelope ```
+/// for ``` #[envelope("data", meta = "meta")] ```. Also usable by clients deserializing the
+/// response body themselves, through ``` into_inner ```.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the Response struct.
+/// - `envelope`: The envelope's payload and meta keys.
+/// - `name`: The identifier of the Response struct.
+/// - `envelope_name`: The identifier of the generated envelope.
+/// - `fields`: The fields of the Response struct, for its generic parameters.
+///
+/// ## Returns
+/// `TokenStream2` containing the envelope struct and its ``` into_inner ``` method.
+fn gen_envelope(
+	vis           : &Visibility,
+	envelope      : &Envelope,
+	name          : &Ident,
+	envelope_name : &Ident,
+	fields        : &StructParameterSlice,
+) -> TokenStream2 {
+	let serde = RestOptions::serde();
+	let serde_crate = RestOptions::quote_serde_crate();
+	let serde_json = RestOptions::serde_json();
+	let generics = fields.generics();
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let key = &envelope.key;
+	// A borrowing payload only borrows from the body when told to.
+	let borrow = if generics.lifetimes().next().is_some() {
+		quote!( #[serde(borrow)] )
+	} else { quote!() };
+	
+	let (meta, into_inner) = match (&envelope.meta, envelope.meta_field()) {
+		(Some(meta_key), Some(meta)) => (
+			quote!(
+				#[serde(rename = #meta_key, default)]
+				#vis #meta: ::core::option::Option<#serde_json::Value>,
+			),
+			quote!(
+				let mut data = self.data;
+				data.#meta = self.#meta;
+				return data;
+			),
+		),
+		_ => (quote!(), quote!( return self.data; )),
+	};
+	
+	let output = quote! {
+		/// # GENERATED: Envelope
+		/// The envelope wrapping this Response's payload.
+		#[derive(#serde::Deserialize)]
+		#serde_crate
+		#vis struct #envelope_name #generics #where_clause {
+			#[serde(rename = #key)]
+			#borrow
+			#vis data: #name #ty_generics,
+			#meta
+		}
+		impl #impl_generics #envelope_name #ty_generics #where_clause {
+			/// Unwraps the payload, along with the captured meta key, if declared.
+			#vis fn into_inner(self) -> #name #ty_generics {
+				#into_inner
+			}
+		}
+	};
+	output
+}
+
+/// The identifier of a Response struct's generated envelope, i.e., ``` UserResponseEnvelope ```
+pub fn envelope_struct_name(name: &Ident) -> Ident {
+	return format_ident!("{}Envelope", name);
+}
+
+#[cfg(test)]
+mod response_tests {
+	use quote::quote;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn response_constructors() {
+		let response = generate("struct Response { id: u32 }");
+		assert!(response.contains(&quote!(__restify::json_constructors!{ Self }).to_string()));
+		let borrowed = generate("#[zero_copy] struct Response { name: String }");
+		assert!(borrowed.contains(&quote!(impl<'a> Response<'a>).to_string()));
+		assert!(!generate("struct Request { id: u32 }").contains("from_json"));
+	}
+	
+	#[test] fn collect_extra_fields() {
+		let response = generate("#[collect_extra] struct Response { id: u32 }");
+		assert!(response.contains(&quote!(
+			#[serde(flatten)]
+			extra: ::std::collections::HashMap<::std::string::String, ::serde_json::Value>,
+		).to_string()));
+		for invalid in [
+			"#[collect_extra] struct Request { id: u32 }",
+			"#[collect_extra] #[deny_unknown_fields] struct Response { id: u32 }",
+			"#[collect_extra] struct Response { extra: u32 }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+	
+	#[test] fn response_envelope() {
+		let response = generate(r#"#[envelope = "data"] struct Response { id: u32 }"#);
+		assert!(response.contains(&quote!(#[serde(rename = "data")] data: Response,).to_string()));
+		assert!(response.contains(&quote!(__restify::json_constructors!{ ResponseEnvelope => ResponseEnvelope::into_inner }).to_string()));
+		let meta = generate(r#"#[envelope("data", meta = "meta")] struct Response { id: u32 }"#);
+		assert!(meta.contains(&quote!(#[serde(skip_deserializing)]).to_string()));
+		assert!(meta.contains(&quote!(data.meta = self.meta;).to_string()));
+		for invalid in [
+			r#"#[envelope = "data"] struct Request { id: u32 }"#,
+			r#"#[envelope("data", meta = "meta")] struct Response { meta: u32 }"#,
+			r#"#[envelope("data", page = "meta")] struct Response { id: u32 }"#,
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+	
+	#[test] fn ndjson_responses() {
+		let response = generate("#[ndjson] struct Response { id: u32 }");
+		assert!(response.contains(&quote!(fn from_ndjson(body: &str)).to_string()));
+		assert!(response.contains(&quote!(__S: ::futures::Stream<Item = ::core::result::Result<__B, __E>>,).to_string()));
+		assert!(!generate("struct Response { id: u32 }").contains("from_ndjson"));
+		for invalid in [
+			"#[ndjson] struct Request { id: u32 }",
+			"#[ndjson] #[zero_copy] struct Response { name: String }",
+			"#[ndjson] struct Response { name: &'a str }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
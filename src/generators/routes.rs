@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::LitStr;
+
+/// # Route
+/// A single REST Method of an Endpoint, as listed within the generated ``` Routes ``` enum.
+///
+/// # Parameters:
+///   - [Ident] name: The name of the REST Method's generated struct, used as the variant.
+///   - [Ident] method: The REST Method, i.e., GET, POST, etc.
+///   - [LitStr] uri: The REST Method's URI template, i.e., ``` "/api/user/{id}" ```
+///   - [Option]<[LitStr]> feature: The crate feature the REST Method is gated behind.
+pub struct Route {
+	pub name    : Ident,
+	pub method  : Ident,
+	pub uri     : LitStr,
+	pub feature : Option<LitStr>,
+}
+
+/// Generates the ``` Routes ``` enum, with one variant per REST Method of every Endpoint,
+/// along with ``` method() ``` and ``` path() ``` accessors. Useful for logging, metrics
+/// labels, and reusing the routes server-side.
+///
+/// ## Parameters
+/// - `name`: The name of the enum, see [RestOptions::routes_name](crate::parsers::options::RestOptions::routes_name).
+/// - `routes`: Every REST Method of every Endpoint, in declaration order.
+///
+/// ## Returns
+/// `TokenStream2` containing the ``` Routes ``` enum and its ``` impl ``` block, or
+/// nothing when no REST Method was declared.
+pub fn gen_routes(name: &Ident, routes: &[Route]) -> TokenStream2 {
+	if routes.is_empty() {
+		return quote!();
+	}
+	let cfgs = routes.iter().map(|route| match &route.feature {
+		Some(feature) => quote!( #[cfg(feature = #feature)] ),
+		None => quote!(),
+	}).collect::<Vec<_>>();
+	let variants = routes.iter().map(|route| &route.name).collect::<Vec<_>>();
+	let methods = routes.iter().map(|route| route.method.to_string());
+	let uris = routes.iter().map(|route| &route.uri);
+	
+	let output = quote! {
+		/// # GENERATED: Routes
+		/// Every REST Method of every Endpoint, along with its REST Method and URI template.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+		pub enum #name {
+			#( #cfgs #variants, )*
+		}
+		impl #name {
+			/// # GENERATED: Routes::method
+			/// Returns the route's REST Method, i.e., ``` "GET" ```
+			pub fn method(&self) -> &'static str {
+				return match self {
+					#( #cfgs #name::#variants => #methods, )*
+				};
+			}
+			
+			/// # GENERATED: Routes::path
+			/// Returns the route's URI template, i.e., ``` "/api/user/{id}" ```
+			pub fn path(&self) -> &'static str {
+				return match self {
+					#( #cfgs #name::#variants => #uris, )*
+				};
+			}
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod routes_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use crate::parsers::options::RestOptions;
+	use crate::generators::routes;
+	
+	#[test] fn routes_enum() {
+		let route = |name: &str, method: &str, uri: &str, feature: Option<&str>| routes::Route {
+			name: Ident::new(name, Span::call_site()),
+			method: Ident::new(method, Span::call_site()),
+			uri: syn::LitStr::new(uri, Span::call_site()),
+			feature: feature.map(|feature| syn::LitStr::new(feature, Span::call_site())),
+		};
+		let name = Ident::new("Routes", Span::call_site());
+		let routes = routes::gen_routes(&name, &[
+			route("UserGET", "GET", "/api/user/{id}", None),
+			route("AdminDELETE", "DELETE", "/api/admin/{id}", Some("admin")),
+		]).to_string();
+		assert!(routes.contains(&quote!(
+			pub enum Routes { UserGET, #[cfg(feature = "admin")] AdminDELETE, }
+		).to_string()));
+		assert!(routes.contains(&quote!(Routes::UserGET => "GET",).to_string()));
+		assert!(routes.contains(&quote!(#[cfg(feature = "admin")] Routes::AdminDELETE => "/api/admin/{id}",).to_string()));
+		assert!(routes::gen_routes(&name, &[]).is_empty());
+		
+		let options = syn::parse_str::<RestOptions>(r#"#[rest:routes = "UserRoutes"]"#).unwrap();
+		assert_eq!(options.routes_name(), "UserRoutes");
+		assert_eq!(RestOptions::default().routes_name(), "Routes");
+		assert!(syn::parse_str::<RestOptions>(r#"#[rest:routes = "User Routes"]"#).is_err());
+	}
+}
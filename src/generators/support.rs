@@ -0,0 +1,261 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use crate::parsers::options::RestOptions;
+
+/// Generates the runtime-support module shared by every generated Type, named after the
+/// invocation, i.e., ``` mod __restify_user ```, see [RestOptions::support].
+///
+/// This is emitted once per `restify!` invocation, so several invocations can share a single
+/// module. Helpers that would otherwise be repeated within every generated Type are emitted
+/// here instead, and called through ``` __restify_user::.. ```:
+///   - **assert_serialize** and **assert_deserialize**: The serde assertions of every struct,
+///     see [StructParameterSlice::quote_serde_assertions](crate::parsers::struct_parameter::StructParameterSlice::quote_serde_assertions)
+///   - **query_pairs**: Splits a rendered query string into its decoded key/value pairs, for
+///     every Query struct's ``` to_pairs ```.
+///   - **expose_secret** and **expose_optional_secret**: The serializers of every
+///     ``` #[secret] ``` field within a Request struct.
+///   - **json_constructors!**, **xml_constructors!** and **xml_serializer!**: The documented
+///     ``` from_json ```/``` from_slice ```, ``` from_xml ```/``` from_slice ``` and ``` to_xml ```
+///     methods of every Response, ReqRes, Error and Request struct.
+///   - **builder_fn!**: The documented ``` builder() ``` of every ``` #[builder] ``` struct.
+///   - **shared**: The Types shared by every generated Type, i.e., ``` PathParseError ```.
+///     They're glob re-exported onto the invocation's root, so a single invocation's can be
+///     named directly, while generated code always names them through their full path.
+///
+/// ## Parameters
+/// - `query_pairs`: True if the invocation declares a Query struct.
+/// - `secrets`: True if the invocation declares a ``` #[secret] ``` field, which needs **secrecy**.
+/// - `shared`: The Types shared by every generated Type.
+///
+/// ## Returns
+/// `TokenStream2` containing the runtime-support module, and the re-export of its shared Types.
+pub fn gen_runtime_support(query_pairs: bool, secrets: bool, shared: TokenStream2) -> TokenStream2 {
+	let support = RestOptions::support();
+	let serde = RestOptions::serde();
+	let query_pairs = if query_pairs {
+		quote! {
+			pub fn query_pairs(query: &str) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+				fn decode(encoded: &str) -> ::std::string::String {
+					let mut bytes = ::std::vec::Vec::with_capacity(encoded.len());
+					let mut encoded = encoded.bytes();
+					while let ::core::option::Option::Some(byte) = encoded.next() {
+						let hex = match byte {
+							b'+' => { bytes.push(b' '); continue; }
+							b'%' => encoded.clone().take(2).collect::<::std::vec::Vec<u8>>(),
+							byte => { bytes.push(byte); continue; }
+						};
+						let decoded = ::core::str::from_utf8(&hex).ok()
+							.filter(|hex| hex.len() == 2)
+							.and_then(|hex| u8::from_str_radix(hex, 16).ok());
+						match decoded {
+							::core::option::Option::Some(decoded) => {
+								bytes.push(decoded);
+								encoded.nth(1);
+							}
+							::core::option::Option::None => bytes.push(byte),
+						}
+					}
+					::std::string::String::from_utf8_lossy(&bytes).into_owned()
+				}
+				query.split('&')
+					.filter(|pair| !pair.is_empty())
+					.map(|pair| match pair.split_once('=') {
+						::core::option::Option::Some((key, value)) => (decode(key), decode(value)),
+						::core::option::Option::None => (decode(pair), ::std::string::String::new()),
+					})
+					.collect()
+			}
+		}
+	} else { quote!() };
+	
+	// Named apart from the user's own Types, which are glob imported.
+	let secrets = if secrets {
+		quote! {
+			pub fn expose_secret<RestifySecret, RestifySerializer>(
+				secret: &::secrecy::Secret<RestifySecret>,
+				serializer: RestifySerializer,
+			) -> ::core::result::Result<RestifySerializer::Ok, RestifySerializer::Error>
+			where
+				RestifySecret: ::secrecy::zeroize::Zeroize + #serde::Serialize,
+				RestifySerializer: #serde::Serializer,
+			{
+				#serde::Serialize::serialize(::secrecy::ExposeSecret::expose_secret(secret), serializer)
+			}
+			
+			pub fn expose_optional_secret<RestifySecret, RestifySerializer>(
+				secret: &::core::option::Option<::secrecy::Secret<RestifySecret>>,
+				serializer: RestifySerializer,
+			) -> ::core::result::Result<RestifySerializer::Ok, RestifySerializer::Error>
+			where
+				RestifySecret: ::secrecy::zeroize::Zeroize + #serde::Serialize,
+				RestifySerializer: #serde::Serializer,
+			{
+				#serde::Serialize::serialize(
+					&secret.as_ref().map(::secrecy::ExposeSecret::expose_secret),
+					serializer,
+				)
+			}
+		}
+	} else { quote!() };
+	
+	let constructors = gen_constructor_macros();
+	let reexport = if shared.is_empty() {
+		quote!()
+	} else {
+		quote!( pub use #support::shared::*; )
+	};
+	let output = quote! {
+		/// # GENERATED: Runtime Support
+		/// Helpers shared by every Type generated by this `restify!` invocation.
+		#[doc(hidden)]
+		#[allow(unused)]
+		mod #support {
+			// A serde path declared through #[rest:serde_path = ".."] resolves as it does outside.
+			use super::*;
+			
+			pub fn assert_serialize<T: ?Sized + #serde::Serialize>() {}
+			
+			pub fn assert_deserialize<T: #serde::de::DeserializeOwned>() {}
+			
+			#query_pairs
+			#secrets
+			#constructors
+			
+			pub mod shared {
+				use super::*;
+				#shared
+			}
+		}
+		#reexport
+	};
+	return output;
+}
+
+/// Generates the macros expanding into the documented methods every generated Type of a
+/// kind shares, so their bodies and doc blocks are only emitted once per invocation:
+///   - ``` json_constructors!(vis Deserialized => unwrap) ```: ``` from_json ``` and ``` from_slice ```,
+///     deserializing ``` Deserialized ```, then mapping it with the optional ``` unwrap ```.
+///   - ``` xml_constructors!(vis Deserialized => unwrap) ```: ``` from_xml ``` and ``` from_slice ```.
+///   - ``` xml_serializer!(vis) ```: ``` to_xml ```.
+///   - ``` builder_fn!(vis Builder) ```: ``` builder() ```, starting an unset ``` Builder ```.
+fn gen_constructor_macros() -> TokenStream2 {
+	let serde = RestOptions::serde();
+	let serde_json = RestOptions::serde_json();
+	let quick_xml = RestOptions::quick_xml();
+	return quote! {
+		macro_rules! json_constructors {
+			($vis:vis $deserialized:ty $(=> $unwrap:path)?) => {
+				/// # GENERATED from_json
+				/// from_json deserializes a JSON body into Self, unwrapping its #[envelope]
+				/// first, if declared.
+				///
+				/// # Returns:
+				///   - Ok(Self) when successful
+				///   - Err(serde_json::Error) when it's not
+				$vis fn from_json<'__de>(json: &'__de str) -> #serde_json::Result<Self>
+				where $deserialized: #serde::Deserialize<'__de>
+				{
+					#serde_json::from_str::<$deserialized>(json) $(.map($unwrap))?
+				}
+				
+				/// # GENERATED from_slice
+				/// from_slice deserializes a JSON body, as raw bytes, into Self, unwrapping its
+				/// #[envelope] first, if declared.
+				///
+				/// # Returns:
+				///   - Ok(Self) when successful
+				///   - Err(serde_json::Error) when it's not
+				$vis fn from_slice<'__de>(bytes: &'__de [u8]) -> #serde_json::Result<Self>
+				where $deserialized: #serde::Deserialize<'__de>
+				{
+					#serde_json::from_slice::<$deserialized>(bytes) $(.map($unwrap))?
+				}
+			};
+		}
+		pub(crate) use json_constructors;
+		
+		macro_rules! xml_constructors {
+			($vis:vis $deserialized:ty $(=> $unwrap:path)?) => {
+				/// # GENERATED from_xml
+				/// from_xml deserializes an XML body into Self, unwrapping its #[envelope]
+				/// first, if declared.
+				///
+				/// # Returns:
+				///   - Ok(Self) when successful
+				///   - Err(quick_xml::DeError) when it's not
+				$vis fn from_xml<'__de>(xml: &'__de str) -> ::core::result::Result<Self, #quick_xml::DeError>
+				where $deserialized: #serde::Deserialize<'__de>
+				{
+					#quick_xml::de::from_str::<$deserialized>(xml) $(.map($unwrap))?
+				}
+				
+				/// # GENERATED from_slice
+				/// from_slice deserializes an XML body, as raw bytes, into Self, unwrapping its
+				/// #[envelope] first, if declared.
+				///
+				/// # Returns:
+				///   - Ok(Self) when successful
+				///   - Err(quick_xml::DeError) when it's not
+				$vis fn from_slice(bytes: &[u8]) -> ::core::result::Result<Self, #quick_xml::DeError>
+				where $deserialized: #serde::de::DeserializeOwned
+				{
+					#quick_xml::de::from_reader::<_, $deserialized>(bytes) $(.map($unwrap))?
+				}
+			};
+		}
+		pub(crate) use xml_constructors;
+		
+		macro_rules! xml_serializer {
+			($vis:vis) => {
+				/// # GENERATED to_xml
+				/// to_xml serializes Self into an XML body.
+				///
+				/// # Returns:
+				///   - Ok(xml) when successful
+				///   - Err(quick_xml::SeError) when it's not
+				$vis fn to_xml(&self) -> ::core::result::Result<::std::string::String, #quick_xml::SeError> {
+					#quick_xml::se::to_string(self)
+				}
+			};
+		}
+		pub(crate) use xml_serializer;
+		
+		macro_rules! builder_fn {
+			($vis:vis $builder:ty) => {
+				/// # GENERATED: Builder
+				/// Starts a builder with every field unset, same as ``` Default::default() ```
+				$vis fn builder() -> $builder {
+					return ::core::default::Default::default();
+				}
+			};
+		}
+		pub(crate) use builder_fn;
+	};
+}
+
+#[cfg(test)]
+mod support_tests {
+	use quote::quote;
+	use crate::generators::generate;
+	use crate::generators::support;
+	
+	#[test] fn runtime_support() {
+		let support = support::gen_runtime_support(true, false, quote!()).to_string();
+		assert!(support.contains("mod __restify"));
+		assert!(support.contains("fn assert_serialize"));
+		assert!(support.contains("fn query_pairs"));
+		assert!(!support.contains("fn expose_secret"));
+		assert!(support.contains(&quote!(pub(crate) use json_constructors;).to_string()));
+		assert!(!support.contains("pub use __restify :: shared"));
+		
+		let support = support::gen_runtime_support(false, true, quote!( pub struct PathParseError; )).to_string();
+		assert!(support.contains(&quote!(pub use __restify::shared::*;).to_string()));
+		assert!(!support.contains("fn query_pairs"));
+		assert!(support.contains("fn expose_secret"));
+		assert!(support.contains("fn expose_optional_secret"));
+		
+		let query = generate("struct Query { page: u32 }");
+		assert!(query.contains(&quote!(__restify::query_pairs(&query)).to_string()));
+		assert!(!query.contains("fn decode"));
+	}
+}
@@ -1,4 +1,6 @@
 use displaydoc::Display;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 
 /// # REST Types:
 /// # Enumerations:
@@ -13,4 +15,15 @@ pub enum RestType {
 	Deserializable,
 	/// Both
 	Both,
-}
\ No newline at end of file
+}
+
+/// Quotes the body of a generated struct from its quoted fields. A struct without any
+/// fields is generated as a unit struct, i.e., ``` struct Ack; ```
+pub fn quote_struct_body(fields: &[TokenStream2]) -> TokenStream2 {
+	if fields.is_empty() {
+		return quote!(;);
+	}
+	return quote!({
+		#( #fields )*
+	});
+}
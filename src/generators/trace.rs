@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{LitStr, Visibility};
+
+/// Generates the `tracing` span instrumentation for a REST Method declared with `#[trace]`.
+///
+/// Restify doesn't generate the client call itself, so the caller runs it within the span
+/// returned by `span()`, and records the response status with `record_status(..)` once it
+/// completes. This is separate from `#[log]`, which generates message-style log calls.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `endpoint`: The identifier of the parent Endpoint.
+/// - `method`: The REST Method, i.e., GET, POST, etc.
+/// - `uri`: The REST Method's URI.
+/// - `name`: The identifier of the generated REST Method struct.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block for the REST Method struct.
+pub fn gen_trace_span(
+	vis      : &Visibility,
+	endpoint : &Ident,
+	method   : &Ident,
+	uri      : &LitStr,
+	name     : &Ident,
+) -> TokenStream2 {
+	let endpoint = endpoint.to_string();
+	let method = method.to_string();
+	
+	let output = quote! {
+		impl #name {
+			/// # GENERATED: Tracing Span
+			/// The span to run this REST Method's client call within.
+			/// The **status** field stays empty until [Self::record_status] is called.
+			#vis fn span() -> ::tracing::Span {
+				::tracing::info_span!(
+					"restify",
+					endpoint = #endpoint,
+					method = #method,
+					uri = #uri,
+					status = ::tracing::field::Empty,
+				)
+			}
+			
+			/// # GENERATED: Tracing Span
+			/// Records the response status onto a span created by [Self::span].
+			#vis fn record_status(span: &::tracing::Span, status: u16) {
+				span.record("status", status);
+			}
+		}
+	};
+	output
+}
+
+#[cfg(test)]
+mod trace_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::generators::trace;
+	
+	#[test] fn trace_span() {
+		let method = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			#[trace]
+			GET "/api/user/{id}" => {
+				struct Query { id: u64 }
+			}
+		"#).unwrap();
+		assert!(method.attributes.iter().any(|a| matches!(a, crate::attributes::MethodAttr::Trace)));
+		
+		let endpoint = Ident::new("User", Span::call_site());
+		let name = Ident::new("UserGet", Span::call_site());
+		let trace = trace::gen_trace_span(&Visibility::Inherited, &endpoint, &method.method, &method.uri, &name).to_string();
+		assert!(trace.contains(&quote!(
+			endpoint = "User", method = "GET", uri = "/api/user/{id}", status = ::tracing::field::Empty,
+		).to_string()));
+	}
+}
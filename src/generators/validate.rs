@@ -0,0 +1,475 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::Visibility;
+use crate::attributes::{ParamAttr, TypeAttr};
+use crate::attributes::ValidateAction;
+use crate::parsers::options::RestOptions;
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::parsers::tools::{is_option_type, option_inner_type};
+
+/// Generates the error types returned by every generated `validate()` method.
+///
+/// ## Returns
+/// `TokenStream2` containing the `ValidationError` and `ValidationErrors` definitions.
+pub fn gen_validation_errors() -> TokenStream2 {
+	let output = quote! {
+		/// # GENERATED: ValidationError
+		/// A single failed `#[validate(..)]` action.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq)]
+		pub struct ValidationError {
+			/// The name of the field that failed validation.
+			pub field: &'static str,
+			/// Why the field failed validation.
+			pub message: ::std::string::String,
+		}
+		
+		/// # GENERATED: ValidationErrors
+		/// Every failed `#[validate(..)]` action of a single `validate()` call.
+		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::default::Default)]
+		pub struct ValidationErrors {
+			pub errors: ::std::vec::Vec<ValidationError>,
+		}
+		
+		impl ValidationErrors {
+			pub fn push(&mut self, field: &'static str, message: impl ::core::convert::Into<::std::string::String>) {
+				self.errors.push(ValidationError { field, message: message.into() });
+			}
+			pub fn is_empty(&self) -> bool {
+				self.errors.is_empty()
+			}
+			pub fn iter(&self) -> impl ::core::iter::Iterator<Item = &ValidationError> {
+				self.errors.iter()
+			}
+			/// Ok(()) when no errors were pushed.
+			pub fn into_result(self) -> ::core::result::Result<(), ValidationErrors> {
+				if self.errors.is_empty() {
+					return ::core::result::Result::Ok(());
+				}
+				::core::result::Result::Err(self)
+			}
+		}
+		
+		impl ::core::fmt::Display for ValidationErrors {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				for (i, error) in self.errors.iter().enumerate() {
+					if i > 0 {
+						::core::write!(f, "; ")?;
+					}
+					::core::write!(f, "{}: {}", error.field, error.message)?;
+				}
+				::core::result::Result::Ok(())
+			}
+		}
+		
+		impl ::std::error::Error for ValidationErrors {}
+	};
+	output
+}
+
+/// Generates the `validate()` method for a struct with `#[validate(..)]` attributes.
+///
+/// Every field-level `#[validate(..)]` is collected into a single method on the
+/// owning type, which runs every parsed action and returns all failures at once.
+/// Optional fields are only validated when they hold a value, except for `required`.
+/// Type-level rules run after every field-level check.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the struct.
+/// - `name`: The identifier of the struct.
+/// - `fields`: The struct's fields.
+/// - `rules`: The type-level actions of the struct's `#[validate(..)]` attributes.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block with the `validate()` method.
+pub fn gen_validate(
+	vis    : &Visibility,
+	name   : &Ident,
+	fields : &StructParameterSlice,
+	rules  : &[ValidateAction<TypeAttr>],
+) -> TokenStream2 {
+	let shared = RestOptions::shared();
+	let field_checks = fields.iter().map(quote_field_checks);
+	let type_checks = rules.iter().map(|rule| rule.quote_type_check(name));
+	let (impl_generics, ty_generics, where_clause) = fields.generics().split_for_impl();
+	
+	let output = quote! {
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// # GENERATED: Validate
+			/// Runs every `#[validate(..)]` action declared for this type.
+			#vis fn validate(&self) -> ::core::result::Result<(), #shared::ValidationErrors> {
+				let mut errors = #shared::ValidationErrors::default();
+				#( #field_checks )*
+				#( #type_checks )*
+				errors.into_result()
+			}
+		}
+	};
+	output
+}
+
+/// Generates the ``` validate() ``` of a REST Method declared with ``` #[validate_on_send] ```.
+///
+/// It's meant to run before the REST Method's client call performs any network I/O, which
+/// short-circuits with its error. Every component's failures are returned at once.
+///
+/// ## Parameters
+/// - `vis`: The visibility specifier of the REST Method struct.
+/// - `name`: The identifier of the generated REST Method struct.
+/// - `components`: The REST Method struct's fields holding a component with a `validate()`.
+///
+/// ## Returns
+/// `TokenStream2` containing an `impl` block with the `validate()` method.
+pub fn gen_validate_on_send(
+	vis        : &Visibility,
+	name       : &Ident,
+	components : &[Ident],
+) -> TokenStream2 {
+	let shared = RestOptions::shared();
+	let output = quote! {
+		impl #name {
+			/// # GENERATED: Validate On Send
+			/// Validates every component of this REST Method, before it's sent.
+			#vis fn validate(&self) -> ::core::result::Result<(), #shared::ValidationErrors> {
+				let mut errors = #shared::ValidationErrors::default();
+				#(
+					if let ::core::result::Result::Err(component) = self.#components.validate() {
+						errors.errors.extend(component.errors);
+					}
+				)*
+				errors.into_result()
+			}
+		}
+	};
+	output
+}
+
+/// Quotes every validation check of a single field.
+fn quote_field_checks(field: &StructParameter) -> TokenStream2 {
+	let chains = field.attributes.iter().filter_map(|a| match a {
+		ParamAttr::Validate(chain) => Some(chain),
+		_ => None,
+	}).collect::<Vec<_>>();
+	if chains.is_empty() {
+		return quote!();
+	}
+	let field_name = &field.name;
+	let field_str = field_name.to_string();
+	let optional = field.optional || is_option_type(&field.ty);
+	
+	let (required, checks): (Vec<_>, Vec<_>) = chains
+		.iter()
+		.flat_map(|chain| chain.actions.iter())
+		.partition(|action| action.is_required());
+	// The checks of an optional field are run against its inner value.
+	let ty = option_inner_type(&field.ty).unwrap_or(&field.ty);
+	let checks = checks.iter().map(|action| action.quote_check(&field_str, ty)).collect::<Vec<_>>();
+	
+	if !optional {
+		// Non-optional fields are always present, so 'required' has nothing to check.
+		if checks.is_empty() {
+			return quote!();
+		}
+		return quote! {
+			{
+				let value = &self.#field_name;
+				#( #checks )*
+			}
+		};
+	}
+	let on_none = match required.is_empty() {
+		true  => quote!(),
+		false => quote!( errors.push(#field_str, "is required"); ),
+	};
+	if checks.is_empty() {
+		return quote! {
+			if self.#field_name.is_none() { #on_none }
+		};
+	}
+	return quote! {
+		match &self.#field_name {
+			::core::option::Option::Some(value) => { #( #checks )* }
+			::core::option::Option::None => { #on_none }
+		}
+	};
+}
+
+#[cfg(test)]
+mod validate_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::validate;
+	
+	#[test] fn validate_method() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(required, range(min: 19, max: 115))]
+				age: u32,
+				#[validate(required, email)]
+				email: ?String,
+				#[validate(regex = "^[a-z]+$", custom = "checks::username")]
+				username: String,
+				nickname: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			fn validate(&self) -> ::core::result::Result<(), __restify::shared::ValidationErrors>
+		).to_string()));
+		assert!(request.contains(&quote!(
+			if *value < 19 {
+				errors.push("age", ::std::format!("must be at least {}", 19));
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(
+			::core::option::Option::None => { errors.push("email", "is required"); }
+		).to_string()));
+		assert!(request.contains(&quote!(::regex::Regex::new("^[a-z]+$")).to_string()));
+		assert!(request.contains(&quote!(checks::username(value)).to_string()));
+		assert!(!request.contains(&quote!(self.nickname).to_string()));
+		
+		// A 'required' alone has nothing to check on a non-optional field.
+		let required = generate(r#"
+			struct Request {
+				#[validate(required)]
+				id: u64,
+				#[validate(required)]
+				note: ?String,
+			}
+		"#);
+		assert!(!required.contains(&quote!(self.id).to_string()));
+		assert!(required.contains(&quote!(
+			if self.note.is_none() { errors.push("note", "is required"); }
+		).to_string()));
+		
+		assert!(!generate("struct Request { id: u64 }").contains("validate"));
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Request { #[validate(regex = "[a-z")] id: String }
+		"#).is_err());
+	}
+	
+	#[test] fn validate_length() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(length(min: 3, max: 16))]
+				username: String,
+				#[validate(length(max: 8))]
+				tags: ?Vec<String>,
+			}
+		"#);
+		// Strings are measured in chars, and collections in items.
+		assert!(request.contains(&quote!(
+			if value.chars().count() < 3 {
+				errors.push("username", ::std::format!("must have a length of at least {}", 3));
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(if value.len() > 8).to_string()));
+		assert!(generate(r#"
+			struct Request { #[validate(length(max: 3))] initials: ?std::borrow::Cow<'static, str> }
+		"#).contains(&quote!(if value.chars().count() > 3).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Request { #[validate(length(max: 8, min: 3))] id: String }
+		"#).is_err());
+	}
+	
+	#[test] fn validate_url() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(required, url)]
+				callback: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			errors.push("callback", "must be a valid URL");
+		).to_string()));
+	}
+	
+	#[test] fn validate_one_of() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(one_of("asc", "desc"))]
+				order: String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			if !["asc", "desc"].contains(&::core::convert::AsRef::<str>::as_ref(value)) {
+				errors.push("order", "must be one of: \"asc\", \"desc\"");
+			}
+		).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Request { #[validate(one_of())] order: String }
+		"#).is_err());
+	}
+	
+	#[test] fn validate_collections() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(min_items = 1, max_items = 10, unique)]
+				ids: Vec<u64>,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			if value.len() < 1 {
+				errors.push("ids", ::std::format!("must contain at least {} items", 1));
+			}
+		).to_string()));
+		assert!(request.contains(&quote!(if value.len() > 10).to_string()));
+		assert!(request.contains(&quote!(
+			errors.push("ids", "must not contain duplicate items");
+		).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Request { #[validate(min_items = "1")] ids: Vec<u64> }
+		"#).is_err());
+	}
+	
+	#[test] fn validate_nested() {
+		let request = generate(r#"
+			struct Request {
+				#[validate(nested)]
+				address: ?Address,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			if let ::core::result::Result::Err(nested) = value.validate()
+		).to_string()));
+	}
+	
+	#[test] fn validate_type_rules() {
+		let request = generate(r#"
+			#[validate(requires_together(start, end), mutually_exclusive(id, name), custom = "checks::request")]
+			struct Request {
+				start: ?u64,
+				end: ?u64,
+				id: ?u64,
+				name: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			let set = [self.start.is_some(), self.end.is_some()];
+		).to_string()));
+		assert!(request.contains(&quote!(
+			errors.push("id, name", "at most one may be set");
+		).to_string()));
+		assert!(request.contains(&quote!(
+			if let ::core::result::Result::Err(message) = checks::request(self) {
+				errors.push("Request", message);
+			}
+		).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			#[validate(requires_together(start, stop))]
+			struct Request { start: ?u64, end: ?u64 }
+		"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			#[validate(mutually_exclusive(id, name))]
+			struct Request { id: u64, name: ?String }
+		"#).is_err());
+	}
+	
+	#[test] fn validate_on_send() {
+		let method = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			#[validate_on_send]
+			POST "/api/user" => {
+				struct Request {
+					#[validate(email)]
+					email: String,
+				}
+			}
+		"#).unwrap();
+		assert!(method.attributes.iter().any(|a| matches!(a, crate::attributes::MethodAttr::ValidateOnSend)));
+		
+		let name = Ident::new("UserPost", Span::call_site());
+		let request = Ident::new("request", Span::call_site());
+		let validate = validate::gen_validate_on_send(&Visibility::Inherited, &name, &[request]).to_string();
+		assert!(validate.contains(&quote!(
+			if let ::core::result::Result::Err(component) = self.request.validate()
+		).to_string()));
+		
+		assert!(syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			#[validate_on_send]
+			POST "/api/user" => {
+				struct Request { email: String }
+			}
+		"#).is_err());
+	}
+	
+	#[test] fn validator_backend() {
+		let request = generate(r#"
+			#[validate(backend = "validator", custom = "checks::request")]
+			struct Request {
+				#[validate(required, email)]
+				email: ?String,
+				#[validate(range(min: 18), regex = "^[a-z]+$")]
+				age: u32,
+			}
+		"#);
+		assert!(request.contains(&quote!(#[derive(::validator::Validate)]).to_string()));
+		assert!(request.contains(&quote!(#[validate(schema(function = "checks::request"))]).to_string()));
+		assert!(request.contains(&quote!(#[validate(required, email)]).to_string()));
+		assert!(request.contains(&quote!(
+			#[validate(range(min = 18), regex(path = *__RESTIFY_REQUEST_AGE_REGEX_0))]
+		).to_string()));
+		assert!(request.contains(&quote!(static __RESTIFY_REQUEST_AGE_REGEX_0).to_string()));
+		assert!(!request.contains("ValidationErrors"));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			#[validate(backend = "validator")]
+			struct Request { #[validate(one_of("a"))] kind: String }
+		"#).is_err());
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			#[validate(backend = "validator", mutually_exclusive(a, b))]
+			struct Request { a: ?u64, b: ?u64 }
+		"#).is_err());
+	}
+	
+	#[test] fn garde_backend() {
+		let request = generate(r#"
+			#[validate(backend = "garde")]
+			struct Request {
+				#[validate(length(min: 1), regex = "^[a-z]+$", custom = "checks::name")]
+				name: String,
+				#[validate(nested)]
+				address: Address,
+				note: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(#[derive(::garde::Validate)]).to_string()));
+		assert!(request.contains(&quote!(
+			#[garde(length(min = 1), custom(checks::name), pattern("^[a-z]+$"))]
+		).to_string()));
+		assert!(request.contains(&quote!(#[garde(dive)]).to_string()));
+		assert!(request.contains(&quote!(#[garde(skip)]).to_string()));
+		assert!(!request.contains("__RESTIFY_"));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			#[validate(backend = "garde", custom = "checks::request")]
+			struct Request { a: ?u64 }
+		"#).is_err());
+	}
+	
+	#[test] fn validated_builder() {
+		let request = generate(r#"
+			#[builder]
+			struct Request {
+				#[validate(range(min: 1))]
+				id: u64,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			fn build(self) -> ::core::result::Result<Request, __restify::shared::ValidationErrors>
+		).to_string()));
+		assert!(request.contains(&quote!(built.validate()?;).to_string()));
+		
+		assert!(!generate(r#"
+			struct Request {
+				#[validate(range(min: 1))]
+				id: u64,
+			}
+		"#).contains("fn build"));
+	}
+}
@@ -1,28 +1,44 @@
-#![feature(try_trait_v2)]
-#![feature(try_trait_v2_residual)]
-#![allow(unused)]
+// Restify's house style: explicit returns.
+#![allow(clippy::needless_return)]
 extern crate proc_macro;
 extern crate proc_macro2;
 
 use proc_macro::TokenStream;
 use crate::doc_str::compile_doc_str;
-use crate::rest_api::compile_rest;
+use crate::rest_api::{compile_rest, compile_rest_debug};
+use crate::utils::logging;
 
 mod utils;
 mod parsers;
 mod doc_str;
 mod rest_api;
 mod generators;
-mod reference;
 mod attributes;
-mod failed_command;
-mod parse;
 
 
 #[proc_macro]
 pub fn restify(input: TokenStream) -> TokenStream {
+	logging::init();
 	compile_rest(input)
 }
 
+/// Runs the same pipeline as `restify!`, but instead of emitting the generated code, reports it
+/// prettified as a compiler warning, or writes it to a file declared before the invocation:
+///
+/// ```ignore
+/// restify_debug! {
+///   file: "generated/user.rs",
+///   [User: { GET "/api/user" => { struct Response { id: u64 } } }]
+/// }
+/// ```
 #[proc_macro]
-pub fn doc_str(input: TokenStream) -> TokenStream { compile_doc_str(input) }
+pub fn restify_debug(input: TokenStream) -> TokenStream {
+	logging::init();
+	compile_rest_debug(input)
+}
+
+#[proc_macro]
+pub fn doc_str(input: TokenStream) -> TokenStream {
+	logging::init();
+	compile_doc_str(input)
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use proc_macro2::Ident;
+use syn::ext::IdentExt;
+use crate::attributes::{Attrs, ParamAttr, TypeAttr};
+use crate::parsers::components::Components;
+use crate::parsers::endpoint::Endpoint;
+use crate::parsers::endpoint_method::{EndpointDataType, EndpointMethod};
+use crate::parsers::naming::check_collisions;
+use crate::parsers::options::RestOptions;
+use crate::parsers::rest_enum::{Enum, EnumParameter};
+use crate::parsers::rest_struct::Struct;
+use crate::parsers::struct_parameter::StructParameter;
+use crate::parsers::symbols::Symbols;
+use crate::utils::RestVariant;
+
+/// # Analysis
+/// The semantic analysis of a `restify!` invocation, run once it's been parsed, named and
+/// its References resolved, and before any code is generated.
+///
+/// Parsing rejects what's invalid within a single declaration. Analysis checks the rules
+/// that cut across them, and collects every violation as a spanned diagnostic, rather than
+/// stopping at the first:
+///   - A REST Method's path parameters can't also be sent as one of its Query fields.
+///   - Param Attributes must apply to the REST component of their struct, i.e., a Request is
+///     never deserialized, so ``` #[alias = ".."] ``` can't change anything within it.
+///   - Enum variants only accept serde's variant attributes.
+///   - Field and variant names, along with the names they're serialized as, are unique.
+///   - Generated identifiers are unique, see [check_collisions].
+pub struct Analysis<'a> {
+	symbols: &'a Symbols<'a>,
+	diagnostics: Vec<syn::Error>,
+}
+
+impl<'a> Analysis<'a> {
+	/// Analyzes a parsed `restify!` invocation, combining every diagnostic into a single
+	/// error. Runs once its [RestOptions] were installed.
+	pub fn run(
+		components : Option<&'a Components>,
+		endpoints  : &'a [Endpoint],
+		symbols    : &'a Symbols<'a>,
+	) -> syn::Result<()> {
+		let mut analysis = Analysis{ symbols, diagnostics: Vec::new() };
+		if let Err(err) = RestOptions::with(|options| check_collisions(options, components, endpoints)) {
+			analysis.diagnostics.push(err);
+		}
+		for data_type in components.iter().flat_map(|components| components.data_types.iter()) {
+			analysis.data_type(data_type);
+		}
+		for method in endpoints.iter().flat_map(|endpoint| endpoint.methods.iter()) {
+			analysis.uri_params(method);
+			for data_type in method.data_types.iter() {
+				analysis.data_type(data_type);
+			}
+		}
+		return analysis.diagnostics.into_iter()
+			.reduce(|mut combined, err| {
+				combined.combine(err);
+				combined
+			})
+			.map_or(Ok(()), Err);
+	}
+	
+	fn data_type(&mut self, data_type: &EndpointDataType) {
+		match data_type {
+			EndpointDataType::Struct(st) => {
+				for st in std::iter::once(st).chain(st.nested_structs()) {
+					self.param_attributes(st);
+					self.field_names(st);
+				}
+			}
+			EndpointDataType::Enum(en) => {
+				self.variant_attributes(en);
+				self.variant_names(en);
+			}
+			// References were checked against their declaration, see Symbols.
+			EndpointDataType::Proto(_) | EndpointDataType::Reference(_) => {}
+		}
+	}
+	
+	/// A path parameter is rendered within the URI, so a Query field of the same name would
+	/// send it twice.
+	fn uri_params(&mut self, method: &EndpointMethod) {
+		if method.path.is_empty() {
+			return;
+		}
+		for data_type in method.data_types.iter() {
+			let EndpointDataType::Struct(st) = self.symbols.resolve(data_type) else {
+				continue;
+			};
+			if !matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Query)) {
+				continue;
+			}
+			for field in st.parameters.iter() {
+				let name = serialized_name(field);
+				if method.path.params.iter().any(|param| *param == name) {
+					self.diagnostics.push(syn::Error::new(
+						field.name.span(),
+						format!("EndpointMethod: \"{name}\" is a path parameter of {}, it can't also be a Query field", method.uri.token())
+					));
+				}
+			}
+		}
+	}
+	
+	/// Serde only reads the attributes of the directions a struct is (de)serialized in, and a
+	/// Header struct isn't (de)serialized with serde at all.
+	fn param_attributes(&mut self, st: &Struct) {
+		let Ok(variant) = RestVariant::try_from(st.variant()) else {
+			return;
+		};
+		let (inapplicable, component): (fn(&ParamAttr) -> Option<&'static str>, &str) = match variant {
+			RestVariant::Request if !derives(st, "Deserialize") => (deserialize_attr, "Request structs are never deserialized"),
+			RestVariant::Response if !derives(st, "Serialize") => (serialize_attr, "Response structs are never serialized"),
+			RestVariant::Error if !derives(st, "Serialize") => (serialize_attr, "Error structs are never serialized"),
+			RestVariant::Header => (serde_attr, "Header structs aren't (de)serialized with serde"),
+			_ => return,
+		};
+		for field in st.parameters.iter() {
+			for (i, attribute) in field.attributes.iter().enumerate() {
+				let Some(name) = inapplicable(attribute) else {
+					continue;
+				};
+				self.diagnostics.push(syn::Error::new(
+					field.attributes.declared_span(i, field.name.span()),
+					format!("ParamAttribute: #[{name}] doesn't apply to \"{}\", {component}", field.name)
+				));
+			}
+		}
+	}
+	
+	/// Serde rejects its field-only attributes on an Enum variant. Unit and Tuple variants
+	/// were checked while parsing, which leaves Struct variants.
+	fn variant_attributes(&mut self, en: &Enum) {
+		for enumeration in en.enums.iter() {
+			if !matches!(enumeration.param, EnumParameter::Struct(_)) {
+				continue;
+			}
+			for (i, attribute) in enumeration.attributes.iter().enumerate() {
+				let Some(name) = field_only(attribute) else {
+					continue;
+				};
+				self.diagnostics.push(syn::Error::new(
+					enumeration.attributes.declared_span(i, enumeration.ident.span()),
+					format!("Enum: #[{name}] can only be attached to a struct field, not the variant \"{}\"", enumeration.ident)
+				));
+			}
+		}
+	}
+	
+	fn field_names(&mut self, st: &Struct) {
+		let header = matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Header));
+		let names = st.parameters.iter()
+			.filter(|field| !field.attributes.iter().any(|a| matches!(a, ParamAttr::Skip | ParamAttr::Flatten)))
+			.map(|field| {
+				let name = if header { field.header_name() } else { serialized_name(field) };
+				(&field.name, name)
+			});
+		self.unique("Struct", &st.name, st.parameters.iter().map(|field| &field.name), names);
+	}
+	
+	fn variant_names(&mut self, en: &Enum) {
+		let names = en.enums.iter()
+			.filter(|enumeration| !enumeration.attributes.iter().any(|a| matches!(a, ParamAttr::Skip)))
+			.map(|enumeration| (&enumeration.ident, serialized_ident(&enumeration.ident, &enumeration.attributes)));
+		self.unique("Enum", &en.name, en.enums.iter().map(|enumeration| &enumeration.ident), names);
+	}
+	
+	/// Reports every identifier declared more than once within **owner**, and every name
+	/// serialized more than once, i.e., two fields renamed to the same key.
+	fn unique<'i>(
+		&mut self,
+		kind       : &str,
+		owner      : &Ident,
+		idents     : impl Iterator<Item = &'i Ident>,
+		serialized : impl Iterator<Item = (&'i Ident, String)>,
+	) {
+		let mut declared: HashMap<String, &Ident> = HashMap::new();
+		for ident in idents {
+			if declared.insert(ident.unraw().to_string(), ident).is_some() {
+				self.diagnostics.push(syn::Error::new(
+					ident.span(),
+					format!("{kind}: \"{ident}\" is declared more than once within \"{owner}\"")
+				));
+			}
+		}
+		let mut names: HashMap<String, &Ident> = HashMap::new();
+		for (ident, name) in serialized {
+			let Some(first) = names.get(&name).copied() else {
+				names.insert(name, ident);
+				continue;
+			};
+			if first.unraw() == ident.unraw() {
+				continue;
+			}
+			self.diagnostics.push(syn::Error::new(
+				ident.span(),
+				format!("{kind}: \"{ident}\" is serialized as \"{name}\", the same as \"{first}\" within \"{owner}\"")
+			));
+		}
+	}
+}
+
+/// The name a field is serialized as, before its struct's ``` #[rename_all] ```.
+fn serialized_name(field: &StructParameter) -> String {
+	return serialized_ident(&field.name, &field.attributes);
+}
+
+fn serialized_ident(ident: &Ident, attributes: &Attrs<ParamAttr>) -> String {
+	return attributes.iter()
+		.find_map(|a| match a {
+			ParamAttr::Rename(rename) => Some(rename.value()),
+			_ => None,
+		})
+		.unwrap_or_else(|| ident.unraw().to_string());
+}
+
+/// Returns true if the user derives a serde trait their REST component doesn't, i.e.,
+/// ``` #[derive(Deserialize)] ``` on a Request struct.
+fn derives(st: &Struct, name: &str) -> bool {
+	let mut derives: Vec<syn::Path> = Vec::new();
+	for attribute in st.attributes.iter() {
+		match attribute {
+			TypeAttr::Derive(idents) => derives.extend(idents.iter().cloned().map(syn::Path::from)),
+			TypeAttr::Derives(ops) => ops.apply(&mut derives),
+			_ => {}
+		}
+	}
+	return derives.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == name));
+}
+
+/// Param Attributes only serde's Deserialize reads.
+fn deserialize_attr(attribute: &ParamAttr) -> Option<&'static str> {
+	return match attribute {
+		ParamAttr::Alias(_) => Some("alias"),
+		ParamAttr::Borrow(_) => Some("borrow"),
+		ParamAttr::DeserializeWith(_) => Some("deserialize_with"),
+		ParamAttr::SkipDeserialize => Some("skip_deserialize"),
+		_ => None,
+	};
+}
+
+/// Param Attributes only serde's Serialize reads.
+fn serialize_attr(attribute: &ParamAttr) -> Option<&'static str> {
+	return match attribute {
+		ParamAttr::SerializeWith(_) => Some("serialize_with"),
+		ParamAttr::SkipIf(_) => Some("skip_if"),
+		ParamAttr::SkipSerialize => Some("skip_serialize"),
+		_ => None,
+	};
+}
+
+/// Param Attributes serde reads, which a Header struct drops.
+fn serde_attr(attribute: &ParamAttr) -> Option<&'static str> {
+	return match attribute {
+		ParamAttr::Bound(_) => Some("bound"),
+		ParamAttr::Flatten => Some("flatten"),
+		ParamAttr::Skip => Some("skip"),
+		ParamAttr::With(_) => Some("with"),
+		_ => deserialize_attr(attribute).or_else(|| serialize_attr(attribute)),
+	};
+}
+
+/// Param Attributes serde, or restify, only accepts on a struct field.
+fn field_only(attribute: &ParamAttr) -> Option<&'static str> {
+	return match attribute {
+		ParamAttr::DateTime(_) => Some("datetime"),
+		ParamAttr::Decimal => Some("decimal"),
+		ParamAttr::Default(Some(_)) => Some("default"),
+		ParamAttr::Flatten => Some("flatten"),
+		ParamAttr::Getter(_) => Some("getter"),
+		ParamAttr::NoAutoSerde => Some("no_auto_serde"),
+		ParamAttr::Patch => Some("patch"),
+		ParamAttr::SkipIf(_) => Some("skip_if"),
+		ParamAttr::Uuid => Some("uuid"),
+		_ => None,
+	};
+}
+
+#[cfg(test)]
+mod analysis_tests {
+	
+	#[test] fn semantic_analysis() {
+		use crate::parsers::analysis::Analysis;
+		use crate::parsers::symbols::Symbols;
+		let analyze = |dsl: &str| -> Vec<String> {
+			let rest = syn::parse_str::<crate::parsers::RestEndpoints>(dsl).unwrap();
+			let symbols = Symbols::build(rest.components.as_ref(), &rest.endpoints).unwrap();
+			return match Analysis::run(rest.components.as_ref(), &rest.endpoints, &symbols) {
+				Ok(()) => vec![],
+				Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+			};
+		};
+		let diagnostics = analyze(r#"
+			[User: {
+				GET "/api/user/{id}" => {
+					struct Query { id: u64, #[alias = "n"] name: String }
+					struct Request { #[alias = "n"] name: String, #[rename = "name"] full_name: String }
+					struct Response { #[skip_if = "Option::is_none"] id: ?u64 }
+					enum Kind { #[flatten] Person { name: String }, Bot, Bot }
+				}
+			}]
+		"#);
+		assert_eq!(diagnostics, vec![
+			r#"EndpointMethod: "id" is a path parameter of "/api/user/{id}", it can't also be a Query field"#,
+			r#"ParamAttribute: #[alias] doesn't apply to "name", Request structs are never deserialized"#,
+			r#"Struct: "full_name" is serialized as "name", the same as "name" within "Request""#,
+			r#"ParamAttribute: #[skip_if] doesn't apply to "id", Response structs are never serialized"#,
+			r#"Enum: #[flatten] can only be attached to a struct field, not the variant "Person""#,
+			r#"Enum: "Bot" is declared more than once within "Kind""#,
+		]);
+		assert!(analyze(r#"
+			[User: {
+				GET "/api/user/{id}" => {
+					struct Query { page: u32 }
+					#[derive(Deserialize)] struct Request { #[alias = "n"] name: String }
+				}
+			}]
+		"#).is_empty());
+	}
+}
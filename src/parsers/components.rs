@@ -0,0 +1,57 @@
+use std::fmt::{Debug, Formatter};
+use proc_macro2::Ident;
+use syn::parse::ParseStream;
+use syn::{Token, Visibility};
+use crate::parsers::endpoint_method::EndpointDataType;
+
+/// # Shared Components
+/// Structs and Enums declared once, before the first Endpoint, within a top-level
+/// ``` components: { .. } ``` block. Each one is generated a single time, and can be
+/// referenced by name from the fields of any Endpoint's REST Method.
+///
+/// # Parameters:
+///   - [Visibility] vis: The visibility of every generated component.
+///   - [Vec]<[EndpointDataType]> data_types: The parsed component Structs and Enums.
+///
+/// # Parser Location:
+/// ```ignore
+/// restify!{
+///   <START>pub components: {
+///     struct Address<Response> {
+///       street: String,
+///       city: String,
+///     }
+///     enum Currency { Usd, Eur, }
+///   }<END>
+///   [MyEndpoint: {
+///     GET "/api/user/{id}" => {
+///       struct Response { address: Address }
+///     }
+///   }]
+/// }
+/// ```
+pub struct Components {
+	pub vis: Visibility,
+	pub data_types: Vec<EndpointDataType>,
+}
+impl Components {
+	/// Returns true if the next tokens within the ParseStream are the start of a
+	/// components block, i.e., ``` components: ``` or ``` pub components: ```
+	pub fn peek(input: ParseStream) -> bool {
+		let fork = input.fork();
+		if fork.peek(Token![pub]) && fork.parse::<Visibility>().is_err() {
+			return false;
+		}
+		return fork.parse::<Ident>().is_ok_and(|ident| ident == "components")
+			&& fork.peek(Token![:]);
+	}
+}
+impl Debug for Components {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "Components: {{")?;
+		for dt in self.data_types.iter() {
+			write!(f, "\t{dt}")?;
+		}
+		write!(f, "}}")
+	}
+}
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Formatter};
 use proc_macro2::Ident;
 use syn::Visibility;
-use crate::attributes::{Attrs, TypeAttr};
+use crate::attributes::{Attrs, EndpointAttr, TypeAttr};
 use crate::parsers::endpoint_method::EndpointMethod;
 
 /// # Level 1 Rest Macro Parser
@@ -10,6 +10,8 @@ use crate::parsers::endpoint_method::EndpointMethod;
 ///
 /// # Parameters:
 ///   - [Attr]<[TypeAttr]> attrs: User-defined Type Attributes for the final Endpoint Struct
+///   - [Attr]<[EndpointAttr]> attributes: User-defined Endpoint Attributes, declared right
+///     above the Endpoint's name, i.e., ``` #[prefix = "/api/v2"] ```
 ///   - [Ident] name: The Identifier for this Endpoint.
 ///   - [Vec]<[EndpointMethod]> A vector of Parsed Endpoint Methods, with their REST
 ///     component structs.
@@ -27,10 +29,11 @@ use crate::parsers::endpoint_method::EndpointMethod;
 /// }
 /// ```
 pub struct Endpoint {
-	pub attrs   : Attrs<TypeAttr>,
-	pub vis     : Visibility,
-	pub name    : Ident,
-	pub methods : Vec<EndpointMethod>,
+	pub attrs      : Attrs<TypeAttr>,
+	pub attributes : Attrs<EndpointAttr>,
+	pub vis        : Visibility,
+	pub name       : Ident,
+	pub methods    : Vec<EndpointMethod>,
 }
 impl Endpoint {
 	/// Builder: Add a [Attrs]<[TypeAttr]> into the Endpoint
@@ -41,6 +44,9 @@ impl Endpoint {
 }
 impl Debug for Endpoint {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		write!(f, "{} {} {:#?}", stringify!(#vis), self.name.to_string(), self.methods)
+		for attribute in self.attributes.iter() {
+			write!(f, "{attribute}")?;
+		}
+		write!(f, "{} {} {:#?}", stringify!(#vis), self.name, self.methods)
 	}
 }
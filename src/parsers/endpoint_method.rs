@@ -1,16 +1,24 @@
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use proc_macro2::Ident;
+use quote::ToTokens;
 use syn::LitStr;
+use crate::attributes::{Attrs, MethodAttr};
 use crate::parsers::rest_enum::Enum;
+use crate::parsers::rest_proto::Proto;
 use crate::parsers::rest_struct::Struct;
+use crate::utils::RestVariant;
 
 /// # Level 2 Rest Macro Parser
 /// Represents each REST Method, and their REST component struct definitions
 ///
 /// # Parameters:
+///   - [Attrs]<[MethodAttr]> attributes: User-defined REST Method Attributes.
 ///   - [Ident] method: The REST Method type, i.e., GET, POST, etc.
 ///   - [LitStr] uri: The Endpoint URI for this Method,
+///   - [UriTemplate] path: The path parameters declared within **uri**.
+///   - [Vec]<[BodyEncoding]> accepts: The response body encodings declared with
+///     ``` accepts: [json, msgpack] ```, in order of preference. Empty when undeclared.
 ///   - [Vec]<([Ident],[StructParameter])> structs: The REST Parameter Structs for this REST METHOD type.
 ///
 /// # Parser Location:
@@ -26,15 +34,27 @@ use crate::parsers::rest_struct::Struct;
 /// }
 /// ```
 pub struct EndpointMethod {
+	pub attributes: Attrs<MethodAttr>,
 	pub method: Ident,
 	pub uri: LitStr,
+	pub path: UriTemplate,
+	pub accepts: Vec<BodyEncoding>,
 	pub data_types: Vec<EndpointDataType>,
 }
+impl EndpointMethod {
+	/// Returns the crate feature declared with ``` #[feature = ".."] ```, if any.
+	pub fn feature(&self) -> Option<&LitStr> {
+		return self.attributes.iter().find_map(|a| match a {
+			MethodAttr::Feature(feature) => Some(feature),
+			_ => None,
+		});
+	}
+}
 impl Debug for EndpointMethod {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		write!(f, "method: {}\n", self.method.to_string())?;
-		write!(f, "uri:    {}\n", self.uri.token().to_string())?;
-		write!(f, "DataTypes: {{\n")?;
+		writeln!(f, "method: {}", self.method)?;
+		writeln!(f, "uri:    {}", self.uri.token())?;
+		writeln!(f, "DataTypes: {{")?;
 		for dt in self.data_types.iter() {
 			write!(f, "\t{dt}")?;
 		}
@@ -43,6 +63,141 @@ impl Debug for EndpointMethod {
 }
 
 
+/// # Body Encoding
+/// A response body encoding a REST Method accepts, declared within the REST Method with
+/// ``` accepts: [json, msgpack, xml] ```.
+///   - **Json**: Decoded with **serde_json**.
+///   - **MsgPack**: Decoded with **rmp-serde**.
+///   - **Xml**: Decoded with **quick-xml**.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BodyEncoding {
+	Json,
+	MsgPack,
+	Xml,
+}
+impl BodyEncoding {
+	/// The media type sent within the ``` Accept ``` header, i.e., ``` "application/json" ```
+	pub fn media_type(&self) -> &'static str {
+		return match self {
+			BodyEncoding::Json    => "application/json",
+			BodyEncoding::MsgPack => "application/msgpack",
+			BodyEncoding::Xml     => "application/xml",
+		};
+	}
+}
+
+/// # URI Template
+/// The path of a REST Method's URI, split around its ``` {param} ``` placeholders,
+/// i.e., ``` "/api/user/{id}/posts" ``` holds the literals ``` ["/api/user/", "/posts"] ```
+/// and the parameter ``` id ```. Everything after a ``` ? ``` is ignored.
+///
+/// # Parameters:
+///   - [Vec]<[String]> literals: The literal text around each parameter, always one
+///     more than **params**.
+///   - [Vec]<[Ident]> params: The path parameters, in order of appearance.
+#[derive(Default)]
+pub struct UriTemplate {
+	pub literals: Vec<String>,
+	pub params: Vec<Ident>,
+}
+impl UriTemplate {
+	pub fn parse(uri: &LitStr) -> syn::Result<Self> {
+		let value = uri.value();
+		let path = value.split('?').next().unwrap_or_default();
+		let mut template = UriTemplate::default();
+		let mut literal = String::new();
+		let mut chars = path.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'{' => {
+					let mut param = String::new();
+					let mut closed = false;
+					for c in chars.by_ref() {
+						if c == '}' {
+							closed = true;
+							break;
+						}
+						param.push(c);
+					}
+					if !closed {
+						return Err(syn::Error::new(uri.span(), "URI: unmatched '{'"));
+					}
+					let Ok(ident) = syn::parse_str::<Ident>(param.trim()) else {
+						return Err(syn::Error::new(
+							uri.span(),
+							format!("URI: \"{{{param}}}\" is not a valid path parameter name")
+						));
+					};
+					if !template.params.is_empty() && literal.is_empty() {
+						return Err(syn::Error::new(
+							uri.span(),
+							format!("URI: path parameter \"{ident}\" must be separated from the previous one")
+						));
+					}
+					if template.params.contains(&ident) {
+						return Err(syn::Error::new(
+							uri.span(),
+							format!("URI: path parameter \"{ident}\" is declared more than once")
+						));
+					}
+					template.literals.push(std::mem::take(&mut literal));
+					template.params.push(Ident::new(&ident.to_string(), uri.span()));
+				}
+				'}' => return Err(syn::Error::new(uri.span(), "URI: unmatched '}'")),
+				c => literal.push(c),
+			}
+		}
+		template.literals.push(literal);
+		return Ok(template);
+	}
+	
+	pub fn is_empty(&self) -> bool {
+		return self.params.is_empty();
+	}
+	
+	/// # Inline Query
+	/// Synthesizes the Query struct of a URI whose query declares its parameter Types inline,
+	/// i.e., ``` "/search?page={u32}&q={?String}" ``` declares
+	/// ``` struct Query { page: u32, q: ?String } ```. Keys that aren't valid identifiers
+	/// are renamed, i.e., ``` page-size={u32} ``` declares ``` #[rename="page-size"] page_size: u32 ```.
+	///
+	/// Returns the URI without its query along with the synthesized Query struct, or
+	/// None when the URI's query doesn't declare any Types.
+	pub fn inline_query(uri: &LitStr) -> syn::Result<Option<(LitStr, EndpointDataType)>> {
+		let value = uri.value();
+		let Some((path, query)) = value.split_once('?') else {
+			return Ok(None);
+		};
+		if !query.contains('{') {
+			return Ok(None);
+		}
+		let mut fields = String::new();
+		for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+			let ty = pair.split_once('=')
+				.and_then(|(key, ty)| Some((key, ty.strip_prefix('{')?.strip_suffix('}')?)));
+			let Some((key, ty)) = ty else {
+				return Err(syn::Error::new(
+					uri.span(),
+					format!("URI: query parameter \"{pair}\" must declare its Type, i.e., \"key={{String}}\"")
+				));
+			};
+			let field = key.replace(['-', '.'], "_");
+			if syn::parse_str::<Ident>(&field).is_err() {
+				return Err(syn::Error::new(
+					uri.span(),
+					format!("URI: query parameter \"{key}\" can't be used as a field name")
+				));
+			}
+			if field != key {
+				fields.push_str(&format!("#[rename = {key:?}] "));
+			}
+			fields.push_str(&format!("{field}: {ty},"));
+		}
+		let query = LitStr::new(&format!("struct Query {{ {fields} }}"), uri.span()).parse::<EndpointDataType>()?;
+		return Ok(Some((LitStr::new(path, uri.span()), query)));
+	}
+}
+
 /// # REST Method DataType
 /// For Every REST Method, You can Define either an
 /// Enum or a Struct data type.
@@ -50,27 +205,105 @@ impl Debug for EndpointMethod {
 /// # Enumerations:
 ///   - Struct([Struct]): Holds a [Struct] Datatype.
 ///   - Enum([Enum]): Holds an [Enum] Datatype.
+///   - Proto([Proto]): Wraps a prost-generated Protobuf message.
+///   - Reference([TypeReference]): Reuses a Struct or Enum declared earlier.
 pub enum EndpointDataType {
 	Struct(Struct),
 	Enum(Enum),
+	Proto(Proto),
+	Reference(TypeReference),
+}
+impl EndpointDataType {
+	/// The identifier of the declared or referenced Type.
+	pub fn name(&self) -> &Ident {
+		return match self {
+			EndpointDataType::Struct(st) => &st.name,
+			EndpointDataType::Enum(en) => &en.name,
+			EndpointDataType::Proto(proto) => &proto.name,
+			EndpointDataType::Reference(reference) => &reference.name,
+		};
+	}
+}
+
+/// # Type Reference
+/// A bare reference to a Struct or Enum declared earlier within the same `restify!`
+/// invocation, either by a previous REST Method or within the ``` components ``` block.
+/// Nothing is generated for a reference; the REST Method reuses the referenced Type.
+///
+/// # Parameters:
+///   - [Ident] role: The REST component the Type is used as, i.e., ``` response ```.
+///   - [RestVariant] variant: The [RestVariant] parsed from **role**.
+///   - [Ident] name: The identifier of the referenced Type.
+///
+/// # Parser Location:
+/// ```ignore
+/// GET "/api/user/{id}" => {
+///   <START>response: User<END>
+/// }
+/// ```
+pub struct TypeReference {
+	pub role: Ident,
+	pub variant: RestVariant,
+	pub name: Ident,
 }
 impl fmt::Display for EndpointDataType {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		//TODO: Include Attributes
 		match &self {
 			EndpointDataType::Enum(ref e) => {
-				write!(f, "enum {}: {{\n", e.name.to_string())?;
+				writeln!(f, "enum {}: {{", e.name)?;
 				for en in e.enums.iter() {
 					write!(f, "\n{}", en)?;
 				}
 			}
 			EndpointDataType::Struct(ref s) => {
-				write!(f, "struct {}: {{\n", s.name.to_string())?;
+				writeln!(f, "struct {}: {{", s.name)?;
 				for st in s.parameters.iter() {
 					write!(f, "\n{}", st)?;
 				}
 			}
+			EndpointDataType::Proto(ref p) => {
+				writeln!(f, "proto {} = {}", p.name, p.message.to_token_stream())?;
+			}
+			EndpointDataType::Reference(ref r) => {
+				writeln!(f, "{}: {}", r.role, r.name)?;
+			}
 		}
 		write!(f,"")
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod endpoint_method_tests {
+	use crate::attributes::ParamAttr;
+	
+	#[test] fn inline_query_params() {
+		use crate::parsers::endpoint_method::{EndpointDataType, EndpointMethod};
+		let method = syn::parse_str::<EndpointMethod>(r#"
+			GET "/search/{kind}?page={u32}&q={?String}&page-size={Vec<?u8>}" => {
+				struct Response { total: u64 }
+			}
+		"#).unwrap();
+		assert_eq!(method.uri.value(), "/search/{kind}");
+		assert_eq!(method.path.literals, vec!["/search/", ""]);
+		let EndpointDataType::Struct(query) = &method.data_types[0] else {
+			panic!("expected the synthesized Query struct first");
+		};
+		assert_eq!(query.name.to_string(), "Query");
+		let fields = query.parameters.iter().map(|p| p.name.to_string()).collect::<Vec<_>>();
+		assert_eq!(fields, vec!["page", "q", "page_size"]);
+		assert!(query.parameters[2].attributes.iter().any(|a| matches!(a, ParamAttr::Rename(name) if name.value() == "page-size")));
+		assert_eq!(method.data_types.len(), 2);
+		
+		let literal = syn::parse_str::<EndpointMethod>(r#"GET "/search?page=1" => {}"#).unwrap();
+		assert_eq!(literal.uri.value(), "/search?page=1");
+		assert!(literal.data_types.is_empty());
+		for invalid in [
+			r#"GET "/search?page={u32}&q=1" => {}"#,
+			r#"GET "/search?page={u32}" => { struct Query { q: String } }"#,
+			r#"GET "/search?page={u32}" => { struct Filter<Query> { q: String } }"#,
+		] {
+			assert!(syn::parse_str::<EndpointMethod>(invalid).is_err());
+		}
+	}
+}
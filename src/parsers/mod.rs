@@ -1,26 +1,36 @@
-use std::process::id;
-use proc_macro2::Span;
-use quote::quote_spanned;
+use quote::quote;
 
 use proc_macro2::Ident;
-use syn::{braced, bracketed, LitStr, parenthesized, Token, Type, Visibility};
-use syn::parse::{Lookahead1, Parse, ParseStream};
+use syn::{braced, bracketed, Expr, ItemUse, LitInt, LitStr, parenthesized, Token, Type, Visibility};
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use crate::attributes::{Attrs, ParamAttr, TypeAttr};
+use crate::attributes::{Attrs, EndpointAttr, MethodAttr, ParamAttr, TypeAttr};
+use crate::parsers::components::Components;
 use crate::parsers::endpoint::Endpoint;
+use crate::parsers::options::RestOptions;
 use crate::parsers::struct_parameter::StructParameter;
-use crate::parsers::endpoint_method::{EndpointDataType, EndpointMethod};
+use crate::parsers::endpoint_method::{BodyEncoding, EndpointDataType, EndpointMethod, TypeReference, UriTemplate};
 use crate::parsers::rest_enum::{Enum, Enumeration, EnumParameter};
+use crate::parsers::rest_proto::Proto;
 use crate::parsers::rest_struct::Struct;
-use crate::parsers::tools::{Lookahead, parse_struct_name_and_variant};
-use crate::utils::{RestMethods, RestVariant};
+use crate::parsers::scalars::{scalar_types, TypeMappings};
+use crate::parsers::symbols::Symbols;
+use crate::parsers::tools::{is_sequence_type, option_inner_type, Lookahead, parse_optional_type, parse_struct_name_and_variant, type_lifetimes};
+use crate::utils::{did_you_mean, RestMethods, RestVariant};
 
 pub mod endpoint;
 pub mod endpoint_method;
 pub mod rest_struct;
 pub mod struct_parameter;
 pub mod rest_enum;
+pub mod rest_proto;
 pub mod tools;
+pub mod options;
+pub mod components;
+pub mod symbols;
+pub mod analysis;
+pub mod naming;
+pub mod scalars;
 
 
 /// # Level 0 Rest Macro Parser
@@ -28,10 +38,16 @@ pub mod tools;
 /// And Parsed a Vector of [Endpoint]'s.
 ///
 /// # Parameter:
+/// - [RestOptions] options: Global options for the whole invocation.
+/// - [Vec]<[ItemUse]> uses: ``` use ``` statements declared before the first Endpoint,
+///   which are emitted at the top of the generated code, so custom field types resolve.
+/// - [Option]<[TypeMappings]> types: Domain names for field Types, substituted into every field.
+/// - [Option]<[Components]> components: Structs and Enums shared by every Endpoint.
 /// - [Vec]<[Endpoint]> endpoints: Parsed Endpoints
 /// # Parser Location:
 /// ```ignore
 /// rest!{<START>
+///   use crate::models::Address;
 ///   [MyEndpoint: {
 ///     GET "/api/user/{id}" => {
 ///       query: {
@@ -41,41 +57,200 @@ pub mod tools;
 ///   }]
 /// <END>}
 /// ```
-#[derive(Debug)]
 pub struct RestEndpoints {
+	pub options: RestOptions,
+	pub uses: Vec<ItemUse>,
+	pub types: Option<TypeMappings>,
+	pub components: Option<Components>,
 	pub endpoints: Vec<Endpoint>
 }
 
+/// # restify_debug! Parser
+/// Parses a `restify!` invocation, optionally preceded by the file its generated code is
+/// written to, relative to the invoking crate's manifest directory.
+///
+/// # Parameter:
+/// - [Option]<[LitStr]> file: The file declared with ``` file: ".." ```, if any.
+/// - [RestEndpoints] rest: The `restify!` invocation.
+/// # Parser Location:
+/// ```ignore
+/// restify_debug!{<START>
+///   file: "generated/user.rs",
+///   [MyEndpoint: { .. }]
+/// <END>}
+/// ```
+pub struct RestDebug {
+	pub file: Option<LitStr>,
+	pub rest: RestEndpoints,
+}
+impl Parse for RestDebug {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let file = if input.peek2(Token![:]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "file") {
+			input.parse::<Ident>()?;
+			input.parse::<Token![:]>()?;
+			let file = input.parse::<LitStr>()?;
+			if input.peek(Token![,]) {
+				input.parse::<Token![,]>()?;
+			}
+			Some(file)
+		} else { None };
+		
+		Ok(RestDebug{ file, rest: input.parse()? })
+	}
+}
+
+impl std::fmt::Debug for RestEndpoints {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let uses = self.uses.iter().map(|u| quote::quote!(#u).to_string()).collect::<Vec<_>>();
+		write!(f, "{:?} {:?} {:?} {:?} {:#?}", self.options, uses, self.types, self.components, self.endpoints)
+	}
+}
+
 //TODO: Parser Implementations >>-------------------------------------------------------------------
 impl Parse for StructParameter {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		// let mut lookahead = input.lookahead1();
-		let mut lookahead = Lookahead::new(&input);
-		let attributes = input.parse::<Attrs<ParamAttr>>()?;
+		let mut lookahead = Lookahead::new(input);
+		let mut attributes = input.parse::<Attrs<ParamAttr>>()?;
 		
 		let name: Ident = input.parse()?;
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Other)) {
+			return Err(syn::Error::new(
+				name.span(),
+				"StructParameter: #[other] can only be attached to an Enum Variant"
+			));
+		}
 		
 		input.parse::<Token![:]>()?;
 		
-		let optional = lookahead.shift_and_peek(Token![?]);
+		let mut optional = lookahead.shift_and_peek(Token![?]);
 		if optional { input.parse::<Token![?]>()?; }
+		// A double optional, i.e., `field: ??T`, is shorthand for `#[patch] field: T`
+		if optional && lookahead.shift_and_peek(Token![?]) {
+			input.parse::<Token![?]>()?;
+			if !attributes.iter().any(|a| matches!(a, ParamAttr::Patch)) {
+				attributes.0.push(ParamAttr::Patch);
+			}
+		}
 		
-		let ty: Type = input.parse()?;
-		
-		//TODO: Not working atm, not sure why
-		let _assert_debug = quote_spanned! {ty.span() =>
-			struct _AssertDebug where #ty: std::display::Debug + std::clone::Clone;
+		// Inline nested struct, i.e., `address: { street: String }`. Its Type is substituted
+		// once the parent Struct's name is known, see Struct::hoist_nested
+		let nested = if lookahead.shift_and_peek(syn::token::Brace) {
+			let content;
+			braced!(content in input);
+			let mut fields: Vec<StructParameter> = Vec::new();
+			while !content.is_empty() {
+				fields.push(content.parse()?);
+			}
+			Some(fields)
+		} else { None };
+		let mut ty: Type = match nested {
+			Some(_) => syn::parse_quote!(()),
+			None => parse_optional_type(input)?,
 		};
 		
+		let default = if lookahead.shift_and_peek(Token![=]) {
+			input.parse::<Token![=]>()?;
+			let default = input.parse::<Expr>()?;
+			if attributes.iter().any(|a| matches!(a, ParamAttr::Default(Some(_)))) {
+				return Err(syn::Error::new(
+					default.span(),
+					"StructParameter: A field can't have both a default value and #[default = \"..\"]"
+				));
+			}
+			Some(default)
+		} else { None };
+		
 		if lookahead.shift_and_peek(Token![,]) {
 			input.parse::<Token![,]>()?;
 		}
 		
+		if attributes.iter().any(|a| matches!(a, ParamAttr::DateTime(_))) {
+			if quote!(#ty).to_string() != "String" {
+				return Err(syn::Error::new(
+					ty.span(),
+					"StructParameter: A #[datetime] field is declared as a String, and generated as its timestamp Type"
+				));
+			}
+			let conflict = attributes.iter().any(|a| matches!(
+				a,
+				ParamAttr::Secret | ParamAttr::With(_) | ParamAttr::SerializeWith(_) | ParamAttr::DeserializeWith(_)
+			));
+			if conflict {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: #[datetime] can't be combined with #[secret], #[with], #[serialize_with] or #[deserialize_with]"
+				));
+			}
+		}
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Uuid)) {
+			if quote!(#ty).to_string() != "String" {
+				return Err(syn::Error::new(
+					ty.span(),
+					"StructParameter: A #[uuid] field is declared as a String, and generated as a Uuid"
+				));
+			}
+			if attributes.iter().any(|a| matches!(a, ParamAttr::DateTime(_) | ParamAttr::Decimal | ParamAttr::Secret)) {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: #[uuid] can't be combined with #[datetime], #[decimal] or #[secret]"
+				));
+			}
+		}
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Decimal)) {
+			if quote!(#ty).to_string() != "String" {
+				return Err(syn::Error::new(
+					ty.span(),
+					"StructParameter: A #[decimal] field is declared as a String, and generated as a Decimal"
+				));
+			}
+			let conflict = attributes.iter().any(|a| matches!(
+				a,
+				ParamAttr::DateTime(_) | ParamAttr::Secret | ParamAttr::With(_) | ParamAttr::SerializeWith(_) | ParamAttr::DeserializeWith(_)
+			));
+			if conflict {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: #[decimal] can't be combined with #[datetime], #[secret], #[with], #[serialize_with] or #[deserialize_with]"
+				));
+			}
+		}
+		// A #[patch] field is generated as Option<Option<T>>, telling an absent field apart from an explicit null.
+		if attributes.iter().any(|a| matches!(a, ParamAttr::Patch)) {
+			if nested.is_some() {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: An inline nested struct can't be declared as #[patch], or ??"
+				));
+			}
+			let conflict = attributes.iter().any(|a| matches!(
+				a,
+				ParamAttr::DateTime(_) | ParamAttr::Decimal | ParamAttr::Secret | ParamAttr::NoAutoSerde
+				| ParamAttr::With(_) | ParamAttr::SerializeWith(_) | ParamAttr::DeserializeWith(_)
+			));
+			if conflict {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: #[patch] can't be combined with #[datetime], #[decimal], #[secret], #[no_auto_serde], #[with], #[serialize_with] or #[deserialize_with]"
+				));
+			}
+			if default.is_some() {
+				return Err(syn::Error::new(
+					name.span(),
+					"StructParameter: A #[patch] field can't have a default value, it's absent unless set"
+				));
+			}
+			optional = true;
+			ty = syn::parse_quote!(::core::option::Option<#ty>);
+		}
+		
 		Ok(StructParameter{
 			attributes,
 			name,
 			ty,
-			optional
+			optional,
+			default,
+			nested,
 		})
 	}
 }
@@ -85,10 +260,10 @@ impl Parse for EnumParameter {
 			input.span(),
 			"Invalid Enumeration Parameter kind"
 		));
-		let mut lookahead = Lookahead::new(&input);
+		let mut lookahead = Lookahead::new(input);
 		
-		if lookahead.peek(Token![,]) {
-			input.parse::<Token![,]>()?;
+		// A unit variant ends at its comma, or at the end of the Enum when it's the last one.
+		if lookahead.peek(Token![,]) || input.is_empty() {
 			param = Ok(EnumParameter::Variant);
 		}
 		else if lookahead.peek(syn::token::Paren) {
@@ -105,7 +280,14 @@ impl Parse for EnumParameter {
 			braced!(params in input);
 			
 			while !params.is_empty() {
-				parameters.push(params.parse()?);
+				let parameter: StructParameter = params.parse()?;
+				if parameter.nested.is_some() {
+					return Err(syn::Error::new(
+						parameter.name.span(),
+						"EnumParameter: Inline nested structs are only supported within Structs"
+					));
+				}
+				parameters.push(parameter);
 			}
 			param = Ok(EnumParameter::Struct(parameters));
 		}
@@ -120,10 +302,34 @@ impl Parse for EnumParameter {
 
 impl Parse for Enumeration {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		let attributes = input.parse::<Attrs<ParamAttr>>()?;
+		let mut attributes = input.parse::<Attrs<ParamAttr>>()?;
 		
 		let ident: Ident = input.parse()?;
+		let discriminant = if input.peek(Token![=]) {
+			input.parse::<Token![=]>()?;
+			Some(input.parse::<LitInt>()
+				.map_err(|syn| syn::Error::new(
+					syn.span(),
+					"Enumeration: A discriminant must be an integer literal"
+				))?)
+		} else { None };
 		let param: EnumParameter = input.parse()?;
+		if let (Some(d), EnumParameter::Tuple{..} | EnumParameter::Struct(_)) = (&discriminant, &param) {
+			return Err(syn::Error::new(
+				d.span(),
+				"Enumeration: Discriminants can only be assigned to unit variants"
+			));
+		}
+		// A bare #[default] marks the Enum's default variant, rather than serde's field default.
+		if let Some(default) = attributes.0.iter_mut().find(|a| matches!(a, ParamAttr::Default(None))) {
+			if !matches!(param, EnumParameter::Variant) {
+				return Err(syn::Error::new(
+					ident.span(),
+					"Enumeration: #[default] can only be attached to a unit variant"
+				));
+			}
+			*default = ParamAttr::DefaultVariant;
+		}
 		if let EnumParameter::Variant | EnumParameter::Tuple{..} = param {
 			if let Some(span) = attributes.contains_struct_specific(){
 				return Err(syn::Error::new(
@@ -133,12 +339,11 @@ impl Parse for Enumeration {
 			}
 		}
 		
-		Ok(Enumeration{ attributes, ident, param })
+		Ok(Enumeration{ attributes, ident, discriminant, param })
 	}
 }
 impl Parse for Enum {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		let i = input.peek(Token![,])
 		let name: Ident = input.parse()?;
 		let mut enums: Vec<Enumeration> = Vec::new();
 		
@@ -148,22 +353,36 @@ impl Parse for Enum {
 			enums.push(enumerations.parse()?);
 		}
 		
-		Ok(Enum{ attributes: Attrs(vec![]), name, enums })
+		Ok(Enum{ attributes: Attrs::default(), name, enums })
 	}
 }
 
 impl Parse for Struct {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
-		let (name, rest_variant) = parse_struct_name_and_variant(&input)?;
+		let (name, generics, rest_variant) = parse_struct_name_and_variant(input)?;
 		let mut parameters: Vec<StructParameter> = Vec::new();
 		
-		let content;
-		braced!(content in input);
-		while !content.is_empty() {
-			parameters.push(content.parse()?);
+		// Unit struct, i.e., `struct Ack<Response>;`
+		if input.peek(Token![;]) {
+			input.parse::<Token![;]>()?;
+		} else {
+			let content;
+			braced!(content in input);
+			while !content.is_empty() {
+				parameters.push(content.parse()?);
+			}
 		}
+		if parameters.is_empty() && !generics.params.is_empty() {
+			return Err(syn::Error::new(
+				name.span(),
+				"Struct: A struct without any fields can't declare generic parameters"
+			));
+		}
+		let variant = rest_variant.as_ref().unwrap_or(&name);
+		let nested = Struct::hoist_nested(&name, variant, &mut parameters);
+		let generics = Struct::with_field_lifetimes(generics, &parameters);
 		
-		Ok(Struct{ attributes: Attrs(vec![]), name, rest_variant, parameters })
+		Ok(Struct{ attributes: Attrs::default(), name, generics, rest_variant, parameters, nested })
 	}
 }
 
@@ -171,51 +390,480 @@ impl Parse for EndpointDataType {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let attributes = input.parse::<Attrs<TypeAttr>>()?;
 		
-		let lookahead = Lookahead::new(&input);
+		let lookahead = Lookahead::new(input);
 		return if lookahead.peek(Token![struct]) {
 			input.parse::<Token![struct]>()?;
+			if let Some(span) = attributes.contains_enum_specific() {
+				return Err(syn::Error::new(
+					span,
+					"Struct: Detected an Enum-Specific Attribute attached to a Struct"
+				));
+			}
 			
-			let st = input.parse::<Struct>()?
+			let mut st = input.parse::<Struct>()?
 				.with_attributes(attributes);
+			for st in std::iter::once(&st).chain(st.nested_structs()) {
+				st.check_validate_rules()?;
+			}
+			let rest_variant = RestVariant::try_from(st.variant());
+			if matches!(rest_variant, Ok(RestVariant::Raw)) {
+				if let Some(field) = st.parameters.first() {
+					return Err(syn::Error::new(
+						field.name.span(),
+						"Struct: A Raw struct's content_type and body are generated, declare it as a unit struct, i.e., struct Avatar<Raw>;"
+					));
+				}
+				let raw_attr = |a: &TypeAttr| matches!(a, TypeAttr::Name(_) | TypeAttr::Derive(_) | TypeAttr::Derives(_) | TypeAttr::Doc(_));
+				if !st.attributes.iter().all(raw_attr) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: Only #[name], #[derive], #[derives] and #[doc] apply to Raw structs"
+					));
+				}
+			}
+			if !matches!(rest_variant, Ok(RestVariant::Request | RestVariant::Response)) {
+				let mut parameters = std::iter::once(&st)
+					.chain(st.nested_structs())
+					.flat_map(|st| st.parameters.iter());
+				if let Some(secret) = parameters.find(|p| p.is_secret()) {
+					return Err(syn::Error::new(
+						secret.name.span(),
+						"Struct: #[secret] is only supported within Request and Response structs"
+					));
+				}
+			}
+			if matches!(rest_variant, Ok(RestVariant::Response | RestVariant::Error))
+				&& st.attributes.iter().any(|a| matches!(a, TypeAttr::SkipNone)) {
+				return Err(syn::Error::new(
+					st.name.span(),
+					"Struct: #[skip_none] only applies to structs that are serialized, Response and Error structs are not"
+				));
+			}
+			if matches!(rest_variant, Ok(RestVariant::Error)) && !st.generics.params.is_empty() {
+				return Err(syn::Error::new(
+					st.name.span(),
+					"Struct: An Error struct can't declare generic parameters or lifetimes, since it implements std::error::Error"
+				));
+			}
+			if matches!(rest_variant, Ok(RestVariant::Header)) {
+				if st.attributes.iter().any(|a| matches!(a, TypeAttr::RenameAll(_))) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[rename_all] doesn't apply to Header structs, rename a single header with #[rename = \"..\"]"
+					));
+				}
+				let invalid = st.parameters.iter().find(|p| {
+					let header = p.header_name();
+					header.is_empty() || !header.chars().all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+				});
+				if let Some(invalid) = invalid {
+					return Err(syn::Error::new(
+						invalid.name.span(),
+						format!("Struct: \"{}\" is not a valid HTTP header name", invalid.header_name())
+					));
+				}
+				if let Some(datetime) = st.parameters.iter().find(|p| p.datetime().is_some()) {
+					return Err(syn::Error::new(
+						datetime.name.span(),
+						"Struct: #[datetime] doesn't apply to Header structs, headers are parsed with their Type's FromStr"
+					));
+				}
+			}
+			let query_attr = st.attributes.iter().find_map(|a| match a {
+				TypeAttr::ArrayFormat(_) => Some("array_format"),
+				TypeAttr::Encode(_) => Some("encode"),
+				TypeAttr::MaxDepth(_) => Some("max_depth"),
+				TypeAttr::QueryBackend(_) => Some("query_backend"),
+				_ => None,
+			});
+			if let Some(query_attr) = query_attr {
+				if !matches!(rest_variant, Ok(RestVariant::Query)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						format!("Struct: #[{query_attr}] only applies to Query structs")
+					));
+				}
+			}
+			let is_form = matches!(rest_variant, Ok(RestVariant::Form));
+			let urlencoded = is_form || st.attributes.iter()
+				.any(|a| matches!(a, TypeAttr::QueryBackend(backend) if backend.value() == "urlencoded"));
+			if urlencoded {
+				let nested_attr = st.attributes.iter().find_map(|a| match a {
+					TypeAttr::ArrayFormat(_) => Some("array_format"),
+					TypeAttr::MaxDepth(_) => Some("max_depth"),
+					_ => None,
+				});
+				if let Some(nested_attr) = nested_attr {
+					return Err(syn::Error::new(
+						st.name.span(),
+						format!("Struct: #[{nested_attr}] requires the serde_qs query backend, serde_urlencoded only supports flat structs")
+					));
+				}
+				// Inline nested fields were already substituted by their hoisted struct's name.
+				let nested = st.parameters.iter().find(|p| {
+					let ty = option_inner_type(&p.ty).unwrap_or(&p.ty);
+					let hoisted = st.nested.iter().any(|nested| matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|seg| seg.ident == nested.name)));
+					hoisted || is_sequence_type(ty)
+				});
+				if let Some(nested) = nested {
+					let message = match is_form {
+						true  => "Struct: serde_urlencoded only supports flat structs, a Form can't declare nested or sequence fields",
+						false => "Struct: serde_urlencoded only supports flat structs, use the serde_qs query backend for nested or sequence fields",
+					};
+					return Err(syn::Error::new(nested.name.span(), message));
+				}
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::CollectExtra)) {
+				if !matches!(rest_variant, Ok(RestVariant::Response)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[collect_extra] only applies to Response structs"
+					));
+				}
+				if st.attributes.iter().any(|a| matches!(a, TypeAttr::DenyUnknownFields)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[collect_extra] can't be combined with #[deny_unknown_fields]"
+					));
+				}
+				if let Some(extra) = st.parameters.iter().find(|p| p.name == "extra") {
+					return Err(syn::Error::new(
+						extra.name.span(),
+						"Struct: \"extra\" is already generated by #[collect_extra]"
+					));
+				}
+			}
+			let envelope = st.attributes.iter().find_map(|a| match a {
+				TypeAttr::Envelope(envelope) => Some(envelope),
+				_ => None,
+			});
+			if let Some(envelope) = envelope {
+				if !matches!(rest_variant, Ok(RestVariant::Response)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[envelope] only applies to Response structs"
+					));
+				}
+				if let Some(meta) = envelope.meta_field() {
+					if let Some(declared) = st.parameters.iter().find(|p| p.name == meta) {
+						return Err(syn::Error::new(
+							declared.name.span(),
+							format!("Struct: \"{meta}\" is already generated by #[envelope(.., meta = \"..\")]")
+						));
+					}
+				}
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::ContentType(_)))
+				&& !matches!(rest_variant, Ok(RestVariant::Request | RestVariant::Response | RestVariant::ReqRes)) {
+				return Err(syn::Error::new(
+					st.name.span(),
+					"Struct: #[content_type] only applies to Request, Response and ReqRes structs"
+				));
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::Hal(_))) {
+				if !matches!(rest_variant, Ok(RestVariant::Response)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[hal] only applies to Response structs"
+					));
+				}
+				if let Some(links) = st.parameters.iter().find(|p| p.name == "links") {
+					return Err(syn::Error::new(
+						links.name.span(),
+						"Struct: \"links\" is already generated by #[hal]"
+					));
+				}
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::Ndjson)) {
+				if !matches!(rest_variant, Ok(RestVariant::Response)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[ndjson] only applies to Response structs"
+					));
+				}
+				let xml = st.attributes.iter().any(|a| matches!(a, TypeAttr::ContentType(content_type) if content_type.value() == "xml"));
+				if xml || st.attributes.iter().any(|a| matches!(a, TypeAttr::ZeroCopy)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[ndjson] can't be combined with #[content_type = \"xml\"] or #[zero_copy]"
+					));
+				}
+				if st.generics.lifetimes().next().is_some() {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[ndjson] requires an owned Response struct, since every line is decoded from a buffer it doesn't outlive"
+					));
+				}
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::Partial)) {
+				if !matches!(rest_variant, Ok(RestVariant::Request | RestVariant::Response | RestVariant::ReqRes)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[partial] only applies to Request, Response and ReqRes structs"
+					));
+				}
+				if st.parameters.is_empty() {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[partial] requires at least one field to mirror"
+					));
+				}
+				if let Some(secret) = st.parameters.iter().find(|p| p.is_secret()) {
+					return Err(syn::Error::new(
+						secret.name.span(),
+						"Struct: #[partial] can't mirror #[secret] fields"
+					));
+				}
+				let custom_serde = st.parameters.iter().find(|p| p.attributes.iter().any(|a| matches!(
+					a,
+					ParamAttr::With(_) | ParamAttr::SerializeWith(_) | ParamAttr::DeserializeWith(_)
+				)));
+				if let Some(custom_serde) = custom_serde {
+					return Err(syn::Error::new(
+						custom_serde.name.span(),
+						"Struct: #[partial] can't mirror fields with #[with], #[serialize_with] or #[deserialize_with], since they'd be wrapped within an Option"
+					));
+				}
+			}
+			if st.attributes.iter().any(|a| matches!(a, TypeAttr::ZeroCopy)) {
+				if !matches!(rest_variant, Ok(RestVariant::Response)) {
+					return Err(syn::Error::new(
+						st.name.span(),
+						"Struct: #[zero_copy] only applies to Response structs"
+					));
+				}
+				st.zero_copy();
+			}
 			
 			Ok(EndpointDataType::Struct(st))
 		} else if lookahead.peek(Token![enum]) {
 			input.parse::<Token![enum]>()?;
+			attributes.check_enum_tagging()?;
+			if attributes.iter().any(|a| matches!(a, TypeAttr::ZeroCopy)) {
+				return Err(syn::Error::new(
+					input.span(),
+					"Enum: #[zero_copy] only applies to Response structs"
+				));
+			}
+			if attributes.iter().any(|a| matches!(a, TypeAttr::CollectExtra | TypeAttr::Envelope(_) | TypeAttr::Hal(_) | TypeAttr::Ndjson)) {
+				return Err(syn::Error::new(
+					input.span(),
+					"Enum: #[collect_extra], #[envelope], #[hal] and #[ndjson] only apply to Response structs"
+				));
+			}
+			if attributes.iter().any(|a| matches!(a, TypeAttr::ContentType(_) | TypeAttr::Partial)) {
+				return Err(syn::Error::new(
+					input.span(),
+					"Enum: #[content_type] and #[partial] only apply to Request, Response and ReqRes structs"
+				));
+			}
+			let query_attr = attributes.iter().any(|a| matches!(
+				a,
+				TypeAttr::ArrayFormat(_) | TypeAttr::Encode(_) | TypeAttr::MaxDepth(_) | TypeAttr::QueryBackend(_)
+			));
+			if query_attr {
+				return Err(syn::Error::new(
+					input.span(),
+					"Enum: #[array_format], #[encode], #[max_depth] and #[query_backend] only apply to Query structs"
+				));
+			}
 			
 			let en = input.parse::<Enum>()?
 				.with_attributes(attributes);
+			en.check_other_variant()?;
+			en.check_repr()?;
+			en.check_sensitive()?;
+			en.check_default_variant()?;
 			Ok(EndpointDataType::Enum(en))
+		} else if input.peek(syn::Ident) && input.peek2(syn::Ident) && input.fork().parse::<Ident>().is_ok_and(|kw| kw == "proto") {
+			input.parse::<Ident>()?;
+			let declared_attr = attributes.iter().any(|a| !matches!(a, TypeAttr::Name(_)));
+			if declared_attr {
+				return Err(syn::Error::new(
+					input.span(),
+					"Proto: Only #[name = \"..\"] applies to a Proto, the message is declared by prost"
+				));
+			}
+			let proto = input.parse::<Proto>()?
+				.with_attributes(attributes);
+			if !matches!(RestVariant::try_from(proto.variant()), Ok(RestVariant::Request | RestVariant::Response | RestVariant::ReqRes)) {
+				return Err(syn::Error::new(
+					proto.variant().span(),
+					"Proto: A Proto can only be a Request, Response or ReqRes"
+				));
+			}
+			Ok(EndpointDataType::Proto(proto))
+		} else if input.peek(syn::Ident) && input.peek2(Token![:]) {
+			let reference = input.parse::<TypeReference>()?;
+			if !attributes.0.is_empty() {
+				return Err(syn::Error::new(
+					reference.name.span(),
+					"TypeReference: Attributes can only be attached to the referenced Type's declaration"
+				));
+			}
+			Ok(EndpointDataType::Reference(reference))
 		} else {
 			Err(syn::Error::new(input.span(), "Failed to find either an Enum nor a Struct"))
 		}
 	}
 }
+impl Parse for Proto {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let (name, generics, rest_variant) = parse_struct_name_and_variant(input)?;
+		if !generics.params.is_empty() || generics.where_clause.is_some() {
+			return Err(syn::Error::new(
+				name.span(),
+				"Proto: A Proto can't declare generic parameters, they're declared by the prost message"
+			));
+		}
+		input.parse::<Token![=]>()?;
+		let message: syn::Path = input.parse()?;
+		if input.peek(Token![;]) {
+			input.parse::<Token![;]>()?;
+		}
+		
+		Ok(Proto{ attributes: Attrs::default(), name, rest_variant, message })
+	}
+}
+impl Parse for TypeReference {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let role: Ident = input.parse()?;
+		let variant = match role.to_string().as_str() {
+			"header"   => RestVariant::Header,
+			"request"  => RestVariant::Request,
+			"response" => RestVariant::Response,
+			"reqres"   => RestVariant::ReqRes,
+			"error"    => RestVariant::Error,
+			"form"     => RestVariant::Form,
+			"query"    => RestVariant::Query,
+			"raw"      => RestVariant::Raw,
+			unknown => {
+				return Err(syn::Error::new(
+					role.span(),
+					format!(
+						"TypeReference: Unknown REST component \"{unknown}\"{}, expected header, request, response, reqres, error, form, query or raw",
+						did_you_mean(unknown, &["header", "request", "response", "reqres", "error", "form", "query", "raw"])
+					)
+				));
+			}
+		};
+		input.parse::<Token![:]>()?;
+		let name: Ident = input.parse()?;
+		if input.peek(Token![,]) {
+			input.parse::<Token![,]>()?;
+		}
+		
+		Ok(TypeReference{ role, variant, name })
+	}
+}
+
 impl Parse for EndpointMethod {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let attributes = input.parse::<Attrs<MethodAttr>>()?;
 		let method: Ident = input.parse()?;
-		if !RestMethods::is_valid(&method) {
-			return Err(syn::Error::new(method.span(), "Invalid REST Method provided"));
+		// Generated names always use the canonical, uppercase REST Method.
+		let canonical = RestMethods::try_from(&method)?.to_string();
+		let method = Ident::new(&canonical, method.span());
+		let mut uri: LitStr = input.parse()?;
+		let inline_query = UriTemplate::inline_query(&uri)?;
+		if let Some((path, _)) = &inline_query {
+			uri = path.clone();
 		}
-		let uri: LitStr = input.parse()?;
+		let path = UriTemplate::parse(&uri)?;
 		input.parse::<Token![=>]>()?;
 		
 		let dt_content;
 		braced!(dt_content in input);
 		
 		let mut data_types: Vec<EndpointDataType> = Vec::new();
+		let mut accepts: Vec<BodyEncoding> = Vec::new();
 		while !dt_content.is_empty() {
-			data_types.push(dt_content.parse()?);
-			if input.peek(Token![,]) {
-				input.parse::<Token![,]>()?;
+			// ``` accepts: [json, msgpack] ```, rather than a TypeReference.
+			if dt_content.peek(syn::Ident) && dt_content.peek2(Token![:]) && dt_content.peek3(syn::token::Bracket) {
+				let keyword: Ident = dt_content.parse()?;
+				if keyword != "accepts" || !accepts.is_empty() {
+					return Err(syn::Error::new(
+						keyword.span(),
+						"EndpointMethod: Expected a single accepts: [..] list, i.e., accepts: [json, msgpack]"
+					));
+				}
+				dt_content.parse::<Token![:]>()?;
+				let encodings;
+				bracketed!(encodings in dt_content);
+				for encoding in encodings.parse_terminated(BodyEncoding::parse, Token![,])? {
+					if accepts.contains(&encoding) {
+						return Err(syn::Error::new(
+							keyword.span(),
+							format!("EndpointMethod: \"{}\" is accepted more than once", encoding.media_type())
+						));
+					}
+					accepts.push(encoding);
+				}
+				if accepts.is_empty() {
+					return Err(syn::Error::new(
+						keyword.span(),
+						"EndpointMethod: accepts: [..] requires at least one encoding, i.e., accepts: [json, msgpack]"
+					));
+				}
+			} else {
+				data_types.push(dt_content.parse()?);
+			}
+			if dt_content.peek(Token![,]) {
+				dt_content.parse::<Token![,]>()?;
+			}
+		}
+		if input.peek(Token![,]) {
+			input.parse::<Token![,]>()?;
+		}
+		if let Some((_, query)) = inline_query {
+			let declared = data_types.iter().find(|dt| match dt {
+				EndpointDataType::Struct(st) => matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Query)),
+				EndpointDataType::Reference(reference) => matches!(reference.variant, RestVariant::Query),
+				EndpointDataType::Enum(_) | EndpointDataType::Proto(_) => false,
+			});
+			if let Some(declared) = declared {
+				return Err(syn::Error::new(
+					declared.name().span(),
+					"URI: The Query struct is already declared by the URI's query, i.e., \"?page={u32}\""
+				));
 			}
+			data_types.insert(0, query);
 		}
 		
-		Ok(EndpointMethod { method, uri, data_types })
+		let validate_on_send = attributes.iter().any(|a| matches!(a, MethodAttr::ValidateOnSend));
+		let has_validate = data_types.iter()
+			.any(|dt| matches!(dt, EndpointDataType::Struct(st) if st.has_validate()));
+		// References are checked once they're resolved, see Symbols
+		let has_reference = data_types.iter()
+			.any(|dt| matches!(dt, EndpointDataType::Reference(_)));
+		if validate_on_send && !has_validate && !has_reference {
+			return Err(syn::Error::new(
+				method.span(),
+				"MethodAttribute::ValidateOnSend - Requires at least one struct with a #[validate(..)] attribute"
+			));
+		}
+		
+		Ok(EndpointMethod { attributes, method, uri, path, accepts, data_types })
+	}
+}
+impl Parse for BodyEncoding {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let encoding: Ident = input.parse()?;
+		return match encoding.to_string().as_str() {
+			"json"    => Ok(BodyEncoding::Json),
+			"msgpack" => Ok(BodyEncoding::MsgPack),
+			"xml"     => Ok(BodyEncoding::Xml),
+			unknown => Err(syn::Error::new(
+				encoding.span(),
+				format!("BodyEncoding: Unknown encoding \"{unknown}\", expected json, msgpack or xml")
+			)),
+		};
 	}
 }
 
 impl Parse for Endpoint {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let attributes = input.parse::<Attrs<EndpointAttr>>()?;
 		let peekable = input.lookahead1();
 		let vis = if peekable.peek(Token![pub]) {
 			input.parse()?
@@ -231,30 +879,126 @@ impl Parse for Endpoint {
 		while !content.is_empty() {
 			methods.push(content.parse()?);
 		}
+		if input.peek(Token![,]) {
+			input.parse::<Token![,]>()?;
+		}
+		
+		let prefix = attributes.iter().map(|a| match a {
+			EndpointAttr::Prefix(prefix) => prefix,
+		}).next();
+		if let Some(prefix) = prefix {
+			for method in methods.iter_mut() {
+				method.uri = EndpointAttr::join_prefix(prefix, &method.uri);
+				method.path = UriTemplate::parse(&method.uri)?;
+			}
+		}
+		
+		Ok(Endpoint{ attrs: Attrs::default(), attributes, vis, name, methods })
+	}
+}
+
+impl Parse for Components {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let vis = if input.peek(Token![pub]) {
+			input.parse()?
+		} else { Visibility::Inherited };
+		input.parse::<Ident>()?;
+		input.parse::<Token![:]>()?;
+		
+		let content;
+		braced!(content in input);
+		let mut data_types: Vec<EndpointDataType> = Vec::new();
+		while !content.is_empty() {
+			data_types.push(content.parse()?);
+			if content.peek(Token![,]) {
+				content.parse::<Token![,]>()?;
+			}
+		}
+		if data_types.is_empty() {
+			return Err(syn::Error::new(
+				content.span(),
+				"Components: Expected at least one Struct or Enum"
+			));
+		}
+		if input.peek(Token![,]) {
+			input.parse::<Token![,]>()?;
+		}
+		
+		Ok(Components{ vis, data_types })
+	}
+}
+impl Parse for TypeMappings {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		input.parse::<Ident>()?;
+		input.parse::<Token![:]>()?;
+		
+		let content;
+		braced!(content in input);
+		let builtin = scalar_types();
+		let mut types: Vec<(Ident, Type)> = Vec::new();
+		while !content.is_empty() {
+			let name = content.parse::<Ident>()?;
+			content.parse::<Token![=>]>()
+				.map_err(|syn| syn::Error::new(
+					syn.span(),
+					"TypeMappings: Expected a fat arrow, i.e., Timestamp => chrono::DateTime<chrono::Utc>"
+				))?;
+			let ty = content.parse::<Type>()?;
+			if builtin.contains_key(&name.to_string()) {
+				return Err(syn::Error::new(
+					name.span(),
+					format!("TypeMappings: {} is a built-in Type, and can't be remapped", name)
+				));
+			}
+			if types.iter().any(|(declared, _)| *declared == name) {
+				return Err(syn::Error::new(
+					name.span(),
+					format!("TypeMappings: {} was already mapped", name)
+				));
+			}
+			if let Some(lifetime) = type_lifetimes(&ty).first() {
+				return Err(syn::Error::new(
+					lifetime.span(),
+					"TypeMappings: A mapped Type can't borrow, since a struct's lifetimes are taken from its declared fields"
+				));
+			}
+			types.push((name, ty));
+			if content.peek(Token![,]) {
+				content.parse::<Token![,]>()?;
+			}
+		}
+		if types.is_empty() {
+			return Err(syn::Error::new(
+				content.span(),
+				"TypeMappings: Expected at least one mapping, i.e., Timestamp => chrono::DateTime<chrono::Utc>"
+			));
+		}
+		if input.peek(Token![,]) {
+			input.parse::<Token![,]>()?;
+		}
 		
-		Ok(Endpoint{ attrs: Attrs::default(), vis, name, methods })
+		Ok(TypeMappings{ types })
 	}
 }
 
 impl Parse for RestEndpoints {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let options = input.parse::<RestOptions>()?;
+		let mut uses: Vec<ItemUse> = Vec::new();
+		while input.peek(Token![use]) || (input.peek(Token![pub]) && input.peek2(Token![use])) {
+			uses.push(input.parse()?);
+		}
+		let types = if TypeMappings::peek(input) {
+			Some(input.parse::<TypeMappings>()?)
+		} else { None };
+		let components = if Components::peek(input) {
+			Some(input.parse::<Components>()?)
+		} else { None };
 		let mut endpoints: Vec<Endpoint> = Vec::new();
+		let mut attrs: Option<Attrs<TypeAttr>>;
 		
-		let mut lookahead: Lookahead1;
-		let mut lookahead = Lookahead::new(&input);
-		let mut attrs: Option<Attrs<TypeAttr>> = None;
-		
+		// Endpoint blocks may be delimited by commas, including a trailing one, or not at all.
 		while !input.is_empty() {
-			if !endpoints.is_empty() {
-				if !lookahead.shift_and_peek(Token![,]){
-					return Err(syn::Error::new(
-						input.span(),
-						"Endpoints must be comma-delimited"
-					));
-				}
-				input.parse::<Token![,]>()?;
-			}
-			
 			attrs = input.parse().ok();
 			
 			let content;
@@ -268,7 +1012,265 @@ impl Parse for RestEndpoints {
 				};
 				endpoints.push(endpoint);
 			}
+			if input.peek(Token![,]) {
+				input.parse::<Token![,]>()?;
+			}
 		}
-		Ok(RestEndpoints{ endpoints })
+		Symbols::build(components.as_ref(), &endpoints)?;
+		Ok(RestEndpoints{ options, uses, types, components, endpoints })
+	}
+}
+
+#[cfg(test)]
+mod parser_tests {
+	use quote::quote;
+	use proc_macro2::{Ident, Span};
+	use syn::Visibility;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::utils::RestVariant;
+	use crate::attributes::{ParamAttr, TypeAttr};
+	use crate::generators::method;
+	
+	#[test] fn use_statements() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			#[rest:serde_path = "my_facade::serde"]
+			use crate::models::Address;
+			pub use chrono::{DateTime, Utc};
+			[User: {
+				GET "/api/user" => {
+					struct Response { address: Address }
+				}
+			}]
+		"#).unwrap();
+		let uses = rest.uses.iter().map(|u| quote!(#u).to_string()).collect::<Vec<_>>();
+		assert_eq!(uses, vec![
+			quote!(use crate::models::Address;).to_string(),
+			quote!(pub use chrono::{DateTime, Utc};).to_string(),
+		]);
+		assert_eq!(rest.endpoints.len(), 1);
+	}
+	
+	#[test] fn shared_components() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			pub components: {
+				struct Address<Response> {
+					street: String,
+					city: String,
+				}
+				enum Currency { Usd, Eur, }
+			}
+			[User: {
+				GET "/api/user" => {
+					struct Response { address: Address, currency: Currency }
+				}
+			}]
+		"#).unwrap();
+		let components = rest.components.unwrap();
+		assert!(matches!(components.vis, Visibility::Public(_)));
+		assert!(matches!(&components.data_types[..], [EndpointDataType::Struct(st), EndpointDataType::Enum(en)]
+			if st.name == "Address" && en.name == "Currency"));
+		assert_eq!(rest.endpoints.len(), 1);
+		
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>("components: {}").is_err());
+	}
+	
+	#[test] fn type_references() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[User: {
+				GET "/api/user/{id}" => {
+					struct User<Response> {
+						#[validate(length(min: 1))]
+						name: String,
+					}
+				}
+				#[validate_on_send]
+				PUT "/api/user/{id}" => {
+					reqres: Echo,
+					response: User
+				}
+			}]
+		"#);
+		assert!(rest.is_err(), "Echo was never declared");
+		
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[User: {
+				GET "/api/user/{id}" => {
+					struct User<Response> {
+						#[validate(length(min: 1))]
+						name: String,
+					}
+				}
+				#[validate_on_send]
+				PUT "/api/user/{id}" => {
+					response: User
+				}
+			}]
+		"#).unwrap();
+		let symbols = crate::parsers::symbols::Symbols::build(rest.components.as_ref(), &rest.endpoints).unwrap();
+		let reference = &rest.endpoints[0].methods[1].data_types[0];
+		assert!(matches!(reference, EndpointDataType::Reference(_)));
+		assert!(matches!(symbols.resolve(reference), EndpointDataType::Struct(st) if st.has_validate()));
+		
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[User: {
+				GET "/api/user" => {
+					struct User<Response> { name: String }
+				}
+				POST "/api/user" => {
+					request: User
+				}
+			}]
+		"#).is_err());
+	}
+	
+	#[test] fn flexible_delimiters() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[UserEndpoint: {
+				GET "/api/user/{id}" => {
+					struct Response { id: u64, },
+					enum Role { Admin, Member },
+				},
+				DELETE "/api/user/{id}" => {
+					struct Ack<Response>;
+				}
+			}, AdminEndpoint: {
+				GET "/api/admin" => { struct Response { id: u64 } }
+			}]
+			[StatusEndpoint: {
+				GET "/api/status" => { struct Response { up: bool } }
+			}],
+		"#).unwrap();
+		let endpoints = rest.endpoints.iter().map(|e| e.name.to_string()).collect::<Vec<_>>();
+		assert_eq!(endpoints, ["UserEndpoint", "AdminEndpoint", "StatusEndpoint"]);
+		assert_eq!(rest.endpoints[0].methods.len(), 2);
+		assert_eq!(rest.endpoints[0].methods[0].data_types.len(), 2);
+	}
+	
+	#[test] fn case_insensitive_methods() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[UserEndpoint: {
+				get "/api/user/{id}" => { struct Response { id: u64 } }
+				Delete "/api/user/{id}" => { struct Ack<Response>; }
+			}]
+		"#).unwrap();
+		let methods = rest.endpoints[0].methods.iter().map(|m| m.method.to_string()).collect::<Vec<_>>();
+		assert_eq!(methods, ["GET", "DELETE"]);
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[UserEndpoint: { fetch "/api/user/{id}" => { struct Response { id: u64 } } }]
+		"#).is_err());
+	}
+	
+	#[test] fn trace_and_connect_methods() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[ProxyEndpoint: {
+				TRACE "/api/echo" => { struct Response { echo: String } }
+				connect "/api/tunnel" => { struct Ack<Response>; }
+			}]
+		"#).unwrap();
+		let methods = rest.endpoints[0].methods.iter().map(|m| m.method.to_string()).collect::<Vec<_>>();
+		assert_eq!(methods, ["TRACE", "CONNECT"]);
+		let name = crate::utils::camelCaseIdent(&["Proxy", &methods[1]], true, Span::call_site());
+		assert_eq!(name.to_string(), "ProxyCONNECT");
+	}
+	
+	#[test] fn method_attributes() {
+		let method = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			#[timeout = "30s"]
+			#[retry = 3]
+			#[content_type = "application/json"]
+			#[feature = "admin"]
+			POST "/api/user" => {
+				struct Request { name: String }
+			}
+		"#).unwrap();
+		let name = Ident::new("UserPOST", Span::call_site());
+		let config = method::gen_method_config(&Visibility::Inherited, &name, &method.method, &method.uri, method.attributes.iter(), false).to_string();
+		assert!(config.contains(&quote!(const PATH: &'static str = "/api/user";).to_string()));
+		assert!(config.contains(&quote!(const METHOD: &'static str = "POST";).to_string()));
+		assert!(config.contains(&quote!(
+			const TIMEOUT: ::core::time::Duration = ::core::time::Duration::from_millis(30000u64);
+		).to_string()));
+		assert!(config.contains(&quote!(const RETRIES: u32 = 3;).to_string()));
+		assert!(config.contains(&quote!(const CONTENT_TYPE: &'static str = "application/json";).to_string()));
+		
+		let feature = method.attributes.iter().find_map(|a| match a {
+			crate::attributes::MethodAttr::Feature(feature) => Some(feature.clone()),
+			_ => None,
+		}).unwrap();
+		let gated = method::gen_feature_gate(&feature, quote!( struct UserPOST; impl UserPOST {} )).to_string();
+		assert!(gated.contains(&quote!(#[cfg(feature = "admin")] struct UserPOST;).to_string()));
+		assert!(gated.contains(&quote!(#[cfg(feature = "admin")] impl UserPOST {}).to_string()));
+		
+		assert!(syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"
+			#[timeout = "soon"]
+			GET "/api/user" => { struct Response { id: u64 } }
+		"#).is_err());
+	}
+	
+	#[test] fn endpoint_prefix() {
+		let rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[#[prefix = "/api/v2/"]
+			UserEndpoint: {
+				GET "/user/{id}" => { struct Response { id: u64 } }
+				POST "user" => { struct Request { name: String } }
+			}
+			StatusEndpoint: {
+				GET "/status" => { struct Response { up: bool } }
+			}]
+		"#).unwrap();
+		let uris = rest.endpoints.iter()
+			.flat_map(|e| e.methods.iter().map(|m| m.uri.value()))
+			.collect::<Vec<_>>();
+		assert_eq!(uris, ["/api/v2/user/{id}", "/api/v2/user", "/status"]);
+		
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			[#[prefix = "api"] UserEndpoint: { GET "/user" => { struct Response { id: u64 } } }]
+		"#).is_err());
+	}
+	
+	#[test] fn restify_debug_file() {
+		use crate::parsers::RestDebug;
+		let debug = syn::parse_str::<RestDebug>(r#"file: "generated/user.rs", [User: { GET "/api/user" => {} }]"#).unwrap();
+		assert_eq!(debug.file.map(|file| file.value()), Some("generated/user.rs".to_string()));
+		assert_eq!(debug.rest.endpoints.len(), 1);
+		
+		let debug = syn::parse_str::<RestDebug>(r#"components: { struct Page<Query> { page: u32 } } [User: { GET "/api/user" => {} }]"#).unwrap();
+		assert!(debug.file.is_none());
+		assert!(debug.rest.components.is_some());
+	}
+	
+	#[test] fn did_you_mean_suggestions() {
+		use crate::attributes::MethodAttr;
+		use crate::utils::closest_match;
+		fn error<T>(result: syn::Result<T>) -> String {
+			return result.err().map(|err| err.to_string()).unwrap_or_default();
+		}
+		assert!(error(syn::parse_str::<ParamAttr>("renam")).ends_with(r#"did you mean "rename"?"#));
+		assert!(error(syn::parse_str::<TypeAttr>("rename_al")).ends_with(r#"did you mean "rename_all"?"#));
+		assert!(error(syn::parse_str::<MethodAttr>("timout")).ends_with(r#"did you mean "timeout"?"#));
+		assert!(error(syn::parse_str::<EndpointDataType>("struct User<Reponse> { id: u64 }")).ends_with(r#"did you mean "Response"?"#));
+		assert!(error(syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>(r#"GTE "/api" => {}"#)).ends_with(r#"did you mean "GET"?"#));
+		assert!(!error(syn::parse_str::<ParamAttr>("serialize")).contains("did you mean"));
+		assert_eq!(closest_match("reqres", &RestVariant::NAMES), Some("ReqRes"));
+		assert_eq!(closest_match("xyz", &RestVariant::NAMES), None);
+		// Every listed name must be a known attribute.
+		for name in TypeAttr::NAMES {
+			assert!(!error(syn::parse_str::<TypeAttr>(name)).contains("Unknown"), "{name}");
+		}
+		for name in ParamAttr::NAMES {
+			assert!(!error(syn::parse_str::<ParamAttr>(name)).contains("Unknown"), "{name}");
+		}
+	}
+	
+	#[test] fn declared_spans() {
+		use crate::attributes::Attrs;
+		let attrs: Attrs<TypeAttr> = syn::parse_str("#[rename_all = \"camelCase\"]\n#[untagged]").unwrap();
+		let span = attrs.contains_enum_specific().expect("untagged is enum-specific");
+		assert_eq!((span.start().line, span.start().column), (2, 2));
+		
+		let err = syn::parse_str::<ParamAttr>("    renam").unwrap_err();
+		assert_eq!(err.span().start().column, 4);
+		let method = syn::parse_str::<crate::parsers::endpoint_method::EndpointMethod>("  GTE \"/api\" => {}").unwrap_err();
+		assert_eq!(method.span().start().column, 2);
 	}
 }
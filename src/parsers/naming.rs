@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use proc_macro2::Ident;
+use crate::attributes::{Attrs, TypeAttr};
+use crate::generators::partial::partial_struct_name;
+use crate::generators::path::path_struct_name;
+use crate::generators::response::envelope_struct_name;
+use crate::parsers::components::Components;
+use crate::parsers::endpoint::Endpoint;
+use crate::parsers::endpoint_method::EndpointDataType;
+use crate::parsers::options::RestOptions;
+use crate::parsers::rest_struct::Struct;
+use crate::utils::camelCaseIdent;
+
+/// # Naming
+/// Applies the user's naming controls onto every declared Struct and Enum, before any
+/// code is generated:
+///   - ``` #[name = ".."] ``` renames a single Struct or Enum.
+///   - ``` #[rest:name_format = ".."] ``` names every Struct declared only by its REST
+///     component, i.e., ``` struct Response { .. } ```, after its Endpoint and REST Method.
+///
+/// Every [TypeReference](crate::parsers::endpoint_method::TypeReference) is renamed along
+/// with the latest declaration of its name, made before it.
+pub fn apply_names(
+	options    : &RestOptions,
+	components : Option<&mut Components>,
+	endpoints  : &mut [Endpoint],
+) {
+	let mut names: HashMap<String, Ident> = HashMap::new();
+	for data_type in components.into_iter().flat_map(|components| components.data_types.iter_mut()) {
+		rename(data_type, None, &mut names);
+	}
+	for endpoint in endpoints.iter_mut() {
+		for method in endpoint.methods.iter_mut() {
+			let format = |variant: &str| options.format_name(&endpoint.name, &method.method, variant);
+			for data_type in method.data_types.iter_mut() {
+				rename(data_type, Some(&format), &mut names);
+			}
+		}
+	}
+}
+
+/// Formats the name of a Struct declared only by its REST variant.
+type NameFormat<'f> = &'f dyn Fn(&str) -> Option<String>;
+
+/// Renames a single data type, and records its declared name for later references.
+fn rename(
+	data_type : &mut EndpointDataType,
+	format    : Option<NameFormat>,
+	names     : &mut HashMap<String, Ident>,
+) {
+	match data_type {
+		EndpointDataType::Reference(reference) => {
+			if let Some(name) = names.get(&reference.name.to_string()) {
+				reference.name = Ident::new(&name.to_string(), reference.name.span());
+			}
+		}
+		EndpointDataType::Enum(en) => {
+			let name = declared_name(&en.attributes).unwrap_or(en.name.to_string());
+			let name = Ident::new(&name, en.name.span());
+			names.insert(en.name.to_string(), name.clone());
+			en.name = name;
+		}
+		EndpointDataType::Proto(proto) => {
+			let declared = proto.name.clone();
+			let formatted = match (&proto.rest_variant, format) {
+				(None, Some(format)) => format(&declared.to_string()),
+				_ => None,
+			};
+			let name = declared_name(&proto.attributes)
+				.or(formatted)
+				.unwrap_or(declared.to_string());
+			let name = Ident::new(&name, declared.span());
+			if name != declared && proto.rest_variant.is_none() {
+				proto.rest_variant = Some(declared.clone());
+			}
+			names.insert(declared.to_string(), name.clone());
+			proto.name = name;
+		}
+		EndpointDataType::Struct(st) => {
+			let declared = st.name.clone();
+			let formatted = match (&st.rest_variant, format) {
+				(None, Some(format)) => format(&declared.to_string()),
+				_ => None,
+			};
+			let name = declared_name(&st.attributes)
+				.or(formatted)
+				.unwrap_or(declared.to_string());
+			let name = Ident::new(&name, declared.span());
+			// The REST component was only declared through the struct's previous name.
+			if name != declared && st.rest_variant.is_none() {
+				st.rest_variant = Some(declared.clone());
+			}
+			names.insert(declared.to_string(), name.clone());
+			st.name = name;
+		}
+	}
+}
+
+/// Returns the name declared with ``` #[name = ".."] ```, if any.
+fn declared_name(attributes: &Attrs<TypeAttr>) -> Option<String> {
+	return attributes.iter().find_map(|a| match a {
+		TypeAttr::Name(name) => Some(name.value()),
+		_ => None,
+	});
+}
+
+/// Returns the name of the struct generated for a REST Method, i.e., ``` UserGET ```,
+/// or as formatted by ``` #[rest:name_format = ".."] ```.
+pub fn method_struct_name(options: &RestOptions, endpoint: &Ident, method: &Ident) -> Ident {
+	return match options.format_name(endpoint, method, "") {
+		Some(name) => Ident::new(&name, method.span()),
+		None => camelCaseIdent(&[
+			endpoint.to_string().as_str(),
+			method.to_string().as_str(),
+		], true, method.span()),
+	};
+}
+
+/// # Name Collisions
+/// Checks that no two generated Types, including REST Method structs, inline nested
+/// structs, Path structs, Response envelopes, #[partial] mirrors and the ``` Routes ``` enum,
+/// resolve to the same identifier. Reports both definition sites, instead of leaving rustc
+/// to report a duplicate definition within the generated code.
+///
+/// Types shared by every generated Type, i.e., ``` PathParseError ```, live within the
+/// invocation's runtime-support module, so they can't collide with a declared Type.
+pub fn check_collisions(
+	options    : &RestOptions,
+	components : Option<&Components>,
+	endpoints  : &[Endpoint],
+) -> syn::Result<()> {
+	fn push_data_type(generated: &mut Vec<Ident>, data_type: &EndpointDataType) {
+		match data_type {
+			EndpointDataType::Struct(st) => {
+				generated.extend(std::iter::once(st).chain(st.nested_structs()).map(|st: &Struct| st.name.clone()));
+				if st.attributes.iter().any(|a| matches!(a, TypeAttr::Envelope(_))) {
+					generated.push(envelope_struct_name(&st.name));
+				}
+				if st.attributes.iter().any(|a| matches!(a, TypeAttr::Partial)) {
+					generated.push(partial_struct_name(&st.name));
+				}
+			}
+			EndpointDataType::Enum(en) => generated.push(en.name.clone()),
+			EndpointDataType::Proto(proto) => generated.push(proto.name.clone()),
+			// References don't generate anything.
+			EndpointDataType::Reference(_) => {}
+		}
+	}
+	let mut generated: Vec<Ident> = Vec::new();
+	if endpoints.iter().any(|endpoint| !endpoint.methods.is_empty()) {
+		generated.push(options.routes_name());
+	}
+	for data_type in components.iter().flat_map(|components| components.data_types.iter()) {
+		push_data_type(&mut generated, data_type);
+	}
+	for endpoint in endpoints.iter() {
+		for method in endpoint.methods.iter() {
+			let method_name = method_struct_name(options, &endpoint.name, &method.method);
+			if !method.path.is_empty() {
+				generated.push(path_struct_name(&method_name));
+			}
+			generated.push(method_name);
+			for data_type in method.data_types.iter() {
+				push_data_type(&mut generated, data_type);
+			}
+		}
+	}
+	
+	let mut names: HashMap<String, &Ident> = HashMap::new();
+	for name in generated.iter() {
+		let Some(first) = names.insert(name.to_string(), name) else {
+			continue;
+		};
+		let mut error = syn::Error::new(
+			name.span(),
+			format!("Naming: \"{name}\" is generated more than once, rename one of them with #[name = \"..\"] or #[rest:name_format = \"..\"]")
+		);
+		error.combine(syn::Error::new(
+			first.span(),
+			format!("Naming: \"{name}\" is first generated here")
+		));
+		return Err(error);
+	}
+	return Ok(());
+}
+
+#[cfg(test)]
+mod naming_tests {
+	use quote::quote;
+	use syn::Visibility;
+	use crate::parsers::struct_parameter::StructParameterSlice;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::gen_endpoint_structs;
+	
+	#[test] fn naming_controls() {
+		let mut rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			#[rest:name_format = "{endpoint}{method}{variant}"]
+			[User: {
+				GET "/api/user/{id}" => {
+					struct Response { id: u64 }
+					#[name = "UserKind"]
+					enum Kind { Admin, Member }
+				}
+				PUT "/api/user/{id}" => {
+					struct Request { name: String }
+					response: Response,
+					struct Profile<Response> { bio: String }
+				}
+			}]
+		"#).unwrap();
+		crate::parsers::naming::apply_names(&rest.options, rest.components.as_mut(), &mut rest.endpoints);
+		let names = rest.endpoints[0].methods.iter()
+			.flat_map(|m| m.data_types.iter().map(|dt| dt.name().to_string()))
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["UserGETResponse", "UserKind", "UserPUTRequest", "UserGETResponse", "Profile"]);
+		assert_eq!(rest.options.format_name(&rest.endpoints[0].name, &rest.endpoints[0].methods[0].method, ""), Some("UserGET".to_string()));
+		
+		let EndpointDataType::Struct(st) = &rest.endpoints[0].methods[0].data_types[0] else { unreachable!() };
+		let response = gen_endpoint_structs(
+			&Visibility::Inherited,
+			st.attributes.iter(),
+			&st.name,
+			&st.rest_variant,
+			&st.name,
+			StructParameterSlice::from(&st.parameters),
+		).to_string();
+		assert!(response.contains(&quote!(#[doc = "Response Variant"]).to_string()));
+		assert!(response.contains(&quote!(struct UserGETResponse { id: u64, }).to_string()));
+		assert!(syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			#[rest:name_format = "{endpoint}{path}"]
+			[User: { GET "/api/user" => { struct Response { id: u64 } } }]
+		"#).is_err());
+	}
+	
+	#[test] fn name_collisions() {
+		let collide = |dsl: &str| {
+			let mut rest = syn::parse_str::<crate::parsers::RestEndpoints>(dsl).unwrap();
+			crate::parsers::naming::apply_names(&rest.options, rest.components.as_mut(), &mut rest.endpoints);
+			crate::parsers::naming::check_collisions(&rest.options, rest.components.as_ref(), &rest.endpoints)
+		};
+		let error = collide(r#"
+			[User: {
+				GET "/api/user/{id}" => { struct Response { id: u64 } }
+				PUT "/api/user/{id}" => { struct Response { id: u64 } }
+			}]
+		"#).unwrap_err();
+		assert_eq!(error.into_iter().count(), 2);
+		assert!(collide(r#"
+			[User: { GET "/api/user" => { struct UserGET<Response> { id: u64 } } }]
+		"#).is_err());
+		assert!(collide(r#"
+			[User: { GET "/api/user" => { struct Response { home: { city: String } } enum ResponseHome { A } } }]
+		"#).is_err());
+		
+		assert!(collide(r#"
+			#[rest:name_format = "{endpoint}{method}{variant}"]
+			[User: {
+				GET "/api/user/{id}" => { struct Response { id: u64 } }
+				PUT "/api/user/{id}" => { struct Response { id: u64 } response: Response }
+			}]
+		"#).is_ok());
+	}
+}
@@ -0,0 +1,512 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{bracketed, LitStr, Path, Token};
+use syn::parse::{Parse, ParseStream};
+use crate::rest_api::SynError;
+use crate::utils::did_you_mean;
+
+thread_local! {
+	/// The [RestOptions] of the `restify!` invocation currently being compiled.
+	static OPTIONS: RefCell<RestOptions> = RefCell::new(RestOptions::default());
+}
+
+/// # RestOptions:
+/// Global options for an entire `restify!` invocation. Declared at the very top of the
+/// macro, before the first Endpoint, with the ``` #[rest:option = "value"] ``` syntax.
+///
+/// # Options:
+///   - **serde_path([Path])**: ``` #[rest:serde_path = "my_facade::serde"] ```: The path every
+///     generated ``` serde::… ``` reference will use. Also passes
+///     ``` #[serde(crate = "..")] ``` to every generated type, for crates that re-export
+///     serde or rename the dependency.
+///   - **serde_qs_path([Path])**: ``` #[rest:serde_qs_path = "my_facade::serde_qs"] ```: The
+///     path every generated ``` serde_qs::… ``` reference will use.
+///   - **serde_json_path([Path])**: ``` #[rest:serde_json_path = "my_facade::serde_json"] ```: The
+///     path every generated ``` serde_json::… ``` reference will use.
+///   - **quick_xml_path([Path])**: ``` #[rest:quick_xml_path = "my_facade::quick_xml"] ```: The
+///     path every generated ``` quick_xml::… ``` reference will use.
+///   - **rmp_serde_path([Path])**: ``` #[rest:rmp_serde_path = "my_facade::rmp_serde"] ```: The
+///     path every generated ``` rmp_serde::… ``` reference will use.
+///   - **chrono_path([Path])**: ``` #[rest:chrono_path = "my_facade::chrono"] ```: The
+///     path every generated ``` chrono::… ``` reference will use.
+///   - **time_path([Path])**: ``` #[rest:time_path = "my_facade::time"] ```: The
+///     path every generated ``` time::… ``` reference will use.
+///   - **uuid_path([Path])**: ``` #[rest:uuid_path = "my_facade::uuid"] ```: The
+///     path every generated ``` uuid::… ``` reference will use.
+///   - **rust_decimal_path([Path])**: ``` #[rest:rust_decimal_path = "my_facade::rust_decimal"] ```:
+///     The path every generated ``` rust_decimal::… ``` reference will use.
+///   - **serde_with_path([Path])**: ``` #[rest:serde_with_path = "my_facade::serde_with"] ```:
+///     The path every generated ``` serde_with::… ``` reference will use.
+///   - **futures_path([Path])**: ``` #[rest:futures_path = "my_facade::futures"] ```: The
+///     path every generated ``` futures::… ``` reference will use.
+///   - **prost_path([Path])**: ``` #[rest:prost_path = "my_facade::prost"] ```: The
+///     path every generated ``` prost::… ``` reference will use.
+///   - **serde_urlencoded_path([Path])**: ``` #[rest:serde_urlencoded_path = "my_facade::serde_urlencoded"] ```:
+///     The path every generated ``` serde_urlencoded::… ``` reference will use.
+///   - **name_format([LitStr])**: ``` #[rest:name_format = "{endpoint}{method}{variant}"] ```:
+///     How Restify names the Types it composes itself, from the ``` {endpoint} ```,
+///     ``` {method} ``` and ``` {variant} ``` placeholders. Applies to every REST Method
+///     struct, where ``` {variant} ``` is empty, and to every struct declared only by its
+///     REST component, i.e., ``` struct Response { .. } ```.
+///   - **debug(bool)**: ``` #[rest:debug] ```: Dumps the generated code, formatted with rustfmt,
+///     into ``` generated/ ```, within the output directory. Also enabled for every invocation
+///     by setting the ``` RESTIFY_DEBUG ``` environment variable.
+///   - **out_dir([LitStr])**: ``` #[rest:out_dir = "target/restify"] ```: The directory debug and
+///     export files are written into, relative to the invoking crate's manifest directory,
+///     see [RestOptions::output_path].
+///   - **routes([Ident])**: ``` #[rest:routes = "UserRoutes"] ```: The name of the generated
+///     ``` Routes ``` enum. Only one invocation declaring REST Methods per module can leave it
+///     as ``` Routes ```, see [RestOptions::routes_name].
+///
+/// Along with options derived from the invocation, rather than declared:
+///   - **support_mod([Ident])**: The name of the invocation's runtime-support module, see
+///     [gen_runtime_support](crate::generators::support::gen_runtime_support).
+///
+/// # Parser Location:
+/// ```ignore
+/// restify!{
+///   <START>#[rest:serde_path = "my_facade::serde"]<END>
+///   [MyEndpoint: {
+///     ...
+///   }]
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct RestOptions {
+	pub serde_path: Option<Path>,
+	pub serde_qs_path: Option<Path>,
+	pub serde_json_path: Option<Path>,
+	pub serde_urlencoded_path: Option<Path>,
+	pub quick_xml_path: Option<Path>,
+	pub rmp_serde_path: Option<Path>,
+	pub prost_path: Option<Path>,
+	pub futures_path: Option<Path>,
+	pub chrono_path: Option<Path>,
+	pub time_path: Option<Path>,
+	pub uuid_path: Option<Path>,
+	pub rust_decimal_path: Option<Path>,
+	pub serde_with_path: Option<Path>,
+	pub name_format: Option<LitStr>,
+	pub debug: bool,
+	pub out_dir: Option<LitStr>,
+	pub routes: Option<Ident>,
+	pub support_mod: Option<Ident>,
+}
+
+impl RestOptions {
+	/// The name of every option, for suggestions on unknown names.
+	pub const NAMES: [&'static str; 17] = [
+		"serde_path", "serde_qs_path", "serde_json_path", "serde_urlencoded_path", "quick_xml_path",
+		"rmp_serde_path", "prost_path", "futures_path", "chrono_path", "time_path", "uuid_path",
+		"rust_decimal_path", "serde_with_path", "name_format", "debug", "out_dir", "routes",
+	];
+	
+	/// Sets these options as the options of the `restify!` invocation currently being compiled.
+	pub fn install(self) {
+		OPTIONS.with(|options| *options.borrow_mut() = self);
+	}
+	
+	/// Runs the provided closure with the [RestOptions] of the current `restify!` invocation.
+	pub fn with<R>(f: impl FnOnce(&RestOptions) -> R) -> R {
+		return OPTIONS.with(|options| f(&options.borrow()));
+	}
+	
+	/// Returns the path generated code should use to reference serde.
+	pub fn serde() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_path {
+			Some(path) => quote!(#path),
+			None => quote!(::serde),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference serde_qs.
+	pub fn serde_qs() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_qs_path {
+			Some(path) => quote!(#path),
+			None => quote!(::serde_qs),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference serde_json.
+	pub fn serde_json() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_json_path {
+			Some(path) => quote!(#path),
+			None => quote!(::serde_json),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference serde_urlencoded.
+	pub fn serde_urlencoded() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_urlencoded_path {
+			Some(path) => quote!(#path),
+			None => quote!(::serde_urlencoded),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference quick-xml.
+	pub fn quick_xml() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.quick_xml_path {
+			Some(path) => quote!(#path),
+			None => quote!(::quick_xml),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference rmp-serde.
+	pub fn rmp_serde() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.rmp_serde_path {
+			Some(path) => quote!(#path),
+			None => quote!(::rmp_serde),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference prost.
+	pub fn prost() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.prost_path {
+			Some(path) => quote!(#path),
+			None => quote!(::prost),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference futures.
+	pub fn futures() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.futures_path {
+			Some(path) => quote!(#path),
+			None => quote!(::futures),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference chrono.
+	pub fn chrono() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.chrono_path {
+			Some(path) => quote!(#path),
+			None => quote!(::chrono),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference time.
+	pub fn time() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.time_path {
+			Some(path) => quote!(#path),
+			None => quote!(::time),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference uuid.
+	pub fn uuid() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.uuid_path {
+			Some(path) => quote!(#path),
+			None => quote!(::uuid),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference rust_decimal.
+	pub fn rust_decimal() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.rust_decimal_path {
+			Some(path) => quote!(#path),
+			None => quote!(::rust_decimal),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference serde_with.
+	pub fn serde_with() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_with_path {
+			Some(path) => quote!(#path),
+			None => quote!(::serde_with),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference the invocation's runtime-support
+	/// module, i.e., ``` __restify_user ```
+	pub fn support() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.support_mod {
+			Some(support) => quote!(#support),
+			None => quote!(__restify),
+		});
+	}
+	
+	/// Returns the path generated code should use to reference the Types shared by every Type
+	/// of the invocation, i.e., ``` __restify_user::shared::PathParseError ```
+	pub fn shared() -> TokenStream2 {
+		let support = RestOptions::support();
+		return quote!( #support::shared );
+	}
+	
+	/// Returns true if the generated code should be dumped, either through ``` #[rest:debug] ```
+	/// or the ``` RESTIFY_DEBUG ``` environment variable. ``` RESTIFY_DEBUG=0 ``` leaves it off.
+	pub fn debug() -> bool {
+		let env = std::env::var("RESTIFY_DEBUG").is_ok_and(|debug| !debug.is_empty() && debug != "0");
+		return env || RestOptions::with(|options| options.debug);
+	}
+	
+	/// Returns the path of a debug or export file, relative to the output directory:
+	///   1. ``` #[rest:out_dir = ".."] ```, relative to the invoking crate's manifest directory.
+	///   2. ``` OUT_DIR ```, when the invoking crate has a build script.
+	///   3. The invoking crate's manifest directory.
+	pub fn output_path(file: &str) -> PathBuf {
+		let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+		let out_dir = RestOptions::with(|options| options.out_dir.as_ref().map(LitStr::value));
+		let out_dir = match (out_dir, std::env::var("OUT_DIR")) {
+			(Some(out_dir), _) => manifest_dir.join(out_dir),
+			(None, Ok(out_dir)) => PathBuf::from(out_dir),
+			(None, Err(_)) => manifest_dir,
+		};
+		return out_dir.join(file);
+	}
+	
+	/// Returns the name of the generated ``` Routes ``` enum, as declared with
+	/// ``` #[rest:routes = ".."] ```. Defaults to ``` Routes ```
+	pub fn routes_name(&self) -> Ident {
+		return match &self.routes {
+			Some(routes) => routes.clone(),
+			None => Ident::new("Routes", proc_macro2::Span::call_site()),
+		};
+	}
+	
+	/// Returns the name of a Type composed by Restify, formatted with name_format.
+	/// Returns None if name_format wasn't declared.
+	pub fn format_name(&self, endpoint: &Ident, method: &Ident, variant: &str) -> Option<String> {
+		let format = self.name_format.as_ref()?;
+		return Some(format_name(format, &endpoint.to_string(), &method.to_string(), variant));
+	}
+	
+	/// Quotes ``` #[serde(crate = "..")] ``` if serde_path was overridden. serde's derive
+	/// macros need it to find serde when it isn't a direct dependency.
+	pub fn quote_serde_crate() -> TokenStream2 {
+		return RestOptions::with(|options| match &options.serde_path {
+			Some(path) => {
+				let path = quote!(#path).to_string().replace(' ', "");
+				quote!( #[serde(crate = #path)] )
+			}
+			None => quote!(),
+		});
+	}
+	
+	/// Returns true if the next tokens within the ParseStream are the start of a RestOption,
+	/// i.e., ``` #[rest: ```
+	pub fn peek(input: ParseStream) -> bool {
+		let fork = input.fork();
+		if fork.parse::<Token![#]>().is_err() {
+			return false;
+		}
+		let bracketed = (|| -> syn::Result<bool> {
+			let content;
+			bracketed!(content in fork);
+			let ident = content.parse::<Ident>()?;
+			return Ok(ident == "rest" && content.peek(Token![:]));
+		})();
+		return bracketed.unwrap_or(false);
+	}
+}
+
+impl Parse for RestOptions {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut options = RestOptions::default();
+		while RestOptions::peek(input) {
+			input.parse::<Token![#]>()?;
+			let content;
+			bracketed!(content in input);
+			content.parse::<Ident>()?;
+			content.parse::<Token![:]>()?;
+			
+			let option = content.parse::<Ident>()?;
+			// Flags are declared without a value, i.e., #[rest:debug]
+			if option == "debug" {
+				if !content.is_empty() {
+					return Err(SynError::new(
+						content.span(),
+						"RestOption - debug doesn't take a value, i.e., #[rest:debug]"
+					));
+				}
+				options.debug = true;
+				continue;
+			}
+			content.parse::<Token![=]>()
+				.map_err(|syn| SynError::new(
+					syn.span(),
+					"RestOption - Expected an equal sign, i.e., #[rest:option = \"value\"]"
+				))?;
+			let value = content.parse::<LitStr>()
+				.map_err(|syn| SynError::new(
+					syn.span(),
+					"RestOption - Expected a string literal, i.e., #[rest:option = \"value\"]"
+				))?;
+			
+			match option.to_string().as_str() {
+				"serde_path" => {
+					options.serde_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"serde_qs_path" => {
+					options.serde_qs_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"serde_json_path" => {
+					options.serde_json_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"serde_urlencoded_path" => {
+					options.serde_urlencoded_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"quick_xml_path" => {
+					options.quick_xml_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"rmp_serde_path" => {
+					options.rmp_serde_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"prost_path" => {
+					options.prost_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"futures_path" => {
+					options.futures_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"chrono_path" => {
+					options.chrono_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"time_path" => {
+					options.time_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"uuid_path" => {
+					options.uuid_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"rust_decimal_path" => {
+					options.rust_decimal_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"serde_with_path" => {
+					options.serde_with_path = Some(value.parse_with(Path::parse_mod_style)?);
+				}
+				"name_format" => {
+					let example = format_name(&value, "Endpoint", "GET", "Response");
+					if example.contains(['{', '}']) {
+						return Err(SynError::new(
+							value.span(),
+							"RestOption - Unknown name_format placeholder, expected {endpoint}, {method} or {variant}"
+						));
+					}
+					if syn::parse_str::<Ident>(&example).is_err() {
+						return Err(SynError::new(
+							value.span(),
+							"RestOption - name_format must produce a valid identifier"
+						));
+					}
+					options.name_format = Some(value);
+				}
+				"out_dir" => {
+					if value.value().is_empty() {
+						return Err(SynError::new(
+							value.span(),
+							"RestOption - out_dir can't be empty"
+						));
+					}
+					options.out_dir = Some(value);
+				}
+				"routes" => {
+					let routes = value.parse::<Ident>()
+						.map_err(|_| SynError::new(
+							value.span(),
+							"RestOption - routes must be a valid identifier, i.e., #[rest:routes = \"UserRoutes\"]"
+						))?;
+					options.routes = Some(routes);
+				}
+				unknown => {
+					return Err(SynError::new(
+						option.span(),
+						format!("RestOption - Unknown option found: \"{unknown}\"{}", did_you_mean(unknown, &RestOptions::NAMES))
+					));
+				}
+			}
+		}
+		return Ok(options);
+	}
+}
+
+impl Debug for RestOptions {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let path = |path: &Option<Path>| path.as_ref().map(|p| quote!(#p).to_string());
+		write!(
+			f,
+			"serde_path: {:?}, serde_qs_path: {:?}, serde_json_path: {:?}, serde_urlencoded_path: {:?}, quick_xml_path: {:?}, rmp_serde_path: {:?}, prost_path: {:?}, futures_path: {:?}, chrono_path: {:?}, time_path: {:?}, uuid_path: {:?}, rust_decimal_path: {:?}, serde_with_path: {:?}, name_format: {:?}, debug: {}, out_dir: {:?}, routes: {:?}",
+			path(&self.serde_path),
+			path(&self.serde_qs_path),
+			path(&self.serde_json_path),
+			path(&self.serde_urlencoded_path),
+			path(&self.quick_xml_path),
+			path(&self.rmp_serde_path),
+			path(&self.prost_path),
+			path(&self.futures_path),
+			path(&self.chrono_path),
+			path(&self.time_path),
+			path(&self.uuid_path),
+			path(&self.rust_decimal_path),
+			path(&self.serde_with_path),
+			self.name_format.as_ref().map(LitStr::value),
+			self.debug,
+			self.out_dir.as_ref().map(LitStr::value),
+			self.routes.as_ref().map(Ident::to_string),
+		)
+	}
+}
+
+/// Substitutes every placeholder of a name_format.
+fn format_name(format: &LitStr, endpoint: &str, method: &str, variant: &str) -> String {
+	return format.value()
+		.replace("{endpoint}", endpoint)
+		.replace("{method}", method)
+		.replace("{variant}", variant);
+}
+
+#[cfg(test)]
+mod options_tests {
+	use quote::quote;
+	use crate::parsers::options::RestOptions;
+	use crate::generators::generate;
+	
+	#[test] fn debug_option() {
+		let options = syn::parse_str::<RestOptions>(r#"#[rest:debug] #[rest:name_format = "{endpoint}{method}"]"#).unwrap();
+		assert!(options.debug);
+		assert!(options.name_format.is_some());
+		assert!(!syn::parse_str::<RestOptions>(r#"#[rest:name_format = "{endpoint}{method}"]"#).unwrap().debug);
+		assert!(syn::parse_str::<RestOptions>(r#"#[rest:debug = "true"]"#).is_err());
+	}
+	
+	#[test] fn out_dir_option() {
+		let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+		syn::parse_str::<RestOptions>(r#"#[rest:out_dir = "target/restify"]"#).unwrap().install();
+		assert_eq!(RestOptions::output_path("user.rs"), manifest_dir.join("target/restify/user.rs"));
+		RestOptions::default().install();
+		assert_eq!(RestOptions::output_path("user.rs"), manifest_dir.join("user.rs"));
+		assert!(syn::parse_str::<RestOptions>(r#"#[rest:out_dir = ""]"#).is_err());
+	}
+	
+	#[test] fn serde_path_override() {
+		syn::parse_str::<RestOptions>(r#"
+			#[rest:serde_path = "my_facade::serde"]
+			#[rest:serde_qs_path = "my_facade::serde_qs"]
+		"#).unwrap().install();
+		
+		let query = generate(r#"
+			struct Query {
+				page: u32,
+			}
+		"#);
+		assert!(query.contains(&quote!(my_facade::serde::Serialize).to_string()));
+		assert!(query.contains(&quote!(#[serde(crate = "my_facade::serde")]).to_string()));
+		assert!(query.contains(&quote!(my_facade::serde_qs::to_string(&self)).to_string()));
+		
+		let en = generate(r#"
+			enum Status {
+				Active,
+			}
+		"#);
+		assert!(en.contains(&quote!(my_facade::serde::Deserialize).to_string()));
+		
+		RestOptions::default().install();
+		let request = generate(r#"
+			struct Request {
+				id: u64,
+			}
+		"#);
+		assert!(!request.contains("my_facade"));
+		assert!(!request.contains("crate ="));
+	}
+}
@@ -4,7 +4,7 @@ use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
 use std::fmt::{self, Formatter};
 use proc_macro2::Ident;
 use quote::quote;
-use syn::Type;
+use syn::{LitInt, Type};
 
 pub struct Enum {
 	pub attributes: Attrs<TypeAttr>,
@@ -16,8 +16,118 @@ impl Enum {
 		self.attributes = attributes;
 		return self;
 	}
+	
+	/// Tests the placement of an enumeration marked with ``` #[other] ```.
+	/// Serde only accepts a single **other** variant per enum, which must be the
+	/// last unit variant of an enum that isn't untagged.
+	pub fn check_other_variant(&self) -> syn::Result<()> {
+		let is_other = |e: &&Enumeration| e.attributes.iter().any(|a| matches!(a, ParamAttr::Other));
+		let others: Vec<&Enumeration> = self.enums.iter().filter(is_other).collect();
+		
+		if let Some(second) = others.get(1) {
+			return Err(syn::Error::new(
+				second.ident.span(),
+				"Enum: Only one variant can be marked with #[other]"
+			));
+		}
+		let Some(other) = others.first() else {
+			return Ok(());
+		};
+		if !matches!(other.param, EnumParameter::Variant) {
+			return Err(syn::Error::new(
+				other.ident.span(),
+				"Enum: A variant marked with #[other] must be a unit variant"
+			));
+		}
+		if self.enums.last().is_none_or(|last| last.ident != other.ident) {
+			return Err(syn::Error::new(
+				other.ident.span(),
+				"Enum: A variant marked with #[other] must be the last variant"
+			));
+		}
+		if self.attributes.iter().any(|a| matches!(a, TypeAttr::Untagged)) {
+			return Err(syn::Error::new(
+				other.ident.span(),
+				"Enum: #[other] can't be used within an untagged enum"
+			));
+		}
+		return Ok(());
+	}
+	
+	/// Tests that at most a single variant was marked with ``` #[default] ```.
+	pub fn check_default_variant(&self) -> syn::Result<()> {
+		if let Some(second) = self.enums.iter().filter(|e| e.is_default()).nth(1) {
+			return Err(syn::Error::new(
+				second.ident.span(),
+				"Enum: Only one variant can be marked with #[default]"
+			));
+		}
+		return Ok(());
+	}
+	
+	/// Returns the integer type of a ``` #[repr(..)] ``` Enum, if it was declared.
+	pub fn repr(&self) -> Option<&Ident> {
+		return self.attributes.iter().find_map(|a| match a {
+			TypeAttr::Repr(ty) => Some(ty),
+			_ => None,
+		});
+	}
+	
+	/// Tests that an Enum can be (de)serialized via serde_repr:
+	///   - Every variant must be a unit variant.
+	///   - Neither the Enum nor its variants can carry serde attributes, since serde isn't derived.
+	pub fn check_repr(&self) -> syn::Result<()> {
+		let Some(repr) = self.repr() else {
+			return Ok(());
+		};
+		let serde_attr = self.attributes.iter().any(|a| matches!(a,
+			TypeAttr::RenameAll(_) | TypeAttr::Remote(_) | TypeAttr::DenyUnknownFields
+			| TypeAttr::Tag(_) | TypeAttr::Content(_) | TypeAttr::Untagged
+		));
+		if serde_attr {
+			return Err(syn::Error::new(
+				repr.span(),
+				"Enum: A #[repr(..)] Enum can't be combined with serde attributes"
+			));
+		}
+		for enumeration in self.enums.iter() {
+			if !matches!(enumeration.param, EnumParameter::Variant) {
+				return Err(syn::Error::new(
+					enumeration.ident.span(),
+					"Enum: A #[repr(..)] Enum can only contain unit variants"
+				));
+			}
+			if enumeration.attributes.iter().any(|a| !matches!(a, ParamAttr::DefaultVariant)) {
+				return Err(syn::Error::new(
+					enumeration.ident.span(),
+					"Enum: Variants of a #[repr(..)] Enum can't have serde attributes"
+				));
+			}
+		}
+		return Ok(());
+	}
+	
+	/// Enums always derive Debug, so ``` #[sensitive] ``` can't redact anything within them.
+	/// Likewise, ``` #[secret] ``` fields can't be serialized within them.
+	/// Rejects both attributes instead of silently leaking the secret.
+	pub fn check_sensitive(&self) -> syn::Result<()> {
+		for enumeration in self.enums.iter() {
+			let is_secret = |a: &ParamAttr| matches!(a, ParamAttr::Sensitive | ParamAttr::Secret);
+			let sensitive = enumeration.attributes.iter().any(is_secret)
+				|| matches!(&enumeration.param, EnumParameter::Struct(params)
+					if params.iter().any(|p| p.attributes.iter().any(is_secret)));
+			if sensitive {
+				return Err(syn::Error::new(
+					enumeration.ident.span(),
+					"Enum: #[sensitive] and #[secret] are only supported on struct fields"
+				));
+			}
+		}
+		return Ok(());
+	}
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum EnumParameter {
 	Tuple {
 		ty: Type,
@@ -29,27 +139,37 @@ pub enum EnumParameter {
 
 
 pub struct Enumeration {
-	pub attributes : Attrs<ParamAttr>,
-	pub ident      : Ident,
-	pub param      : EnumParameter,
+	pub attributes   : Attrs<ParamAttr>,
+	pub ident        : Ident,
+	pub discriminant : Option<LitInt>,
+	pub param        : EnumParameter,
+}
+impl Enumeration {
+	/// Returns true if this variant was marked with ``` #[default] ```
+	pub fn is_default(&self) -> bool {
+		return self.attributes.iter().any(|a| matches!(a, ParamAttr::DefaultVariant));
+	}
 }
 
 impl fmt::Display for Enumeration {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-		write!(f, "{}", self.ident.to_string())?;
+		write!(f, "{}", self.ident)?;
+		if let Some(discriminant) = &self.discriminant {
+			write!(f, " = {}", discriminant)?;
+		}
 		
 		match &self.param {
-			EnumParameter::Variant => write!(f, ",\n")?,
+			EnumParameter::Variant => writeln!(f, ",")?,
 			EnumParameter::Tuple {ty, opt} => {
 				let ty = if !opt {
 					quote! { #ty }
 				} else {
-					quote! { Option<#ty> }
+					quote! { ::core::option::Option<#ty> }
 				};
-				write!(f, "({}),\n", ty.to_string())?
+				writeln!(f, "({}),", ty)?
 			},
 			EnumParameter::Struct(st) => {
-				write!(f, " {{\n")?;
+				writeln!(f, " {{")?;
 				for s in st.iter() {
 					write!(f, "\t {s}")?;
 				}
@@ -62,6 +182,7 @@ impl fmt::Display for Enumeration {
 pub struct EnumsSlice<'s> {
 	slice: &'s [Enumeration],
 	current: usize,
+	no_auto_serde: bool,
 }
 impl<'s> Iterator for EnumsSlice<'s> {
 	type Item = &'s Enumeration;
@@ -79,7 +200,8 @@ impl<'s> From<&'s Vec<Enumeration>> for EnumsSlice<'s> {
 	fn from(slice: &'s Vec<Enumeration>) -> Self {
 		Self {
 			slice: slice.as_slice(),
-			current: 0
+			current: 0,
+			no_auto_serde: false,
 		}
 	}
 }
@@ -88,52 +210,97 @@ impl<'s> EnumsSlice<'s> {
 	pub fn len(&self) -> usize {
 		self.slice.len()
 	}
-	pub fn iter(&self) -> EnumsSlice {
+	pub fn iter(&self) -> EnumsSlice<'_> {
 		EnumsSlice {
-			slice: &self.slice,
-			current: 0
+			slice: self.slice,
+			current: 0,
+			no_auto_serde: self.no_auto_serde,
 		}
 	}
+	/// Builder: When true, Restify will stop injecting serde attributes into the
+	/// optional fields of every Struct Variant.
+	pub fn with_no_auto_serde(mut self, no_auto_serde: bool) -> Self {
+		self.no_auto_serde = no_auto_serde;
+		return self;
+	}
+	/// Returns true if a variant was marked with ``` #[default] ```, in which case the Enum derives
+	/// ``` Default ```.
+	pub fn has_default_variant(&self) -> bool {
+		return self.iter().any(Enumeration::is_default);
+	}
 	pub fn quote_fields(&self) -> Vec<TokenStream2> {
 		return self.iter().map(|enumeration| {
-			let Enumeration { attributes, ident, param } = enumeration;
+			let Enumeration { attributes, ident, discriminant, param } = enumeration;
 			
 			let compiled_attributes: CompiledAttrs<ParamAttr> = attributes.into();
 			let quotes = compiled_attributes.quotes_ref();
+			let discriminant = discriminant.as_ref().map(|d| quote!(= #d));
 			
 			//TODO: Implement quote_attributes -> Include in all quotes
 			match param {
 				EnumParameter::Variant => {
 					let output = quote!{
 						#( #quotes )*
-						#ident,
+						#ident #discriminant,
 					};
-					output.into()
+					output
 				}
 				EnumParameter::Tuple {ty, opt} => {
 					let output = if *opt {
 						quote!{
 						#( #quotes )*
-							#ident(Option<#ty>),
+							#ident(::core::option::Option<#ty>),
 						}
 					} else {
 						quote!{
+							#( #quotes )*
 							#ident(#ty),
 						}};
-					output.into()
+					output
 				}
 				EnumParameter::Struct(st) => {
-					let slice: StructParameterSlice = st.into();
+					let slice = StructParameterSlice::from(st)
+						.with_no_auto_serde(self.no_auto_serde);
 					let params = slice.quote_enum_struct_params();
 					
 					let output = quote!{
+						#( #quotes )*
 						#ident {
 							#( #params )*
 						},
 					};
-					output.into()
+					output
 				}
 			}
 		}).collect();
 	}
+	
+	/// # Enumerations: serde_repr
+	/// Quotes the unit variants of a ``` #[repr(..)] ``` Enum, assigning each variant
+	/// an explicit discriminant. Variants without a declared discriminant continue
+	/// counting from the previous variant, same as rustc would.
+	pub fn quote_repr_fields(&self) -> Vec<TokenStream2> {
+		let mut next: i128 = 0;
+		return self.iter().map(|enumeration| {
+			let ident = &enumeration.ident;
+			let value = enumeration.discriminant
+				.as_ref()
+				.and_then(|d| d.base10_parse::<i128>().ok())
+				.unwrap_or(next);
+			next = value + 1;
+			
+			let discriminant = LitInt::new(&value.to_string(), ident.span());
+			// serde_repr takes no variant attributes, only doc comments and #[default] are carried over.
+			let docs = enumeration.attributes.iter().filter_map(|a| match a {
+				ParamAttr::Doc(doc) => Some(quote!( #[doc = #doc] )),
+				ParamAttr::DefaultVariant => Some(quote!( #[default] )),
+				_ => None,
+			});
+			let output = quote!{
+				#( #docs )*
+				#ident = #discriminant,
+			};
+			output
+		}).collect();
+	}
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use proc_macro2::Ident;
+use syn::Path;
+use crate::attributes::{Attrs, TypeAttr};
+
+/// # Proto:
+/// A REST component whose body is a prost-generated Protobuf message, rather than a serde
+/// Struct. Encoded and decoded through ``` prost::Message ```, letting gRPC-gateway style
+/// endpoints share the DSL.
+///
+/// # Parameters:
+///   - [Attribute] attributes: Only ``` #[name = ".."] ``` applies to a Proto.
+///   - [Ident] name: The provided name, to be used for naming the resulting newtype.
+///   - [Option]<[Ident]> rest_variant: The Rest Component Variant, when not declared by **name**.
+///   - [Path] message: The path of the prost-generated message type, i.e., ``` pb::UserReply ```.
+///
+/// # Parser Location:
+/// ```ignore
+/// GET "/api/user/{id}" => {
+///   <START>proto Response = pb::UserReply;<END>
+/// }
+/// ```
+pub struct Proto {
+	pub attributes: Attrs<TypeAttr>,
+	pub name: Ident,
+	pub rest_variant: Option<Ident>,
+	pub message: Path,
+}
+impl Proto {
+	pub fn with_attributes(mut self, attributes: Attrs<TypeAttr>) -> Self {
+		self.attributes = attributes;
+		return self;
+	}
+	/// Returns the REST component this Proto was declared as, i.e., ``` Response ```.
+	pub fn variant(&self) -> &Ident {
+		return self.rest_variant.as_ref().unwrap_or(&self.name);
+	}
+}
@@ -1,6 +1,10 @@
 use proc_macro2::Ident;
+use syn::{parse_quote, GenericParam, Generics, LifetimeParam, Type};
 use crate::attributes::{Attrs, TypeAttr};
-use crate::parsers::struct_parameter::StructParameter;
+use crate::attributes::{ValidateAction, ValidateBackend};
+use crate::parsers::struct_parameter::{StructParameter, StructParameterSlice};
+use crate::parsers::tools::{is_option_type, option_inner_type, type_lifetimes};
+use crate::utils::camelCase;
 
 /// # Struct:
 /// A Data type for holding the data parsed from `restify!`s TokenStream input.
@@ -10,11 +14,15 @@ use crate::parsers::struct_parameter::StructParameter;
 ///   - [Attribute] attributes: An attribute is a special command to tell the code generator to either
 ///     include special tokens for the final product. Or how it should generate the final code.
 ///   - [Ident] name: The provided name, to be used for naming the resulting struct.
+///   - [Generics] generics: The struct's generic parameters and where clause, i.e.,
+///     ``` struct Page<T><Response> ```. Carried over onto every generated implementation.
 ///   - [Option]<[Ident]> rest_variant: An Optional parameter for holding the
 ///     information that describes the Rest Component Variant for the resulting struct.
 ///     it will determine what functionalities will be generated for said struct.
 ///   - [Vec]<[StructParameter]> parameters: A SubStructure for 'Struct' which will contain
 ///     all the parsed struct parameters extracted from `restify`s original TokenStream.
+///   - [Vec]<[Struct]> nested: The structs generated for the inline nested fields of this
+///     Struct, i.e., ``` address: { street: String } ```. See [Struct::hoist_nested]
 pub struct Struct {
 	//TODO: Lifetime Parsing.
 	// From syn's Documentation
@@ -22,12 +30,175 @@ pub struct Struct {
 	// | A lifetime is not an identifier. Use syn::Lifetime instead.
 	pub attributes: Attrs<TypeAttr>,
 	pub name: Ident,
+	pub generics: Generics,
 	pub rest_variant: Option<Ident>,
 	pub parameters: Vec<StructParameter>,
+	pub nested: Vec<Struct>,
 }
 impl Struct {
 	pub fn with_attributes(mut self, attributes: Attrs<TypeAttr>) -> Self {
 		self.attributes = attributes;
 		return self;
 	}
-}
\ No newline at end of file
+	/// Takes the fields of every inline nested field, ``` address: { street: String } ```,
+	/// into a struct named after its parent and the field, i.e., ``` UserAddress ```.
+	/// The nested struct's name is substituted for the field's Type, and it shares
+	/// its parent's REST Variant.
+	pub fn hoist_nested(
+		parent     : &Ident,
+		variant    : &Ident,
+		parameters : &mut [StructParameter],
+	) -> Vec<Struct> {
+		return parameters.iter_mut().filter_map(|param| {
+			let mut fields = param.nested.take()?;
+			let name = camelCase(&[parent.to_string().as_str(), param.name.to_string().as_str()], true);
+			let name = Ident::new(&name, param.name.span());
+			let nested = Struct::hoist_nested(&name, variant, &mut fields);
+			// Lifetimes borrowed by the nested fields are declared on the nested struct.
+			let generics = Struct::with_field_lifetimes(Generics::default(), &fields);
+			let (_, ty_generics, _) = generics.split_for_impl();
+			param.ty = parse_quote!(#name #ty_generics);
+			Some(Struct {
+				attributes: Attrs::default(),
+				name,
+				generics,
+				rest_variant: Some(variant.clone()),
+				parameters: fields,
+				nested,
+			})
+		}).collect();
+	}
+	/// Declares every lifetime used by the provided fields' Types that wasn't already
+	/// declared, i.e., ``` name: &'a str ``` declares ``` 'a ``` on the parent struct.
+	pub fn with_field_lifetimes(mut generics: Generics, parameters: &[StructParameter]) -> Generics {
+		let declared = generics.lifetimes().map(|param| param.lifetime.clone()).collect::<Vec<_>>();
+		let mut undeclared = parameters.iter()
+			.flat_map(|param| type_lifetimes(&param.ty))
+			.filter(|lifetime| !declared.contains(lifetime))
+			.collect::<Vec<_>>();
+		undeclared.dedup();
+		// Lifetime parameters are declared ahead of every other generic parameter.
+		for (i, lifetime) in undeclared.into_iter().enumerate() {
+			generics.params.insert(i, GenericParam::Lifetime(LifetimeParam::new(lifetime)));
+		}
+		if generics.lt_token.is_none() && !generics.params.is_empty() {
+			generics.lt_token = Some(Default::default());
+			generics.gt_token = Some(Default::default());
+		}
+		return generics;
+	}
+	/// # Struct: Zero-Copy
+	/// Applies ``` #[zero_copy] ```: every ``` String ``` and ``` Option<String> ``` field of
+	/// this Struct and its nested structs becomes a ``` Cow<'a, str> ```, and ``` 'a ``` is
+	/// declared on every struct borrowing it, including through a nested struct.
+	pub fn zero_copy(&mut self) {
+		let mut nested_types: Vec<(Ident, Type)> = Vec::new();
+		for nested in self.nested.iter_mut() {
+			nested.zero_copy();
+			let name = &nested.name;
+			let (_, ty_generics, _) = nested.generics.split_for_impl();
+			nested_types.push((name.clone(), parse_quote!(#name #ty_generics)));
+		}
+		for param in self.parameters.iter_mut() {
+			if let Some(ty) = zero_copy_type(&param.ty) {
+				param.ty = ty;
+				continue;
+			}
+			let Type::Path(path) = &param.ty else { continue };
+			let Some(segment) = path.path.segments.last() else { continue };
+			if let Some((_, ty)) = nested_types.iter().find(|(name, _)| name == &segment.ident) {
+				param.ty = ty.clone();
+			}
+		}
+		self.generics = Struct::with_field_lifetimes(self.generics.clone(), &self.parameters);
+	}
+	/// Returns every nested struct of this Struct, including the nested structs of those.
+	pub fn nested_structs(&self) -> Vec<&Struct> {
+		return self.nested.iter()
+			.flat_map(|nested| std::iter::once(nested).chain(nested.nested_structs()))
+			.collect();
+	}
+	/// Returns the Identifier holding this Struct's REST Variant.
+	/// Either the declared variant, ``` struct MyStruct<Request> ```,
+	/// or the struct name itself, ``` struct Request ```.
+	pub fn variant(&self) -> &Ident {
+		return self.rest_variant.as_ref().unwrap_or(&self.name);
+	}
+	/// Returns true if a ``` validate() ``` method will be generated for this Struct.
+	/// Not the case when validation is delegated to a [ValidateBackend].
+	pub fn has_validate(&self) -> bool {
+		if self.validate_backend() != ValidateBackend::Restify {
+			return false;
+		}
+		return self.attributes.iter().any(|a| matches!(a, TypeAttr::Validate(_)))
+			|| StructParameterSlice::from(&self.parameters).has_validate();
+	}
+	/// Returns every type-level Validate Action of this Struct.
+	fn validate_rules(&self) -> impl Iterator<Item = &ValidateAction<TypeAttr>> {
+		return self.attributes.iter().filter_map(|a| match a {
+			TypeAttr::Validate(chain) => Some(chain.actions.iter()),
+			_ => None,
+		}).flatten();
+	}
+	/// Returns the [ValidateBackend] this Struct's validation is delegated to.
+	pub fn validate_backend(&self) -> ValidateBackend {
+		return ValidateBackend::from_actions(self.validate_rules());
+	}
+	/// Checks that every field named by a type-level ``` #[validate(..)] ``` rule exists,
+	/// and is optional. A required field is always set, so those rules would be meaningless.
+	/// Also checks that every Validate Action is supported by the Struct's [ValidateBackend].
+	pub fn check_validate_rules(&self) -> syn::Result<()> {
+		let backends = self.validate_rules()
+			.filter(|rule| matches!(rule, ValidateAction::Backend(_)))
+			.count();
+		if backends > 1 {
+			return Err(syn::Error::new(
+				self.name.span(),
+				"Struct: #[validate(backend = \"..\")] can only be declared once"
+			));
+		}
+		let backend = self.validate_backend();
+		if let Some(rule) = self.validate_rules().find(|rule| !rule.supported_by(&backend)) {
+			return Err(syn::Error::new(
+				self.name.span(),
+				format!("Struct: #[validate({rule:?})] isn't supported by the \"{backend}\" backend")
+			));
+		}
+		for param in self.parameters.iter() {
+			if let Some(action) = param.validate_actions().find(|action| !action.supported_by(&backend)) {
+				return Err(syn::Error::new(
+					param.name.span(),
+					format!("Struct: #[validate({action:?})] isn't supported by the \"{backend}\" backend")
+				));
+			}
+		}
+		for field in self.validate_rules().flat_map(ValidateAction::fields) {
+			let Some(param) = self.parameters.iter().find(|p| &p.name == field) else {
+				return Err(syn::Error::new(
+					field.span(),
+					format!("Struct: #[validate(..)] refers to an unknown field \"{field}\"")
+				));
+			};
+			if !param.optional && !is_option_type(&param.ty) {
+				return Err(syn::Error::new(
+					field.span(),
+					format!("Struct: #[validate(..)] field \"{field}\" must be optional")
+				));
+			}
+		}
+		return Ok(());
+	}
+}
+
+/// Returns the zero-copy replacement of a ``` String ``` or ``` Option<String> ``` Type.
+fn zero_copy_type(ty: &Type) -> Option<Type> {
+	let is_string = |ty: &Type| matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("String"));
+	if is_string(ty) {
+		return Some(parse_quote!(::std::borrow::Cow<'a, str>));
+	}
+	let inner = option_inner_type(ty)?;
+	if is_string(inner) {
+		return Some(parse_quote!(::core::option::Option<::std::borrow::Cow<'a, str>>));
+	}
+	return None;
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use proc_macro2::Ident;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::{parse_quote, Token, Type};
+use crate::attributes::ParamAttr;
+use crate::parsers::components::Components;
+use crate::parsers::endpoint::Endpoint;
+use crate::parsers::endpoint_method::EndpointDataType;
+use crate::parsers::options::RestOptions;
+use crate::parsers::rest_enum::EnumParameter;
+use crate::parsers::struct_parameter::StructParameter;
+use crate::parsers::tools::substitute_types;
+
+/// # Scalar Types
+/// Resolves the scalar Types Restify knows by name onto every declared field, before any
+/// code is generated:
+///   - ``` Uuid ```, or a ``` String ``` field marked with ``` #[uuid] ```, resolves to
+///     ``` uuid::Uuid ```.
+///   - ``` Money ```, or a ``` String ``` field marked with ``` #[decimal] ```, resolves to
+///     ``` rust_decimal::Decimal ```. A field declared as either is (de)serialized as a
+///     string, see [ParamAttr::Decimal].
+///   - Every name declared within the top-level ``` types: { .. } ``` block resolves to its
+///     mapped Type, see [TypeMappings].
+///
+/// Names are resolved at any nesting depth, i.e., ``` Vec<?Uuid> ```. Qualified paths,
+/// i.e., ``` my_ids::Uuid ```, are left as is. Must run after the invocation's
+/// [RestOptions] were installed.
+pub fn resolve_scalars(
+	types      : Option<&TypeMappings>,
+	components : Option<&mut Components>,
+	endpoints  : &mut [Endpoint],
+) {
+	let mut scalars = scalar_types();
+	// A mapped Type may itself use a built-in name, i.e., ``` Id => Uuid ```
+	let mapped = types.iter()
+		.flat_map(|types| types.types.iter())
+		.map(|(name, ty)| (name.to_string(), substitute_types(ty, &scalars)))
+		.collect::<Vec<_>>();
+	scalars.extend(mapped);
+	for data_type in components.into_iter().flat_map(|components| components.data_types.iter_mut()) {
+		resolve(data_type, &scalars);
+	}
+	for endpoint in endpoints.iter_mut() {
+		for method in endpoint.methods.iter_mut() {
+			for data_type in method.data_types.iter_mut() {
+				resolve(data_type, &scalars);
+			}
+		}
+	}
+}
+
+/// # Type Mappings
+/// Domain names for field Types, declared once within a top-level ``` types: { .. } ```
+/// block, and substituted into every field declared with them. See [resolve_scalars]
+///
+/// # Parameters:
+///   - [Vec]<([Ident], [Type])> types: Every declared name, and the Type it's mapped to.
+///
+/// # Parser Location:
+/// ```ignore
+/// restify!{
+///   <START>types: {
+///     Timestamp => chrono::DateTime<chrono::Utc>,
+///     Id => crate::ids::UserId,
+///   }<END>
+///   [MyEndpoint: {
+///     GET "/api/user/{id}" => {
+///       struct Response { id: Id, created_at: Timestamp }
+///     }
+///   }]
+/// }
+/// ```
+pub struct TypeMappings {
+	pub types: Vec<(Ident, Type)>,
+}
+impl TypeMappings {
+	/// Returns true if the next tokens within the ParseStream are the start of a
+	/// types block, i.e., ``` types: ```
+	pub fn peek(input: ParseStream) -> bool {
+		let fork = input.fork();
+		return fork.parse::<Ident>().is_ok_and(|ident| ident == "types")
+			&& fork.peek(Token![:]);
+	}
+}
+impl Debug for TypeMappings {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "TypeMappings: {{")?;
+		for (name, ty) in self.types.iter() {
+			writeln!(f, "\t{} => {}", name, quote!(#ty))?;
+		}
+		write!(f, "}}")
+	}
+}
+
+/// Returns every scalar Type Restify knows by name, and the Type it resolves to.
+pub fn scalar_types() -> HashMap<String, Type> {
+	let uuid = RestOptions::uuid();
+	let rust_decimal = RestOptions::rust_decimal();
+	return HashMap::from([
+		("Uuid".to_string(), parse_quote!(#uuid::Uuid)),
+		("Money".to_string(), parse_quote!(#rust_decimal::Decimal)),
+	]);
+}
+
+/// Resolves the scalar Types of a single data type's fields, including its nested structs.
+pub fn resolve(data_type: &mut EndpointDataType, scalars: &HashMap<String, Type>) {
+	match data_type {
+		EndpointDataType::Struct(st) => {
+			let mut structs = vec![st];
+			while let Some(st) = structs.pop() {
+				resolve_fields(&mut st.parameters, scalars);
+				structs.extend(st.nested.iter_mut());
+			}
+		}
+		EndpointDataType::Enum(en) => {
+			for enumeration in en.enums.iter_mut() {
+				match &mut enumeration.param {
+					EnumParameter::Tuple{ ty, .. } => *ty = substitute_types(ty, scalars),
+					EnumParameter::Struct(fields) => resolve_fields(fields, scalars),
+					EnumParameter::Variant => {}
+				}
+			}
+		}
+		EndpointDataType::Proto(_) | EndpointDataType::Reference(_) => {}
+	}
+}
+
+fn resolve_fields(fields: &mut [StructParameter], scalars: &HashMap<String, Type>) {
+	for field in fields.iter_mut() {
+		// A #[uuid] field is declared as a String, which may already be wrapped, i.e., by #[patch]
+		if field.attributes.iter().any(|a| matches!(a, ParamAttr::Uuid)) {
+			field.ty = substitute_types(&field.ty, &HashMap::from([("String".to_string(), parse_quote!(Uuid))]));
+		}
+		// A Money field is (de)serialized like a #[decimal] one, unless it brings its own serde module.
+		let custom_serde = field.attributes.iter().any(|a| matches!(
+			a,
+			ParamAttr::With(_) | ParamAttr::SerializeWith(_) | ParamAttr::DeserializeWith(_)
+		));
+		if field.is_decimal() {
+			field.ty = parse_quote!(Money);
+		} else if !custom_serde && matches!(&field.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Money")) {
+			field.attributes.0.push(ParamAttr::Decimal);
+		}
+		field.ty = substitute_types(&field.ty, scalars);
+		if let Some(nested) = field.nested.as_mut() {
+			resolve_fields(nested, scalars);
+		}
+	}
+}
+
+#[cfg(test)]
+mod scalars_tests {
+	use quote::quote;
+	use syn::Visibility;
+	use crate::parsers::struct_parameter::StructParameterSlice;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	use crate::generators::gen_endpoint_structs;
+	
+	#[test] fn uuid_fields() {
+		let request = generate(r#"
+			struct Request {
+				id: Uuid,
+				#[uuid]
+				parent_id: ?String,
+				tags: Vec<Uuid>,
+				other: ids::Uuid,
+			}
+		"#);
+		assert!(request.contains(&quote!(id: ::uuid::Uuid,).to_string()));
+		assert!(request.contains(&quote!(parent_id: ::core::option::Option<::uuid::Uuid>,).to_string()));
+		assert!(request.contains(&quote!(tags: Vec<::uuid::Uuid>,).to_string()));
+		assert!(request.contains(&quote!(other: ids::Uuid,).to_string()));
+		for invalid in [
+			"struct Request { #[uuid] id: u128 }",
+			"struct Request { #[uuid] #[secret] id: String }",
+			"struct Request { #[uuid(simple)] id: String }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+	
+	#[test] fn decimal_fields() {
+		let request = generate(r#"
+			struct Request {
+				amount: Money,
+				#[decimal]
+				discount: ?String,
+				history: Vec<Money>,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			#[serde(with = "::rust_decimal::serde::str")]
+			amount: ::rust_decimal::Decimal,
+		).to_string()));
+		assert!(request.contains(&quote!(#[serde(with = "::rust_decimal::serde::str_option")]).to_string()));
+		assert!(request.contains(&quote!(discount: ::core::option::Option<::rust_decimal::Decimal>,).to_string()));
+		assert!(request.contains(&quote!(history: Vec<::rust_decimal::Decimal>,).to_string()));
+		for invalid in [
+			"struct Request { #[decimal] amount: f64 }",
+			"struct Request { #[decimal] #[with = \"money\"] amount: String }",
+			"struct Request { #[decimal] #[uuid] amount: String }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
+		}
+	}
+	
+	#[test] fn type_mappings() {
+		let mut rest = syn::parse_str::<crate::parsers::RestEndpoints>(r#"
+			types: {
+				Timestamp => chrono::DateTime<chrono::Utc>,
+				Id => Uuid,
+			}
+			[User: {
+				GET "/api/user/{id}" => {
+					struct Response {
+						id: Id,
+						friends: Vec<Id>,
+						created_at: ?Timestamp,
+					}
+				}
+			}]
+		"#).unwrap();
+		crate::parsers::scalars::resolve_scalars(rest.types.as_ref(), rest.components.as_mut(), &mut rest.endpoints);
+		let EndpointDataType::Struct(st) = &rest.endpoints[0].methods[0].data_types[0] else { unreachable!() };
+		let response = gen_endpoint_structs(
+			&Visibility::Inherited,
+			st.attributes.iter(),
+			&st.name,
+			&st.rest_variant,
+			&st.name,
+			StructParameterSlice::from(&st.parameters),
+		).to_string();
+		assert!(response.contains(&quote!(id: ::uuid::Uuid,).to_string()));
+		assert!(response.contains(&quote!(friends: Vec<::uuid::Uuid>,).to_string()));
+		assert!(response.contains(&quote!(created_at: ::core::option::Option<chrono::DateTime<chrono::Utc> >,).to_string()));
+		for invalid in [
+			"types: {}",
+			"types: { Id = u64 }",
+			"types: { Id => u64, Id => String }",
+			"types: { Uuid => u128 }",
+			"types: { Name => Cow<'a, str> }",
+		] {
+			assert!(syn::parse_str::<crate::parsers::RestEndpoints>(invalid).is_err(), "{invalid}");
+		}
+	}
+}
@@ -1,12 +1,17 @@
 use proc_macro2::TokenStream as TokenStream2;
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 use proc_macro2::Ident;
-use quote::{quote, quote_spanned};
-use syn::{Type, Visibility};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_quote, Expr, Generics, Type, TypeParamBound, Visibility};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 use crate::generators::tools::RestType;
-use crate::attributes::{Attrs, ParamAttr};
+use crate::attributes::{Attrs, CompiledAttrs, DateTimeFormat, Log, ParamAttr, ValidateAction, ValidateBackend};
+use crate::generators::datetime::{datetime_serde_attr, datetime_type, gen_datetime_fns};
 use crate::utils::doc_str::DocString;
+use crate::parsers::tools::{is_implicitly_borrowed, is_option_type, references_generics, type_lifetimes};
+use crate::parsers::options::RestOptions;
 
 /// # StructParameter:
 /// A Data type for holding the data parsed from `restify!` TokenStream input.
@@ -21,11 +26,198 @@ use crate::utils::doc_str::DocString;
 ///     This will cause the code to turn this type into an Optional value. Along with any
 ///     corresponding serde attributes, depending on the REST Component Type of the parent
 ///     struct.
+///   - [Option]<[Expr]> default: The field's default value, declared after its type,
+///     i.e., ``` count: u32 = 10 ```. Used by both serde and the generated ``` Default ```
+///   - [Option]<[Vec]<[StructParameter]>> nested: The fields of an inline nested struct,
+///     i.e., ``` address: { street: String } ```. Taken by the parent [Struct](crate::parsers::rest_struct::Struct)'s
+///     parser, which generates the nested struct, and substitutes its name for **ty**.
+#[derive(Clone)]
 pub struct StructParameter {
 	pub attributes: Attrs<ParamAttr>,
 	pub name: Ident,
 	pub ty: Type,
 	pub optional: bool,
+	pub default: Option<Expr>,
+	pub nested: Option<Vec<StructParameter>>,
+}
+
+impl StructParameter {
+	/// Returns true if this field was marked with ``` #[sensitive] ```
+	pub fn is_sensitive(&self) -> bool {
+		return self.attributes.iter().any(|a| matches!(a, ParamAttr::Sensitive));
+	}
+	
+	/// Returns true if this field was marked with ``` #[secret] ```
+	pub fn is_secret(&self) -> bool {
+		return self.attributes.iter().any(|a| matches!(a, ParamAttr::Secret));
+	}
+	
+	/// Returns true if this field is a ``` Money ```, or was marked with ``` #[decimal] ```.
+	/// Only known once its scalar Types were resolved, see [resolve_scalars](crate::parsers::scalars::resolve_scalars)
+	pub fn is_decimal(&self) -> bool {
+		return self.attributes.iter().any(|a| matches!(a, ParamAttr::Decimal));
+	}
+	
+	/// Returns true if this field was marked with ``` #[patch] ```, or declared as ``` ??T ```
+	pub fn is_patch(&self) -> bool {
+		return self.attributes.iter().any(|a| matches!(a, ParamAttr::Patch));
+	}
+	
+	/// Returns the field's ``` #[datetime(..)] ``` arguments, if declared.
+	pub fn datetime(&self) -> Option<&DateTimeFormat> {
+		return self.attributes.iter().find_map(|a| match a {
+			ParamAttr::DateTime(datetime) => Some(datetime),
+			_ => None,
+		});
+	}
+	
+	/// Returns the HTTP header name of a Header struct's field: either its ``` #[rename = ".."] ```,
+	/// or its name in kebab-case, i.e., ``` x-request-id ``` for ``` x_request_id ```.
+	/// Header names are case-insensitive, so the name is always lowercase.
+	pub fn header_name(&self) -> String {
+		let rename = self.attributes.iter().find_map(|a| match a {
+			ParamAttr::Rename(rename) => Some(rename.value()),
+			_ => None,
+		});
+		return rename
+			.unwrap_or_else(|| self.name.unraw().to_string().replace('_', "-"))
+			.to_ascii_lowercase();
+	}
+	
+	/// Returns the field's generated Type, without its optional wrapper.
+	/// A ``` #[secret] ``` field is wrapped within **secrecy**'s ``` Secret<T> ```,
+	/// or ``` SecretString ``` for a ``` String ```. A ``` #[datetime(..)] ``` field is generated
+	/// as its timestamp Type, instead of its declared ``` String ```.
+	pub fn quote_type(&self) -> TokenStream2 {
+		let ty = &self.ty;
+		if let Some(datetime) = self.datetime() {
+			return datetime_type(datetime);
+		}
+		if !self.is_secret() {
+			return quote!(#ty);
+		}
+		if quote!(#ty).to_string() == "String" {
+			return quote!(::secrecy::SecretString);
+		}
+		return quote!(::secrecy::Secret<#ty>);
+	}
+	
+	/// Renders this field's Type for the generated documentation. Path Types become an
+	/// intra-doc link, i.e., ``` [String] ```, while Types like ``` [u8; 32] ``` are quoted
+	/// as code instead, since rustdoc would otherwise read them as a nested link.
+	pub fn doc_type(&self) -> String {
+		let ty = &self.ty;
+		let rendered = quote!(#ty).to_string().replace(" ;", ";");
+		return match ty {
+			Type::Path(_) => format!("[{}]", rendered),
+			_ => format!("`{}`", rendered),
+		};
+	}
+	
+	/// Returns every field-level Validate Action of this field.
+	pub fn validate_actions(&self) -> impl Iterator<Item = &ValidateAction<ParamAttr>> {
+		return self.attributes.iter()
+			.filter_map(|a| match a {
+				ParamAttr::Validate(chain) => Some(chain.actions.iter()),
+				_ => None,
+			})
+			.flatten();
+	}
+	
+	/// Quotes the field attribute delegating this field's Validate Actions to a
+	/// [ValidateBackend], i.e., ``` #[validate(required, email)] ``` for **validator**,
+	/// or ``` #[garde(required, email)] ``` for **garde**.
+	pub fn quote_backend_validate(&self, backend: &ValidateBackend, owner: &Ident) -> TokenStream2 {
+		let args = self.validate_actions()
+			.filter(|action| !matches!(action, ValidateAction::Regex(_)))
+			.map(|action| action.quote_backend_arg(backend, &self.name))
+			.chain(self.validate_actions()
+				.filter(|action| matches!(action, ValidateAction::Regex(_)))
+				.enumerate()
+				.map(|(i, action)| action.quote_backend_arg(backend, &backend_regex(owner, &self.name, i))))
+			.collect::<Vec<_>>();
+		return backend.quote_field_attr(&args);
+	}
+	
+	/// Quotes the statics every ``` #[validate(regex = "..")] ``` of this field is compiled into.
+	/// A [ValidateBackend] refers to a regex by path, rather than by pattern.
+	pub fn quote_backend_regexes(&self, owner: &Ident) -> TokenStream2 {
+		let regexes = self.validate_actions()
+			.filter_map(|action| match action {
+				ValidateAction::Regex(regex) => Some(regex),
+				_ => None,
+			})
+			.enumerate()
+			.map(|(i, regex)| {
+				let name = backend_regex(owner, &self.name, i);
+				quote! {
+					static #name: ::std::sync::LazyLock<::regex::Regex> =
+						::std::sync::LazyLock::new(|| ::regex::Regex::new(#regex).unwrap());
+				}
+			});
+		return quote!( #( #regexes )* );
+	}
+}
+
+/// Names the static a field's regex is compiled into, for a [ValidateBackend].
+fn backend_regex(owner: &Ident, field: &Ident, i: usize) -> Ident {
+	let owner = owner.to_string().to_uppercase();
+	let field = field.to_string().to_uppercase();
+	return format_ident!("__RESTIFY_{}_{}_REGEX_{}", owner, field, i);
+}
+
+/// Names the function returning a field's declared default value, i.e., ``` count: u32 = 10 ```
+fn default_fn(field: &Ident) -> Ident {
+	return format_ident!("__restify_default_{}", field);
+}
+
+/// Quotes a field's declared default value. String literals are converted with
+/// ``` Into ```, so ``` name: String = "restify" ``` works as expected.
+fn quote_default_value(default: &Expr) -> TokenStream2 {
+	if let Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(_), .. }) = default {
+		return quote!( ::core::convert::Into::into(#default) );
+	}
+	return quote!(#default);
+}
+
+/// Bounds every generic Type parameter with the provided trait, same as a derive would.
+fn bounded_generics(generics: &Generics, bound: TypeParamBound) -> Generics {
+	let mut generics = generics.clone();
+	for param in generics.type_params_mut() {
+		param.bounds.push(bound.clone());
+	}
+	return generics;
+}
+
+/// Quotes the serde attribute a Decimal field is (de)serialized as a string with.
+fn quote_decimal_serde(optional: bool) -> TokenStream2 {
+	let rust_decimal = RestOptions::rust_decimal();
+	let rust_decimal = quote!(#rust_decimal).to_string().replace(' ', "");
+	let with = match optional {
+		true  => format!("{}::serde::str_option", rust_decimal),
+		false => format!("{}::serde::str", rust_decimal),
+	};
+	return quote!( #[serde(with = #with)] );
+}
+
+/// Quotes the serde attributes a ``` #[patch] ``` field is (de)serialized with. Both ``` default ```
+/// and ``` skip_serializing_if ``` are required, even within a ``` #[no_auto_serde] ``` struct,
+/// since they're what tell an absent field apart from a null one.
+fn quote_patch_serde() -> TokenStream2 {
+	let serde_with = RestOptions::serde_with();
+	let with = format!("{}::rust::double_option", quote!(#serde_with).to_string().replace(' ', ""));
+	return quote!( #[serde(default, skip_serializing_if = "::core::option::Option::is_none", with = #with)] );
+}
+
+/// Quotes the serde attribute a Request's ``` #[secret] ``` field is serialized with.
+/// See [gen_runtime_support](crate::generators::support::gen_runtime_support)
+fn quote_secret_serializer(optional: bool) -> TokenStream2 {
+	let support = RestOptions::support();
+	let serializer = match optional {
+		true  => format!("{support}::expose_optional_secret"),
+		false => format!("{support}::expose_secret"),
+	};
+	return quote!( #[serde(serialize_with = #serializer)] );
 }
 
 /// # A Slice of a Vec<StructParameter>
@@ -39,31 +231,322 @@ pub struct StructParameter {
 pub struct StructParameterSlice<'s>{
 	slice: &'s [StructParameter],
 	current: usize,
+	skip_none: bool,
+	no_auto_serde: bool,
+	default_owner: Option<Ident>,
+	datetime_owner: Option<Ident>,
+	validate_backend: Option<(ValidateBackend, Ident)>,
+	validated: bool,
+	log: Option<Log>,
+	generics: Generics,
 }
 
 impl<'s> StructParameterSlice<'s> {
 	pub fn len(&self) -> usize {
 		self.slice.len()
 	}
-	pub fn iter(&self) -> StructParameterSlice {
+	pub fn is_empty(&self) -> bool {
+		self.slice.is_empty()
+	}
+	pub fn iter(&self) -> StructParameterSlice<'_> {
 		StructParameterSlice {
-			slice: &self.slice,
+			slice: self.slice,
 			current: 0,
+			skip_none: self.skip_none,
+			no_auto_serde: self.no_auto_serde,
+			default_owner: self.default_owner.clone(),
+			datetime_owner: self.datetime_owner.clone(),
+			validate_backend: self.validate_backend.clone(),
+			validated: self.validated,
+			log: self.log.clone(),
+			generics: self.generics.clone(),
+		}
+	}
+	
+	/// Builder: When true, every optional field will be skipped while serializing
+	/// if its value is None. Fields declared with '?' already are, this extends it to
+	/// fields declared with an explicit ``` Option<T> ``` type.
+	pub fn with_skip_none(mut self, skip_none: bool) -> Self {
+		self.skip_none = skip_none;
+		return self;
+	}
+	
+	/// Builder: When true, Restify will stop injecting serde attributes into optional
+	/// fields, for every field within this slice.
+	/// See [StructParameterSlice::auto_fill]
+	pub fn with_no_auto_serde(mut self, no_auto_serde: bool) -> Self {
+		self.no_auto_serde = no_auto_serde;
+		return self;
+	}
+	
+	/// Builder: Deserialize every field with a declared default value, i.e.,
+	/// ``` count: u32 = 10 ```, via the functions generated for the owning struct by
+	/// [Self::quote_default_fns]
+	pub fn with_default_owner(mut self, owner: &Ident) -> Self {
+		self.default_owner = Some(owner.clone());
+		return self;
+	}
+	
+	/// Builder: (De)serialize every ``` #[datetime(format = "..")] ``` field via the functions
+	/// generated for the owning struct by [Self::quote_datetime_fns]
+	pub fn with_datetime_owner(mut self, owner: &Ident) -> Self {
+		self.datetime_owner = Some(owner.clone());
+		return self;
+	}
+	
+	/// Builder: Delegate every field's ``` #[validate(..)] ``` to a [ValidateBackend], by
+	/// quoting the backend's field attribute. Nothing changes for the default backend.
+	pub fn with_validate_backend(mut self, backend: &ValidateBackend, owner: &Ident) -> Self {
+		self.validate_backend = match backend {
+			ValidateBackend::Restify => None,
+			backend => Some((backend.clone(), owner.clone())),
+		};
+		return self;
+	}
+	
+	/// Builder: When true, the parent struct has a generated ``` validate() ```, which
+	/// its other generated methods can call into.
+	pub fn with_validated(mut self, validated: bool) -> Self {
+		self.validated = validated;
+		return self;
+	}
+	
+	/// Builder: The parent struct's ``` #[log(..)] ```, whose generated log methods its
+	/// other generated methods can call into.
+	pub fn with_log(mut self, log: Option<Log>) -> Self {
+		self.log = log;
+		return self;
+	}
+	
+	/// Builder: The parent struct's generic parameters, i.e., ``` struct Page<T><Response> ```,
+	/// which every generated implementation for the parent struct carries over.
+	pub fn with_generics(mut self, generics: &Generics) -> Self {
+		self.generics = generics.clone();
+		return self;
+	}
+	
+	/// Returns the parent struct's generic parameters.
+	pub fn generics(&self) -> &Generics {
+		return &self.generics;
+	}
+	
+	/// Returns true if the parent struct has a generated ``` validate() ```
+	pub fn is_validated(&self) -> bool {
+		return self.validated;
+	}
+	
+	/// Returns the parent struct's ``` #[log(..)] ```, if it declared one.
+	pub fn log(&self) -> Option<&Log> {
+		return self.log.as_ref();
+	}
+	
+	/// Compiles a field's attributes, along with its [ValidateBackend] attribute.
+	fn compile_attributes(&self, field: &StructParameter) -> CompiledAttrs<ParamAttr> {
+		let mut compiled_attributes = field.attributes.compile();
+		if let (Some(_), Some(owner)) = (&field.default, &self.default_owner) {
+			let default = format!("{}::{}", owner, default_fn(&field.name));
+			compiled_attributes.quotes.push(quote!( #[serde(default = #default)] ));
+		}
+		if let (Some(datetime), Some(owner)) = (field.datetime(), &self.datetime_owner) {
+			compiled_attributes.quotes.push(datetime_serde_attr(datetime, owner, &field.name, field.optional));
+		}
+		if field.is_decimal() {
+			compiled_attributes.quotes.push(quote_decimal_serde(field.optional));
 		}
+		if field.is_patch() {
+			compiled_attributes.quotes.push(quote_patch_serde());
+		}
+		if let Some((backend, owner)) = &self.validate_backend {
+			compiled_attributes.quotes.push(field.quote_backend_validate(backend, owner));
+		}
+		return compiled_attributes;
+	}
+	
+	/// Compiles a deserialized field's attributes. Fields borrowing from the input
+	/// through a lifetime, other than a bare ``` &str ``` or ``` &[u8] ```, which serde
+	/// borrows implicitly, are given ``` #[serde(borrow)] ```, enabling zero-copy
+	/// deserialization of types like ``` Cow<'a, str> ``` or ``` Option<&'a str> ```
+	fn compile_de_attributes(&self, field: &StructParameter) -> CompiledAttrs<ParamAttr> {
+		let mut compiled_attributes = self.compile_attributes(field);
+		let needs_borrow = !field.is_secret()
+			&& !type_lifetimes(&field.ty).is_empty()
+			&& (field.optional || !is_implicitly_borrowed(&field.ty))
+			&& !field.attributes.iter().any(|a| matches!(a, ParamAttr::Borrow(_)));
+		if needs_borrow {
+			compiled_attributes.quotes.push(quote!( #[serde(borrow)] ));
+		}
+		return compiled_attributes;
+	}
+	
+	/// Quotes the statics every field's regex is compiled into, when delegating
+	/// validation to a [ValidateBackend].
+	pub fn quote_backend_regexes(&self) -> TokenStream2 {
+		let Some((ValidateBackend::Validator, owner)) = &self.validate_backend else {
+			return quote!();
+		};
+		let regexes = self.iter().map(|field| field.quote_backend_regexes(owner));
+		return quote!( #( #regexes )* );
+	}
+	
+	/// Returns true if any field within this slice has a ``` #[validate(..)] ``` attribute.
+	pub fn has_validate(&self) -> bool {
+		return self.iter().any(|field| field.attributes.iter().any(|a| matches!(a, ParamAttr::Validate(_))));
+	}
+	
+	/// Returns true if any field within this slice was marked with ``` #[secret] ```
+	pub fn has_secret(&self) -> bool {
+		return self.iter().any(StructParameter::is_secret);
+	}
+	
+	/// Runs [CompiledAttrs::auto_fill_serde_attrs] for an optional field, unless
+	/// the automatic serde injection was disabled for either the parent type or the field
+	/// itself via ``` #[no_auto_serde] ```. Which gives "send explicit null" semantics.
+	fn auto_fill(
+		&self,
+		field: &StructParameter,
+		compiled_attributes: &CompiledAttrs<ParamAttr>,
+		stream: TokenStream2,
+		rest_type: RestType,
+	) -> TokenStream2 {
+		if self.no_auto_serde || field.attributes.iter().any(|a| matches!(a, ParamAttr::NoAutoSerde)) {
+			return stream;
+		}
+		return compiled_attributes.auto_fill_serde_attrs(stream, rest_type);
+	}
+	
+	/// Generates the functions every ``` #[datetime(format = "..")] ``` field is serialized and
+	/// deserialized with. See [gen_datetime_fns]
+	pub fn quote_datetime_fns(&self, name: &Ident) -> TokenStream2 {
+		let fns = self.iter()
+			.filter_map(|field| Some(gen_datetime_fns(field.datetime()?, &field.name, field.optional)))
+			.filter(|fns| !fns.is_empty())
+			.collect::<Vec<_>>();
+		if fns.is_empty() {
+			return quote!();
+		}
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		return quote! {
+			impl #impl_generics #name #ty_generics #where_clause {
+				#( #fns )*
+			}
+		};
+	}
+	
+	/// Returns true if any field within this slice has a ``` #[default] ``` attribute,
+	/// or a declared default value.
+	pub fn has_default(&self) -> bool {
+		return self.iter().any(|field| field.default.is_some()
+			|| field.attributes.iter().any(|a| matches!(a, ParamAttr::Default(_))));
+	}
+	
+	/// Generates the functions returning every declared default value, i.e.,
+	/// ``` count: u32 = 10 ```. serde's ``` #[serde(default = "..")] ``` takes a function path.
+	pub fn quote_default_fns(&self, name: &Ident) -> TokenStream2 {
+		let fns = self.iter().filter_map(|field| {
+			let default = quote_default_value(field.default.as_ref()?);
+			let fn_name = default_fn(&field.name);
+			let ty = field.quote_type();
+			if field.optional {
+				return Some(quote! {
+					#[doc(hidden)]
+					fn #fn_name() -> ::core::option::Option<#ty> {
+						return ::core::option::Option::Some(#default);
+					}
+				});
+			}
+			return Some(quote! {
+				#[doc(hidden)]
+				fn #fn_name() -> #ty {
+					return #default;
+				}
+			});
+		}).collect::<Vec<_>>();
+		if fns.is_empty() {
+			return quote!();
+		}
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		return quote! {
+			impl #impl_generics #name #ty_generics #where_clause {
+				#( #fns )*
+			}
+		};
+	}
+	
+	/// # StructParameter: Default
+	/// Generates a manual ``` Default ``` implementation for the parent struct, which
+	/// agrees with the serde defaults of its fields. ``` #[default = "path::to::fn"] ```
+	/// fields call that function, fields with a declared default value call the function
+	/// generated by [Self::quote_default_fns], and every other field uses its own ``` Default ```
+	pub fn quote_default_impl(&self, name: &Ident) -> TokenStream2 {
+		let fields = self.iter().map(|field| {
+			let field_name = &field.name;
+			if field.default.is_some() {
+				let default = default_fn(field_name);
+				return quote!( #field_name: Self::#default(), );
+			}
+			let default = field.attributes.iter().find_map(|a| match a {
+				ParamAttr::Default(Some(path)) => path.parse::<syn::Path>().ok(),
+				_ => None,
+			});
+			// Same as serde, the function returns the field's full Type, Option included.
+			if let Some(default) = default {
+				return quote!( #field_name: #default(), );
+			}
+			return quote!( #field_name: ::core::default::Default::default(), );
+		});
+		let generics = bounded_generics(&self.generics, parse_quote!(::core::default::Default));
+		let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+		return quote! {
+			impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+				fn default() -> Self {
+					return #name {
+						#( #fields )*
+					};
+				}
+			}
+		};
+	}
+	
+	/// Returns true if any field within this slice was marked with ``` #[sensitive] ```
+	pub fn has_sensitive(&self) -> bool {
+		return self.iter().any(StructParameter::is_sensitive);
+	}
+	
+	/// # StructParameter: Redacted Debug
+	/// Generates a manual ``` Debug ``` implementation for the parent struct, which
+	/// prints ``` *** ``` in place of every ``` #[sensitive] ``` and ``` #[secret] ``` field's value.
+	pub fn quote_debug_impl(&self, name: &Ident) -> TokenStream2 {
+		let name_str = name.to_string();
+		let fields = self.iter().map(|field| {
+			let field_name = &field.name;
+			let field_str = field_name.to_string();
+			if field.is_sensitive() || field.is_secret() {
+				return quote!( .field(#field_str, &"***") );
+			}
+			return quote!( .field(#field_str, &self.#field_name) );
+		});
+		let generics = bounded_generics(&self.generics, parse_quote!(::core::fmt::Debug));
+		let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+		return quote! {
+			impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					f.debug_struct(#name_str)
+						#( #fields )*
+						.finish()
+				}
+			}
+		};
 	}
 	
-	#[allow(unused)]
 	pub fn query_field_docs(&self) -> Vec<TokenStream2> {
 		return self.iter().map(|field| {
 			let field_name = &field.name.to_string();
-			let ty = &field.ty;
-			let field_type = quote!(#ty).to_string();
-			let doc_comment = format!("[{}] {}", field_type, field_name);
+			let doc_comment = format!("{} {}", field.doc_type(), field_name);
 			
 			let output = quote!{
 				#[doc = #doc_comment]
 			};
-			output.into()
+			output
 		}).collect()
 	}
 	
@@ -76,9 +559,7 @@ impl<'s> StructParameterSlice<'s> {
 		
 		for field in self.iter() {
 			let name = &field.name;
-			let ty = &field.ty;
-			let ty = quote!( #ty).to_string();
-			doc.add_doc(format!("  * [{}] {}", ty, name.to_string()))
+			doc.add_doc(format!("  * {} {}", field.doc_type(), name))
 		}
 		
 		return doc;
@@ -96,29 +577,7 @@ impl<'s> StructParameterSlice<'s> {
 	/// else { quote!{} }
 	/// ```
 	pub fn quote_serialize(&self, vis: &Visibility) -> Vec<TokenStream2> {
-		return self.iter().map(|field| {
-			let field_name = &field.name;
-			let field_type = &field.ty;
-			let compiled_attributes = field.attributes.compile();
-			let quotes = compiled_attributes.quotes_ref();
-			
-			let _assert_ser = quote_spanned! {field_type.span() =>
-				struct _AssertSer where #field_type: serde::Serialize;
-			};
-			if !field.optional {
-				return quote!(
-					#( #quotes )*
-					#vis #field_name: #field_type,
-				).into();
-			}
-			return compiled_attributes.auto_fill_serde_attrs(
-				quote!(
-					#( #quotes )*
-					#vis #field_name: Option<#field_type>,
-				),
-				RestType::Serializable,
-			).into();
-		}).collect();
+		return self.iter().map(|field| self.quote_field(field, vis, RestType::Serializable)).collect();
 	}
 	
 	/// # StructParameter: Deserialize
@@ -135,59 +594,115 @@ impl<'s> StructParameterSlice<'s> {
 	/// else { quote!{} }
 	/// ```
 	pub fn quote_deserialize(&self, vis: &Visibility) -> Vec<TokenStream2>{
-		return self.iter().map(|field| {
-			let field_name = &field.name;
-			let field_type = &field.ty;
-			let compiled_attributes = field.attributes.compile();
-			
-			let quotes = compiled_attributes.quotes_ref();
-			
-			let _assert_de = quote_spanned! {field_type.span() =>
-				struct _AssertSer where #field_type: for<'de> serde::Deserialize<'de>;
-			};
-			if !field.optional {
-				return quote! (
-					#( #quotes )*
-					#vis #field_name: #field_type,
-				).into();
-			}
-			return compiled_attributes.auto_fill_serde_attrs(
-				quote! {
-						#( #quotes )*
-						#vis #field_name: Option<#field_type>,
-					},
-				RestType::Deserializable
-			).into();
-		}).collect();
+		return self.iter().map(|field| self.quote_field(field, vis, RestType::Deserializable)).collect();
 	}
 	/// # StructParameter: Deserialize & Serialize
-	#[allow(unused)]
 	pub fn quote_full_serde(&self, vis: &Visibility) -> Vec<TokenStream2> {
-		return self.slice.iter().map(|field| {
-			let field_name = &field.name;
-			let field_type = &field.ty;
-			let compiled_attributes = field.attributes.compile();
-			let quotes = compiled_attributes.quotes_ref();
-			
-			//TODO: Not working atm, not sure why
-			let _assert_de = quote_spanned! {field_type.span() =>
-				struct _AssertSer where #field_type: serde::Serialize + for<'de> serde::Deserialize<'de>;
-			};
-			
-			if !field.optional {
-				return quote! (
+		return self.iter().map(|field| self.quote_field(field, vis, RestType::Both)).collect();
+	}
+	
+	/// Quotes a single field declaration for a struct (de)serialized as **rest_type**.
+	/// The field's attributes are compiled once, and shared by every code path.
+	fn quote_field(&self, field: &StructParameter, vis: &Visibility, rest_type: RestType) -> TokenStream2 {
+		let field_name = &field.name;
+		let field_type = field.quote_type();
+		let compiled_attributes = match rest_type {
+			RestType::Serializable => {
+				let mut compiled_attributes = self.compile_attributes(field);
+				if field.is_secret() {
+					compiled_attributes.quotes.push(quote_secret_serializer(field.optional));
+				}
+				compiled_attributes
+			}
+			RestType::Deserializable | RestType::Both => self.compile_de_attributes(field),
+		};
+		let quotes = compiled_attributes.quotes_ref();
+		
+		let serialized = matches!(rest_type, RestType::Serializable | RestType::Both);
+		if serialized && self.skip_none && !field.is_secret() && is_option_type(&field.ty) {
+			return self.auto_fill(
+				field,
+				&compiled_attributes,
+				quote!(
 					#( #quotes )*
 					#vis #field_name: #field_type,
-				).into();
+				),
+				RestType::Serializable,
+			);
+		}
+		if !field.optional {
+			return quote!(
+				#( #quotes )*
+				#vis #field_name: #field_type,
+			);
+		}
+		return self.auto_fill(
+			field,
+			&compiled_attributes,
+			quote!(
+				#( #quotes )*
+				#vis #field_name: ::core::option::Option<#field_type>,
+			),
+			rest_type,
+		);
+	}
+	
+	/// # StructParameter: Serde Assertions
+	/// Asserts at compile-time that each field's Type implements the serde traits its struct
+	/// derives, spanned onto the field's Type, so a missing impl is reported at the field
+	/// rather than at the derive.
+	///
+	/// Fields serde never (de)serializes through their own Type, i.e., ``` #[with = ".."] ```,
+	/// ``` #[skip] ```, ``` #[secret] ``` or ``` #[datetime] ``` fields, and fields whose Types
+	/// borrow or name the struct's generics, aren't asserted.
+	///
+	/// The assertions call into the runtime-support module, see
+	/// [gen_runtime_support](crate::generators::support::gen_runtime_support), and each
+	/// Type is only asserted once per struct.
+	///
+	/// ```ignore
+	/// const _: fn() = || {
+	///   __restify_user::assert_serialize::<#field_type>();
+	/// };
+	/// ```
+	pub fn quote_serde_assertions(&self, rest_type: RestType) -> TokenStream2 {
+		let serialize = !matches!(rest_type, RestType::Deserializable);
+		let deserialize = !matches!(rest_type, RestType::Serializable);
+		
+		let support = RestOptions::support();
+		let mut assertions: Vec<TokenStream2> = Vec::new();
+		let mut asserted: HashSet<(String, bool)> = HashSet::new();
+		for field in self.iter() {
+			if references_generics(&field.ty, &self.generics) {
+				continue;
 			}
-			return compiled_attributes.auto_fill_serde_attrs(
-				quote! {
-						#( #quotes )*
-						#vis #field_name: Option<#field_type>,
-					},
-				RestType::Both
-			).into();
-		}).collect()
+			let custom = field.attributes.iter().any(|a| matches!(
+				a,
+				ParamAttr::With(_) | ParamAttr::Skip | ParamAttr::Secret | ParamAttr::DateTime(_)
+					| ParamAttr::Decimal | ParamAttr::Uuid | ParamAttr::Bound(_) | ParamAttr::Borrow(_)
+			));
+			if custom {
+				continue;
+			}
+			let ty = &field.ty;
+			let key = quote!(#ty).to_string();
+			let serialized = field.attributes.iter().all(|a| !matches!(a, ParamAttr::SerializeWith(_) | ParamAttr::SkipSerialize));
+			if serialize && serialized && asserted.insert((key.clone(), true)) {
+				assertions.push(quote_spanned! {ty.span() => #support::assert_serialize::<#ty>(); });
+			}
+			let deserialized = field.attributes.iter().all(|a| !matches!(a, ParamAttr::DeserializeWith(_) | ParamAttr::SkipDeserialize));
+			if deserialize && deserialized && asserted.insert((key, false)) {
+				assertions.push(quote_spanned! {ty.span() => #support::assert_deserialize::<#ty>(); });
+			}
+		}
+		if assertions.is_empty() {
+			return quote!();
+		}
+		return quote! {
+			const _: fn() = || {
+				#( #assertions )*
+			};
+		};
 	}
 	
 	/// # Builder Functions Compiler:
@@ -211,13 +726,13 @@ impl<'s> StructParameterSlice<'s> {
 	pub fn quote_builder_fn(&self, vis: &Visibility) -> Vec<TokenStream2> {
 		return self.iter().map(|field| {
 			let name = &field.name;
-			let ty   = &field.ty;
+			let ty   = field.quote_type();
 			let fn_name = Ident::new(
-				&format!("with_{}", name.to_string()),
+				&format!("with_{}", name),
 				name.span(),
 			);
 			let ty = if field.optional {
-				quote!(Option<#ty>)
+				quote!(::core::option::Option<#ty>)
 			} else {
 				quote!(#ty)
 			};
@@ -229,7 +744,7 @@ impl<'s> StructParameterSlice<'s> {
 				}
 			};
 			
-			output.into()
+			output
 		}).collect();
 	}
 	
@@ -237,22 +752,24 @@ impl<'s> StructParameterSlice<'s> {
 		return self.iter().map(|field| {
 			let name = &field.name;
 			let ty   = &field.ty;
-			let compiled_attributes = field.attributes.compile();
+			let compiled_attributes = self.compile_de_attributes(field);
 			let quotes = compiled_attributes.quotes_ref();
 			
 			if !field.optional {
 				return quote!(
 					#( #quotes )*
 					#name: #ty,
-				).into();
+				);
 			}
-			return compiled_attributes.auto_fill_serde_attrs(
+			return self.auto_fill(
+				field,
+				&compiled_attributes,
 				quote! {
 						#( #quotes )*
-						#name: Option<#ty>,
+						#name: ::core::option::Option<#ty>,
 					},
 				RestType::Both,
-			).into();
+			);
 		}).collect();
 	}
 }
@@ -273,6 +790,14 @@ impl<'s> From<&'s Vec<StructParameter>> for StructParameterSlice<'s> {
 		Self{
 			slice: value.as_slice(),
 			current: 0,
+			skip_none: false,
+			no_auto_serde: false,
+			default_owner: None,
+			datetime_owner: None,
+			validate_backend: None,
+			validated: false,
+			log: None,
+			generics: Generics::default(),
 		}
 	}
 }
@@ -280,13 +805,367 @@ impl<'s> From<&'s Vec<StructParameter>> for StructParameterSlice<'s> {
 impl Display for StructParameter {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		// TODO: Implement Display for Attributes
-		write!(f, "{}: ", self.name.to_string())?;
+		write!(f, "{}: ", self.name)?;
 		let ty = &self.ty;
 		let d_type = quote!{ #ty };
 		if self.optional {
-			write!(f, "Option<{}>, \n", d_type.to_string())
+			writeln!(f, "Option<{}>, ", d_type)
 		} else {
-			write!(f, "{}, \n", d_type.to_string())
+			writeln!(f, "{}, ", d_type)
+		}
+	}
+}
+
+#[cfg(test)]
+mod struct_parameter_tests {
+	use quote::quote;
+	use crate::parsers::struct_parameter::StructParameterSlice;
+	use crate::parsers::endpoint_method::EndpointDataType;
+	use crate::generators::generate;
+	
+	#[test] fn serialize_with_lands_on_fields() {
+		let request = generate(r#"
+			struct Request {
+				#[serialize_with="ser::ids"]
+				ids: Vec<u64>,
+				#[serialize_with="ser::date"]
+				date: ?String,
+			}
+		"#);
+		assert!(request.contains(&quote!(#[serde(serialize_with = "ser::ids")] ids: Vec<u64>,).to_string()));
+		assert!(request.contains(&quote!(#[serde(serialize_with = "ser::date")] date: ::core::option::Option<String>,).to_string()));
+		
+		let query = generate(r#"
+			struct Query {
+				#[serialize_with="ser::page"]
+				page: u32,
+			}
+		"#);
+		assert!(query.contains(&quote!(#[serde(serialize_with = "ser::page")] page: u32,).to_string()));
+	}
+	
+	#[test] fn deserialize_with_lands_on_fields() {
+		let response = generate(r#"
+			struct Response {
+				#[deserialize_with="de::date"]
+				created_at: DateTime,
+				#[deserialize_with="de::optional_date"]
+				updated_at: ?DateTime,
+			}
+		"#);
+		assert!(response.contains(&quote!(#[serde(deserialize_with = "de::date")] created_at: DateTime,).to_string()));
+		assert!(response.contains(&quote!(#[serde(deserialize_with = "de::optional_date")] updated_at: ::core::option::Option<DateTime>,).to_string()));
+	}
+	
+	#[test] fn bound_clauses() {
+		let response = generate(r#"
+			struct Response {
+				#[bound = "T: serde::de::DeserializeOwned"]
+				data: T,
+				#[bound]
+				meta: M,
+			}
+		"#);
+		assert!(response.contains(&quote!(#[serde(bound = "T: serde::de::DeserializeOwned")] data: T,).to_string()));
+		assert!(response.contains(&quote!(#[serde(bound = "")] meta: M,).to_string()));
+	}
+	
+	#[test] fn skip_none_applies_to_explicit_options() {
+		let query = generate(r#"
+			#[skip_none]
+			struct Query {
+				page: Option<u32>,
+				size: ?u32,
+				sort: String,
+			}
+		"#);
+		assert!(query.contains(&quote!(#[serde(skip_serializing_if="::core::option::Option::is_none")] page: Option<u32>,).to_string()));
+		assert!(query.contains(&quote!(#[serde(skip_serializing_if="::core::option::Option::is_none")] size: ::core::option::Option<u32>,).to_string()));
+		assert_eq!(query.matches("skip_serializing_if").count(), 2);
+		
+		let request = generate(r#"
+			struct Request {
+				page: Option<u32>,
+			}
+		"#);
+		assert!(!request.contains("skip_serializing_if"));
+	}
+	
+	#[test] fn no_auto_serde() {
+		let request = generate(r#"
+			struct Request {
+				#[no_auto_serde]
+				nickname: ?String,
+				email: ?String,
+			}
+		"#);
+		assert!(request.contains(&format!("{{ {}", quote!(nickname: ::core::option::Option<String>,))));
+		assert!(request.contains(&quote!(#[serde(skip_serializing_if="::core::option::Option::is_none")] email: ::core::option::Option<String>,).to_string()));
+		
+		let response = generate(r#"
+			#[no_auto_serde]
+			struct Response {
+				nickname: ?String,
+			}
+		"#);
+		assert!(!response.contains("default"));
+		
+		let en = generate(r#"
+			#[no_auto_serde]
+			enum Patch {
+				Update { nickname: ?String },
+			}
+		"#);
+		assert!(!en.contains("skip_serializing_if"));
+	}
+	
+	#[test] fn no_auto_serde_overrides_skip_none() {
+		let query = generate(r#"
+			#[skip_none]
+			#[no_auto_serde]
+			struct Query {
+				page: Option<u32>,
+				size: ?u32,
+			}
+		"#);
+		assert!(!query.contains("skip_serializing_if"));
+		
+		let query = generate(r#"
+			#[skip_none]
+			struct Query {
+				#[no_auto_serde]
+				page: Option<u32>,
+				size: ?u32,
+			}
+		"#);
+		assert!(query.contains(&format!("{{ {}", quote!(page: Option<u32>,))));
+		assert_eq!(query.matches("skip_serializing_if").count(), 1);
+	}
+	
+	#[test] fn sensitive_fields_are_redacted() {
+		let request = generate(r#"
+			#[log(info = "Signing in {username} with {password}")]
+			struct Request {
+				username: String,
+				#[sensitive]
+				password: String,
+			}
+		"#);
+		assert!(request.contains(&quote!(#[derive(::core::clone::Clone, ::serde::Serialize)]).to_string()));
+		assert!(request.contains(&quote!(
+			f.debug_struct("Request")
+				.field("username", &self.username)
+				.field("password", &"***")
+				.finish()
+		).to_string()));
+		assert!(request.contains(&quote!(password = "***").to_string()));
+		
+		let no_debug = generate(r#"
+			#[derives(remove(Debug))]
+			struct Request {
+				#[sensitive]
+				password: String,
+			}
+		"#);
+		assert!(!no_debug.contains("Debug"));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			enum Auth {
+				Login { #[sensitive] password: String },
+			}
+		"#).is_err());
+	}
+	
+	#[test] fn secret_fields() {
+		let request = generate(r#"
+			struct Request {
+				#[secret]
+				password: String,
+				#[secret]
+				pin: ?u32,
+			}
+		"#);
+		// secrecy can't Debug a Secret<u32>, so every secret is redacted by hand instead.
+		assert!(request.contains(&quote!(#[derive(::serde::Serialize)]).to_string()));
+		assert!(request.contains(&quote!(.field("pin", &"***")).to_string()));
+		assert!(request.contains(&quote!(
+			#[serde(serialize_with = "__restify::expose_secret")]
+			password: ::secrecy::SecretString,
+		).to_string()));
+		assert!(request.contains(&quote!(
+			#[serde(serialize_with = "__restify::expose_optional_secret")]
+			pin: ::core::option::Option<::secrecy::Secret<u32> >,
+		).to_string()));
+		
+		let response = generate(r#"
+			struct Response {
+				#[secret]
+				token: String,
+			}
+		"#);
+		assert!(response.contains(&format!("{{ {}", quote!(token: ::secrecy::SecretString,))));
+		assert!(!response.contains("expose_secret"));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Query { #[secret] token: String }
+		"#).is_err());
+	}
+	
+	#[test] fn default_impl() {
+		let response = generate(r#"
+			#[derives(add(Default))]
+			struct Response {
+				#[default = "defaults::page_size"]
+				page_size: u32,
+				#[default]
+				cursor: ?String,
+				total: u64,
+			}
+		"#);
+		assert!(response.contains(&quote!(
+			impl ::core::default::Default for Response {
+				fn default() -> Self {
+					return Response {
+						page_size: defaults::page_size(),
+						cursor: ::core::default::Default::default(),
+						total: ::core::default::Default::default(),
+					};
+				}
+			}
+		).to_string()));
+		// The derived Default is replaced by the manual implementation.
+		let derives = response.split("struct Response").next().unwrap();
+		assert!(!derives.contains("Default"));
+		assert!(!generate("struct Response { id: u64 }").contains("Default"));
+	}
+	
+	#[test] fn default_values() {
+		let response = generate(r#"
+			struct Response {
+				count: u32 = 10,
+				note: ?String = "restify",
+			}
+		"#);
+		assert!(response.contains(&quote!(
+			#[serde(default = "Response::__restify_default_count")]
+		).to_string()));
+		assert!(response.contains(&quote!(
+			fn __restify_default_count() -> u32 {
+				return 10;
+			}
+		).to_string()));
+		assert!(response.contains(&quote!(
+			fn __restify_default_note() -> ::core::option::Option<String> {
+				return ::core::option::Option::Some(::core::convert::Into::into("restify"));
+			}
+		).to_string()));
+		assert!(response.contains(&quote!( count: Self::__restify_default_count(), ).to_string()));
+		
+		assert!(syn::parse_str::<EndpointDataType>(r#"
+			struct Response {
+				#[default = "defaults::count"]
+				count: u32 = 10,
+			}
+		"#).is_err());
+	}
+	
+	#[test] fn nested_optional_types() {
+		let response = generate(r#"
+			struct Response {
+				tags: Vec<?String>,
+				scores: std::collections::HashMap<String, ?Vec<?u64>>,
+				grid: ?Vec<Vec<?u8>>,
+			}
+		"#);
+		assert!(response.contains(&quote!(tags: Vec<::core::option::Option<String> >,).to_string()));
+		assert!(response.contains(&quote!(
+			scores: std::collections::HashMap<String, ::core::option::Option<Vec<::core::option::Option<u64> > > >,
+		).to_string()));
+		assert!(response.contains(&quote!(
+			grid: ::core::option::Option<Vec<Vec<::core::option::Option<u8> > > >,
+		).to_string()));
+	}
+	
+	#[test] fn array_fields() {
+		let hash = generate(r#"
+			#[builder]
+			#[constructor]
+			struct Hash<Request> {
+				digest: [u8; 32],
+				position: ?[f64; 3],
+			}
+		"#);
+		assert!(hash.contains(&quote!(digest: [u8; 32],).to_string()));
+		assert!(hash.contains(&quote!(position: ::core::option::Option<[f64; 3]>,).to_string()));
+		assert!(hash.contains(&quote!(fn new(digest: [u8; 32]) -> Self).to_string()));
+		assert!(hash.contains(&quote!(fn with_digest(self, digest: [u8; 32])).to_string()));
+		
+		let dt = syn::parse_str::<EndpointDataType>("struct Hash<Request> { digest: [u8; 32] }").unwrap();
+		let EndpointDataType::Struct(st) = dt else { unreachable!() };
+		let doc = StructParameterSlice::from(&st.parameters).doc_string().build().to_string();
+		assert!(doc.contains("`[u8; 32]` digest"));
+	}
+	
+	#[test] fn serde_assertions() {
+		let request = generate(r#"struct Request<T> { id: u64, #[with = "ts"] at: Instant, data: Vec<T> }"#);
+		assert!(request.contains(&quote!(__restify::assert_serialize::<u64>();).to_string()));
+		assert!(!request.contains("assert_serialize :: < Instant >"));
+		assert!(!request.contains("assert_serialize :: < Vec < T > >"));
+		assert!(!request.contains("assert_deserialize :: <"));
+		
+		let reqres = generate("struct ReqRes { name: String }");
+		assert!(reqres.contains(&quote!(
+			__restify::assert_serialize::<String>();
+			__restify::assert_deserialize::<String>();
+		).to_string()));
+		
+		// Each Type is only asserted once.
+		let response = generate("struct Response { first: String, last: String, age: u8 }");
+		assert_eq!(response.matches("assert_deserialize :: < String >").count(), 1);
+	}
+	
+	#[test] fn patch_fields() {
+		let request = generate(r#"
+			struct Request {
+				nickname: ??String,
+				#[patch]
+				age: u8,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			#[serde(default, skip_serializing_if = "::core::option::Option::is_none", with = "::serde_with::rust::double_option")]
+			nickname: ::core::option::Option<::core::option::Option<String> >,
+		).to_string()));
+		assert!(request.contains(&quote!(age: ::core::option::Option<::core::option::Option<u8> >,).to_string()));
+		assert_eq!(request.matches("skip_serializing_if").count(), 2);
+		for invalid in [
+			"struct Request { #[patch] #[with = \"module\"] nickname: String }",
+			"struct Request { nickname: ??String = None }",
+			"struct Request { address: ??{ street: String } }",
+		] {
+			assert!(syn::parse_str::<EndpointDataType>(invalid).is_err(), "{invalid}");
 		}
 	}
+	
+	#[test] fn optional_field_names() {
+		// A field named after a serde attribute still has its optional attributes filled in.
+		let response = generate(r#"
+			struct Response {
+				default_page: ?u32,
+			}
+		"#);
+		assert!(response.contains(&quote!(
+			#[serde(default)]
+			default_page: ::core::option::Option<u32>,
+		).to_string()));
+		let request = generate(r#"
+			struct Request {
+				skip_serializing_if: ?u32,
+			}
+		"#);
+		assert!(request.contains(&quote!(
+			#[serde(skip_serializing_if="::core::option::Option::is_none")]
+			skip_serializing_if: ::core::option::Option<u32>,
+		).to_string()));
+	}
 }
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use crate::attributes::{MethodAttr, TypeAttr};
+use crate::parsers::components::Components;
+use crate::parsers::endpoint::Endpoint;
+use crate::parsers::endpoint_method::{EndpointDataType, EndpointMethod, TypeReference};
+use crate::parsers::rest_struct::Struct;
+use crate::utils::RestVariant;
+
+/// # Symbols
+/// The symbol table of a `restify!` invocation, mapping the name of every declared
+/// Struct and Enum to its declaration. Built in declaration order: ``` components ```
+/// first, then every REST Method of every Endpoint.
+///
+/// A [TypeReference] resolves to the latest declaration of its name made before it.
+/// Since REST Methods commonly reuse names like ``` Response ```, redeclaring a name
+/// is allowed, and shadows the previous declaration. The generated identifiers must still
+/// be unique, see [check_collisions](crate::parsers::naming::check_collisions).
+pub struct Symbols<'s> {
+	types: HashMap<String, &'s EndpointDataType>,
+	references: HashMap<*const TypeReference, &'s EndpointDataType>,
+}
+
+impl<'s> Symbols<'s> {
+	/// Builds the symbol table, and checks that every [TypeReference] refers to a
+	/// previously declared Type of the matching REST component.
+	pub fn build(
+		components : Option<&'s Components>,
+		endpoints  : &'s [Endpoint],
+	) -> syn::Result<Self> {
+		let mut symbols = Symbols{ types: HashMap::new(), references: HashMap::new() };
+		for data_type in components.iter().flat_map(|components| components.data_types.iter()) {
+			symbols.declare(data_type)?;
+		}
+		for method in endpoints.iter().flat_map(|endpoint| endpoint.methods.iter()) {
+			for data_type in method.data_types.iter() {
+				symbols.declare(data_type)?;
+			}
+			symbols.check_validate_on_send(method)?;
+			symbols.check_accepts(method)?;
+			symbols.check_form(method)?;
+		}
+		let data_types = components.iter()
+			.flat_map(|components| components.data_types.iter())
+			.chain(endpoints.iter().flat_map(|endpoint| endpoint.methods.iter()).flat_map(|method| method.data_types.iter()));
+		for data_type in data_types {
+			Symbols::check_hal_follows(data_type, endpoints)?;
+		}
+		return Ok(symbols);
+	}
+	
+	/// Returns the declaration of a [TypeReference], or the data type itself otherwise.
+	pub fn resolve(&self, data_type: &'s EndpointDataType) -> &'s EndpointDataType {
+		return match data_type {
+			EndpointDataType::Reference(reference) => self.references
+				.get(&(reference as *const TypeReference))
+				.copied()
+				.unwrap_or(data_type),
+			_ => data_type,
+		};
+	}
+	
+	fn declare(&mut self, data_type: &'s EndpointDataType) -> syn::Result<()> {
+		let EndpointDataType::Reference(reference) = data_type else {
+			self.types.insert(data_type.name().to_string(), data_type);
+			return Ok(());
+		};
+		let Some(declaration) = self.types.get(&reference.name.to_string()).copied() else {
+			return Err(syn::Error::new(
+				reference.name.span(),
+				format!("TypeReference: \"{}\" must be declared before it's referenced", reference.name)
+			));
+		};
+		let declared_variant = match declaration {
+			EndpointDataType::Struct(st) => Some(st.variant()),
+			EndpointDataType::Proto(proto) => Some(proto.variant()),
+			_ => None,
+		};
+		if let Some(declared_variant) = declared_variant {
+			let variant = RestVariant::try_from(declared_variant)?;
+			if variant != reference.variant {
+				return Err(syn::Error::new(
+					reference.role.span(),
+					format!("TypeReference: \"{}\" was declared as a {}, not a {}", reference.name, variant, reference.variant)
+				));
+			}
+		}
+		self.references.insert(reference as *const TypeReference, declaration);
+		return Ok(());
+	}
+	
+	/// Returns the Response, or ReqRes, struct a REST Method's response body decodes into,
+	/// resolving references.
+	pub fn response_of(&self, method: &'s EndpointMethod) -> Option<&'s Struct> {
+		return method.data_types.iter().find_map(|dt| match self.resolve(dt) {
+			EndpointDataType::Struct(st) if matches!(
+				RestVariant::try_from(st.variant()),
+				Ok(RestVariant::Response | RestVariant::ReqRes)
+			) => Some(st),
+			_ => None,
+		});
+	}
+	
+	/// Returns the Error struct a REST Method's failed response body decodes into, resolving
+	/// references. Only the first declared Error struct is used.
+	pub fn error_of(&self, method: &'s EndpointMethod) -> Option<&'s Struct> {
+		return method.data_types.iter().find_map(|dt| match self.resolve(dt) {
+			EndpointDataType::Struct(st) if matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Error)) => Some(st),
+			_ => None,
+		});
+	}
+	
+	/// Returns the Form struct a REST Method sends as its body, resolving references.
+	pub fn form_of(&self, method: &'s EndpointMethod) -> Option<&'s Struct> {
+		return method.data_types.iter().find_map(|dt| match self.resolve(dt) {
+			EndpointDataType::Struct(st) if matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Form)) => Some(st),
+			_ => None,
+		});
+	}
+	
+	/// A REST Method sends a single body, so a Form body can't be combined with a Request
+	/// or ReqRes one, or with another Form.
+	fn check_form(&self, method: &'s EndpointMethod) -> syn::Result<()> {
+		if self.form_of(method).is_none() {
+			return Ok(());
+		}
+		let mut bodies = method.data_types.iter().filter_map(|dt| {
+			let (name, variant) = match self.resolve(dt) {
+				EndpointDataType::Struct(st) => (&st.name, st.variant()),
+				EndpointDataType::Proto(proto) => (&proto.name, proto.variant()),
+				_ => return None,
+			};
+			return match RestVariant::try_from(variant) {
+				Ok(RestVariant::Form | RestVariant::Request | RestVariant::ReqRes) => Some(name),
+				_ => None,
+			};
+		});
+		if let Some(body) = bodies.nth(1) {
+			return Err(syn::Error::new(
+				body.span(),
+				"EndpointMethod: A REST Method sending a Form body can't declare another Request, ReqRes or Form body"
+			));
+		}
+		return Ok(());
+	}
+	
+	/// ``` accepts: [..] ``` decodes the response body into the REST Method's Response struct,
+	/// which must be owned, since the decoded body doesn't outlive the decoder.
+	fn check_accepts(&self, method: &'s EndpointMethod) -> syn::Result<()> {
+		if method.accepts.is_empty() {
+			return Ok(());
+		}
+		let Some(response) = self.response_of(method) else {
+			return Err(syn::Error::new(
+				method.method.span(),
+				"EndpointMethod: accepts: [..] requires a Response or ReqRes struct to decode into"
+			));
+		};
+		if !response.generics.params.is_empty() {
+			return Err(syn::Error::new(
+				response.name.span(),
+				"EndpointMethod: accepts: [..] requires a Response struct without generic parameters or lifetimes"
+			));
+		}
+		return Ok(());
+	}
+	
+	/// Every ``` #[hal(rel = Endpoint::METHOD)] ``` must link to a declared REST Method, whose
+	/// URI declares the path parameters its Path struct is parsed into.
+	fn check_hal_follows(data_type: &EndpointDataType, endpoints: &[Endpoint]) -> syn::Result<()> {
+		let EndpointDataType::Struct(st) = data_type else {
+			return Ok(());
+		};
+		let follows = st.attributes.iter()
+			.filter_map(|a| match a {
+				TypeAttr::Hal(follows) => Some(follows.iter()),
+				_ => None,
+			})
+			.flatten();
+		for follow in follows {
+			let method = endpoints.iter()
+				.filter(|endpoint| endpoint.name == follow.endpoint)
+				.flat_map(|endpoint| endpoint.methods.iter())
+				.find(|method| method.method == follow.method);
+			let Some(method) = method else {
+				return Err(syn::Error::new(
+					follow.endpoint.span(),
+					format!("TypeAttribute::Hal - \"{}::{}\" isn't declared within this restify!", follow.endpoint, follow.method)
+				));
+			};
+			if method.path.is_empty() {
+				return Err(syn::Error::new(
+					follow.endpoint.span(),
+					format!("TypeAttribute::Hal - \"{}::{}\" has no path parameters to follow into", follow.endpoint, follow.method)
+				));
+			}
+		}
+		return Ok(());
+	}
+	
+	/// ``` #[validate_on_send] ``` requires a validated component, which may be referenced.
+	fn check_validate_on_send(&self, method: &'s EndpointMethod) -> syn::Result<()> {
+		if !method.attributes.iter().any(|a| matches!(a, MethodAttr::ValidateOnSend)) {
+			return Ok(());
+		}
+		let has_validate = method.data_types.iter()
+			.any(|dt| matches!(self.resolve(dt), EndpointDataType::Struct(st) if st.has_validate()));
+		if !has_validate {
+			return Err(syn::Error::new(
+				method.method.span(),
+				"MethodAttribute::ValidateOnSend - Requires at least one struct with a #[validate(..)] attribute"
+			));
+		}
+		return Ok(());
+	}
+}
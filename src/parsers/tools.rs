@@ -1,7 +1,11 @@
-use proc_macro2::Ident;
-use syn::Token;
+use std::collections::HashMap;
+use proc_macro2::{Group, Ident, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{parse_quote, Generics, Lifetime, Token, Type};
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Lookahead1, ParseBuffer, ParseStream, Peek};
-use crate::utils::{RestMethods, RestVariant};
+use crate::utils::{closest_match, did_you_mean, RestVariant};
 
 pub struct Lookahead<'p> {
 	pub peeker: Lookahead1<'p>,
@@ -52,34 +56,280 @@ impl<'p> Lookahead<'p> {
 ///     variant. Which will make our code generator add all `Response` related functionalities
 ///     to `MyCustomStructName`.
 ///   * `struct Response {` => Defaults the struct declaration as a `Response` variant.
+///
+/// Generic parameters are declared between the struct name and its variant, followed by
+/// an optional where clause, i.e., `struct Page<T><Response> where T: Clone {`. Angle
+/// brackets holding a single REST Component Type are always parsed as the variant.
 pub fn parse_struct_name_and_variant(
 	input: ParseStream
-) -> syn::Result<(Ident, Option<Ident>)>
+) -> syn::Result<(Ident, Generics, Option<Ident>)>
 {
 	let name: Ident = input.parse()?;
 	let mut variant: Option<Ident> = None;
+	let mut generics = if input.peek(Token![<]) && !peek_variant(input) {
+		input.parse::<Generics>()?
+	} else { Generics::default() };
 	let lookahead = input.lookahead1();
 	
 	if lookahead.peek(Token![<]) {
 		input.parse::<Token![<]>()?;
-		variant = input.parse::<Ident>().and_then(|var| {
-			if !RestVariant::is_valid(&var) {
-				return Err(syn::Error::new(var.span(), "Invalid REST Component Variant used"))
-			} else {
-				Ok(var)
-			}
-		}).ok();
+		let var = input.parse::<Ident>()?;
+		RestVariant::try_from(&var)?;
+		variant = Some(var);
 		input.parse::<Token![>]>()?;
 	} else if !RestVariant::is_valid(&name) {
-		return Err(syn::Error::new(name.span(), "Invalid REST Component used for struct name"));
+		// A misspelled variant, i.e., <Reponse>, was parsed as a generic parameter.
+		let misspelled = generics.type_params()
+			.find(|param| closest_match(&param.ident.to_string(), &RestVariant::NAMES).is_some());
+		if let Some(param) = misspelled {
+			return Err(syn::Error::new(
+				param.ident.span(),
+				format!("Invalid REST Component Variant \"{}\"{}", param.ident, did_you_mean(&param.ident.to_string(), &RestVariant::NAMES))
+			));
+		}
+		return Err(syn::Error::new(
+			name.span(),
+			format!("Invalid REST Component used for struct name{}", did_you_mean(&name.to_string(), &RestVariant::NAMES))
+		));
+	}
+	if input.peek(Token![where]) {
+		generics.where_clause = Some(input.parse()?);
+	}
+	Ok((name, generics, variant))
+}
+
+/// Returns true if the next tokens are a REST Component Type within angle brackets,
+/// i.e., ``` <Response> ```
+fn peek_variant(input: ParseStream) -> bool {
+	let fork = input.fork();
+	return fork.parse::<Token![<]>().is_ok()
+		&& fork.parse::<Ident>().is_ok_and(|variant| RestVariant::is_valid(&variant))
+		&& fork.peek(Token![>]);
+}
+
+/// # Optional Type Parser
+/// Parses a field's Type, where any generic argument may be marked as optional with a
+/// leading ``` ? ```, at any nesting depth, i.e., ``` Vec<?String> ``` parses as
+/// ``` Vec<Option<String>> ```, and ``` HashMap<String, ?u64> ``` as
+/// ``` HashMap<String, Option<u64>> ```. Types without any ``` ? ``` are parsed by syn.
+pub fn parse_optional_type(input: ParseStream) -> syn::Result<Type> {
+	let fork = input.fork();
+	if let Ok(ty) = fork.parse::<Type>() {
+		input.advance_to(&fork);
+		return Ok(ty);
+	}
+	if input.peek(Token![?]) {
+		input.parse::<Token![?]>()?;
+		let inner = parse_optional_type(input)?;
+		return Ok(parse_quote!(::core::option::Option<#inner>));
+	}
+	if !input.peek(syn::Ident) && !input.peek(Token![::]) {
+		return input.parse();
+	}
+	let leading = input.parse::<Option<Token![::]>>()?;
+	let mut segments: Vec<TokenStream2> = Vec::new();
+	loop {
+		let ident = input.call(Ident::parse_any)?;
+		let mut args: Vec<TokenStream2> = Vec::new();
+		if input.peek(Token![<]) {
+			input.parse::<Token![<]>()?;
+			while !input.peek(Token![>]) {
+				args.push(parse_optional_generic_argument(input)?);
+				if !input.peek(Token![,]) {
+					break;
+				}
+				input.parse::<Token![,]>()?;
+			}
+			input.parse::<Token![>]>()?;
+		}
+		segments.push(match args.is_empty() {
+			true => quote!(#ident),
+			false => quote!(#ident < #( #args ),* >),
+		});
+		if !input.peek(Token![::]) {
+			break;
+		}
+		input.parse::<Token![::]>()?;
+	}
+	return syn::parse2(quote!( #leading #( #segments )::* ));
+}
+
+/// Parses a single generic argument for [parse_optional_type], i.e., a lifetime,
+/// an associated type binding like ``` Item = ?T ```, or an optional Type.
+fn parse_optional_generic_argument(input: ParseStream) -> syn::Result<TokenStream2> {
+	if input.peek(syn::Lifetime) {
+		let lifetime = input.parse::<Lifetime>()?;
+		return Ok(quote!(#lifetime));
+	}
+	if input.peek(syn::Ident) && input.peek2(Token![=]) {
+		let ident = input.parse::<Ident>()?;
+		input.parse::<Token![=]>()?;
+		let ty = parse_optional_type(input)?;
+		return Ok(quote!(#ident = #ty));
+	}
+	let ty = parse_optional_type(input)?;
+	return Ok(quote!(#ty));
+}
+
+/// Returns true if the provided Type is written as an ``` Option<T> ```,
+/// i.e., ``` Option<T> ```, ``` std::option::Option<T> ```, etc.
+pub fn is_option_type(ty: &Type) -> bool {
+	let Type::Path(path) = ty else {
+		return false;
+	};
+	return path.qself.is_none() && path.path.segments
+		.last()
+		.is_some_and(|segment| segment.ident == "Option");
+}
+
+/// Returns true if the provided Type is a sequence serde_qs renders with indexed keys,
+/// i.e., ``` Vec<T> ```, ``` HashSet<T> ```, ``` [T; N] ```, etc.
+pub fn is_sequence_type(ty: &Type) -> bool {
+	return match ty {
+		Type::Array(_) | Type::Slice(_) => true,
+		Type::Reference(reference) => is_sequence_type(&reference.elem),
+		Type::Path(path) => path.path.segments.last().is_some_and(|segment| matches!(
+			segment.ident.to_string().as_str(),
+			"Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap"
+		)),
+		_ => false,
+	};
+}
+
+/// Returns true if the provided Type is a string, whose length is counted in chars,
+/// i.e., ``` String ```, ``` &str ```, ``` Cow<'a, str> ```, ``` Box<str> ```, etc.
+pub fn is_string_type(ty: &Type) -> bool {
+	return match ty {
+		Type::Reference(reference) => is_string_type(&reference.elem),
+		Type::Path(path) => path.path.segments.last().is_some_and(|segment| match segment.ident.to_string().as_str() {
+			"String" | "str" => true,
+			"Cow" | "Box" | "Rc" | "Arc" => matches!(&segment.arguments, syn::PathArguments::AngleBracketed(args)
+				if args.args.iter().any(|arg| matches!(arg, syn::GenericArgument::Type(ty) if is_string_type(ty)))
+			),
+			_ => false,
+		}),
+		_ => false,
+	};
+}
+
+/// Returns every named lifetime within the provided Type, in order of appearance,
+/// i.e., ``` 'a ``` for ``` Cow<'a, str> ```. Excludes ``` 'static ``` and ``` '_ ```
+pub fn type_lifetimes(ty: &Type) -> Vec<Lifetime> {
+	fn walk(tokens: TokenStream2, lifetimes: &mut Vec<Lifetime>) {
+		let mut tokens = tokens.into_iter();
+		while let Some(token) = tokens.next() {
+			match token {
+				TokenTree::Group(group) => walk(group.stream(), lifetimes),
+				TokenTree::Punct(apostrophe) if apostrophe.as_char() == '\'' => {
+					let Some(TokenTree::Ident(ident)) = tokens.next() else { continue };
+					if ident == "static" || ident == "_" {
+						continue;
+					}
+					let lifetime = Lifetime { apostrophe: apostrophe.span(), ident };
+					if !lifetimes.contains(&lifetime) {
+						lifetimes.push(lifetime);
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+	let mut lifetimes = Vec::new();
+	walk(quote!(#ty), &mut lifetimes);
+	return lifetimes;
+}
+
+/// Substitutes every bare Type name within the provided Type with the Type it's mapped to,
+/// at any nesting depth, i.e., ``` Vec<Uuid> ``` becomes ``` Vec<::uuid::Uuid> ```.
+/// Qualified paths, i.e., ``` my_ids::Uuid ```, are left as is.
+pub fn substitute_types(ty: &Type, types: &HashMap<String, Type>) -> Type {
+	fn walk(tokens: TokenStream2, types: &HashMap<String, Type>) -> TokenStream2 {
+		let mut substituted = TokenStream2::new();
+		let mut tokens = tokens.into_iter().peekable();
+		// Whether the previous token was either a path separator or a lifetime's apostrophe.
+		let mut qualified = false;
+		while let Some(token) = tokens.next() {
+			let separator = matches!(&token, TokenTree::Punct(p) if p.as_char() == ':' || p.as_char() == '\'');
+			match token {
+				TokenTree::Group(group) => {
+					let mut inner = Group::new(group.delimiter(), walk(group.stream(), types));
+					inner.set_span(group.span());
+					substituted.extend([TokenTree::Group(inner)]);
+				}
+				TokenTree::Ident(ident) if !qualified => {
+					let segment = matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ':');
+					match types.get(&ident.to_string()) {
+						Some(ty) if !segment => substituted.extend(quote!(#ty)),
+						_ => substituted.extend([TokenTree::Ident(ident)]),
+					}
+				}
+				token => substituted.extend([token]),
+			}
+			qualified = separator;
+		}
+		return substituted;
+	}
+	if types.is_empty() {
+		return ty.clone();
+	}
+	return syn::parse2(walk(quote!(#ty), types)).unwrap_or_else(|_| ty.clone());
+}
+
+/// Returns true if the provided Type borrows, i.e., ``` &str ``` or ``` Cow<'a, str> ```, or
+/// names one of the provided Generics' parameters. Such a Type can only be named within
+/// the generic context of its struct.
+pub fn references_generics(ty: &Type, generics: &Generics) -> bool {
+	fn walk(tokens: TokenStream2, params: &[String]) -> bool {
+		return tokens.into_iter().any(|token| match token {
+			TokenTree::Group(group) => walk(group.stream(), params),
+			TokenTree::Punct(punct) => punct.as_char() == '&',
+			TokenTree::Ident(ident) => params.contains(&ident.to_string()),
+			TokenTree::Literal(_) => false,
+		});
+	}
+	if !type_lifetimes(ty).is_empty() {
+		return true;
+	}
+	let params: Vec<String> = generics.type_params().map(|param| param.ident.to_string())
+		.chain(generics.const_params().map(|param| param.ident.to_string()))
+		.collect();
+	return walk(quote!(#ty), &params);
+}
+
+/// Returns true if the provided Type is a reference to either ``` str ``` or ``` [u8] ```,
+/// which serde borrows from the deserializer's input without ``` #[serde(borrow)] ```
+pub fn is_implicitly_borrowed(ty: &Type) -> bool {
+	let Type::Reference(reference) = ty else {
+		return false;
+	};
+	return match reference.elem.as_ref() {
+		Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+		Type::Slice(slice) => matches!(slice.elem.as_ref(), Type::Path(path) if path.path.is_ident("u8")),
+		_ => false,
+	};
+}
+
+/// Returns the ``` T ``` of a Type written as an ``` Option<T> ```, see [is_option_type]
+pub fn option_inner_type(ty: &Type) -> Option<&Type> {
+	let Type::Path(path) = ty else {
+		return None;
+	};
+	if !is_option_type(ty) {
+		return None;
 	}
-	Ok((name, variant))
+	let syn::PathArguments::AngleBracketed(args) = &path.path.segments.last()?.arguments else {
+		return None;
+	};
+	return match args.args.first()? {
+		syn::GenericArgument::Type(inner) => Some(inner),
+		_ => None,
+	};
 }
 
 /// # Extension functions for syn::Result
 /// * **and_parse_next**: A Clone of std::Result's **and_then** function.
-///    Only difference being that this version is implemented for syn::Result and will
-///    return a syn::Result.
+///   Only difference being that this version is implemented for syn::Result and will
+///   return a syn::Result.
 pub trait SynExtent<T>{
 	fn and_next<P, F: FnOnce(T) -> syn::Result<P>>(self, op: F) -> syn::Result<P>;
 }
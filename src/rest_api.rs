@@ -1,77 +1,237 @@
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Ident};
-use syn::spanned::Spanned;
+use quote::{format_ident, quote};
+use proc_macro2::Span;
+use syn::{Ident, Visibility};
+use crate::attributes::{MethodAttr, TypeAttr};
 use crate::generators::{gen_endpoint_structs, gen_endpoint_enums};
+use crate::generators::enum_str::{gen_unknown_variant, has_enum_str};
+use crate::generators::hal::gen_hal_links;
+use crate::generators::header::{gen_header_error, gen_header_names};
+use crate::generators::method::{gen_content_negotiation, gen_decode_error, gen_error_decoder, gen_feature_gate, gen_method_config};
+use crate::generators::proto::gen_proto;
+use crate::generators::response::gen_ndjson_error;
+use crate::generators::path::{gen_path_error, gen_path_params};
+use crate::generators::routes::{gen_routes, Route};
+use crate::generators::support::gen_runtime_support;
+use crate::generators::trace::gen_trace_span;
+use crate::generators::validate::{gen_validate_on_send, gen_validation_errors};
+use crate::parsers::components::Components;
+use crate::parsers::endpoint::Endpoint;
 use crate::parsers::endpoint_method::EndpointDataType;
 use crate::parsers::rest_enum::Enum;
 use crate::parsers::rest_struct::Struct;
-use crate::parsers::RestEndpoints;
-use crate::utils::{camelCase, camelCaseIdent, create_type_identifier, snake_case, snake_case_ident};
-use crate::utils::fmt::{rust_fmt_quotes};
+use crate::parsers::{RestDebug, RestEndpoints};
+use crate::parsers::analysis::Analysis;
+use crate::parsers::naming::{apply_names, method_struct_name};
+use crate::parsers::options::RestOptions;
+use crate::parsers::scalars::resolve_scalars;
+use crate::parsers::symbols::Symbols;
+use crate::parsers::struct_parameter::StructParameterSlice;
+use crate::utils::{snake_case, snake_case_ident, RestVariant};
+use crate::utils::fmt::{rust_fmt_quotes, rust_fmt_string};
+use crate::utils::profile;
 
 pub type SynError = syn::Error;
 
 /// Parses `restify!` TokenStream then compiles RESTful Client code.
+/// With ``` RESTIFY_PROFILE=1 ```, the expansion's timings are reported as a compiler warning.
 pub fn compile_rest(input: TokenStream) -> TokenStream {
+	profile::start(profile::enabled());
+	let rest = profile::time("parse", || syn::parse::<RestEndpoints>(input));
+	let output = match rest.and_then(generate_rest) {
+		Ok(output) => output,
+		Err(err) => err.to_compile_error(),
+	};
+	let report = match profile::report() {
+		Some(report) => quote_note(quote!(RestifyProfile), &report),
+		None => quote!(),
+	};
+	return quote!( #output #report ).into();
+}
+
+/// Parses `restify_debug!` TokenStream, then compiles the same RESTful Client code as
+/// `restify!` would. Instead of emitting it, the prettified code is either reported as a
+/// compiler warning, or written to the file declared with ``` file: ".." ```, relative to the
+/// output directory, see [RestOptions::output_path].
+pub fn compile_rest_debug(input: TokenStream) -> TokenStream {
+	profile::start(profile::enabled());
+	let note = profile::time("parse", || syn::parse::<RestDebug>(input))
+		.and_then(debug_note);
+	let note = match note {
+		Ok(note) => quote_note(quote!(RestifyDebug), &note),
+		Err(err) => return err.to_compile_error().into(),
+	};
+	let report = match profile::report() {
+		Some(report) => quote_note(quote!(RestifyProfile), &report),
+		None => quote!(),
+	};
+	return quote!( #note #report ).into();
+}
+
+/// Generates the code of a `restify_debug!` invocation, and returns the note reported to the
+/// user: either the prettified code, or where it was written to.
+fn debug_note(debug: RestDebug) -> syn::Result<String> {
+	let RestDebug{ file, rest } = debug;
+	let generated = rust_fmt_string(&generate_rest(rest)?.to_string());
+	let Some(file) = file else {
+		return Ok(format!("restify_debug: The generated code\n{generated}"));
+	};
+	let path = RestOptions::output_path(&file.value());
+	path.parent()
+		.map_or(Ok(()), std::fs::create_dir_all)
+		.and_then(|_| std::fs::write(&path, &generated))
+		.map_err(|err| SynError::new(file.span(), format!("restify_debug: Failed to write \"{}\": {err}", path.display())))?;
+	return Ok(format!("restify_debug: The generated code was written to \"{}\"", path.display()));
+}
+
+/// Reports **note** to the user as a compiler warning. Stable proc-macros can't emit notes,
+/// so it's reported as the deprecation warning of a private item named **name**.
+fn quote_note(name: TokenStream2, note: &str) -> TokenStream2 {
+	return quote! {
+		const _: () = {
+			#[deprecated(note = #note)]
+			struct #name;
+			let _ = #name;
+		};
+	};
+}
+
+/// The `restify!` pipeline shared by `restify!` and `restify_debug!`: names, resolves and
+/// analyzes the parsed invocation, then generates its code.
+fn generate_rest(rest: RestEndpoints) -> syn::Result<TokenStream2> {
+	log::debug!("restify - Parsed: {:?}", rest);
 	let RestEndpoints{
-		endpoints
-	} = parse_macro_input!(input as RestEndpoints);
+		mut options,
+		uses,
+		types,
+		mut components,
+		mut endpoints
+	} = rest;
+	profile::time("naming", || apply_names(&options, components.as_mut(), &mut endpoints));
+	options.support_mod = support_mod_name(components.as_ref(), &endpoints);
+	options.install();
+	profile::time("scalars", || resolve_scalars(types.as_ref(), components.as_mut(), &mut endpoints));
+	let symbols = profile::time("symbols", || Symbols::build(components.as_ref(), &endpoints))?;
+	profile::time("analysis", || Analysis::run(components.as_ref(), &endpoints, &symbols))?;
+	
+	// Every Data Type declared within this invocation, and every Struct among them, nested
+	// Structs included.
+	let methods = endpoints.iter()
+		.flat_map(|endpoint| endpoint.methods.iter())
+		.collect::<Vec<_>>();
+	let data_types = methods.iter()
+		.flat_map(|method| method.data_types.iter())
+		.chain(components.iter().flat_map(|components| components.data_types.iter()))
+		.collect::<Vec<_>>();
+	let structs = data_types.iter()
+		.filter_map(|dt| match dt {
+			EndpointDataType::Struct(st) => Some(st),
+			_ => None,
+		})
+		.flat_map(|st| std::iter::once(st).chain(st.nested_structs()))
+		.collect::<Vec<_>>();
+	let has_type_attr = |is: fn(&TypeAttr) -> bool| structs.iter().any(|st| st.attributes.iter().any(is));
+	
+	// Each part of the runtime support is only emitted when a declared Type needs it, see
+	// gen_runtime_support.
+	let validation_errors = if structs.iter().any(|st| st.has_validate()) {
+		gen_validation_errors()
+	} else { quote!() };
+	
+	let header_structs = structs.iter()
+		.filter(|st| matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Header)))
+		.collect::<Vec<_>>();
+	let headers = if !header_structs.is_empty() {
+		let header_names = header_structs.iter()
+			.flat_map(|st| st.parameters.iter().map(|field| field.header_name()))
+			.collect::<Vec<_>>();
+		let header_names = gen_header_names(&header_names);
+		let header_error = gen_header_error();
+		quote!( #header_error #header_names )
+	} else { quote!() };
+	
+	let hal_links = if has_type_attr(|a| matches!(a, TypeAttr::Hal(_))) {
+		gen_hal_links()
+	} else { quote!() };
+	
+	let ndjson_error = if has_type_attr(|a| matches!(a, TypeAttr::Ndjson)) {
+		gen_ndjson_error()
+	} else { quote!() };
+	
+	let needs_unknown_variant = data_types.iter()
+		.any(|dt| matches!(dt, EndpointDataType::Enum(en) if has_enum_str(en.attributes.iter(), (&en.enums).into())));
+	let unknown_variant = if needs_unknown_variant {
+		gen_unknown_variant()
+	} else { quote!() };
+	
+	let decode_error = if methods.iter().any(|method| !method.accepts.is_empty()) {
+		gen_decode_error()
+	} else { quote!() };
+	
+	let path_error = if methods.iter().any(|method| !method.path.is_empty()) {
+		gen_path_error()
+	} else { quote!() };
+	
+	let needs_query_pairs = structs.iter().any(|st| matches!(RestVariant::try_from(st.variant()), Ok(RestVariant::Query)));
+	let needs_secrets = structs.iter().any(|st| st.parameters.iter().any(|field| field.is_secret()));
+	let shared = quote! {
+		#validation_errors
+		#headers
+		#hal_links
+		#path_error
+		#decode_error
+		#ndjson_error
+		#unknown_variant
+	};
+	let runtime_support = gen_runtime_support(needs_query_pairs, needs_secrets, shared);
+	
+	// Shared components are generated once, ahead of every Endpoint referencing them.
+	let components = match &components {
+		Some(components) => {
+			let data_objects = components.data_types.iter()
+				.map(|dt| profile::time_with(|| format!("components: {}", dt.name()), || gen_data_type(&components.vis, dt)))
+				.collect::<Vec<TokenStream2>>();
+			if RestOptions::debug() {
+				let generated_file = std::iter::once(quote!( #( #uses )* ))
+					.chain(data_objects.iter().cloned())
+					.collect::<Vec<_>>();
+				rust_fmt_quotes(&RestOptions::output_path("generated/Components_generated_code.rs"), &generated_file)
+					.map_err(|err| SynError::new(Span::call_site(), format!("restify: Failed to dump the generated code: {err}")))?;
+			}
+			quote!( #( #data_objects )* )
+		}
+		None => quote!(),
+	};
+	
+	// Every REST Method of every Endpoint, listed within a single Routes enum.
+	let routes = endpoints.iter()
+		.flat_map(|endpoint| endpoint.methods.iter().map(move |method| Route {
+			name: RestOptions::with(|options| method_struct_name(options, &endpoint.name, &method.method)),
+			method: method.method.clone(),
+			uri: method.uri.clone(),
+			feature: method.feature().cloned(),
+		}))
+		.collect::<Vec<_>>();
+	let routes_name = RestOptions::with(RestOptions::routes_name);
+	let routes = profile::time("routes", || gen_routes(&routes_name, &routes));
 	
-	let _generated_code: Vec<TokenStream2> = endpoints.iter().map(|endpoint| {
+	let generated_code: Vec<TokenStream2> = endpoints.iter().map(|endpoint| {
 		let vis = &endpoint.vis;
 		let endpoint_name = &endpoint.name;
-		let methods: Vec<TokenStream2> = endpoint.methods.iter().map(|method| {
+		let methods: Vec<TokenStream2> = endpoint.methods.iter().map(|method| profile::time_with(|| format!("{endpoint_name}: {} {}", method.method, method.uri.value()), || {
 			let method_name = &method.method;
-			let _uri = &method.uri;
 			let mut type_idents: Vec<Ident> = Vec::new();
 			
 			let data_objects: Vec<TokenStream2> = method.data_types.iter().map(|endpoint_dt| {
-				match endpoint_dt {
-					EndpointDataType::Enum(en) => {
-						let Enum {
-							attributes,
-							name,
-							enums,
-						} = en;
-						type_idents.push(name.clone());
-						
-						gen_endpoint_enums(
-							vis,
-							attributes.iter(),
-							name,
-							enums.into()
-						)
-					},
-					EndpointDataType::Struct(st) => {
-						let Struct {
-							attributes,
-							name,
-							rest_variant,
-							parameters
-						} = st;
-						
-						let struct_name = name;
-						type_idents.push(name.clone());
-						
-						gen_endpoint_structs(
-							vis,
-							attributes.iter(),
-							name,
-							rest_variant,
-							struct_name,
-							parameters.into()
-						)
-					}
-				}
+				type_idents.push(endpoint_dt.name().clone());
+				gen_data_type(vis, endpoint_dt)
 			}).collect(); // data_objects: Internal user-defined structs and enums
 			
-			let _rest_method_struct_name = create_type_identifier(&[""]);
 			let method_params = type_idents
 				.iter()
 				.fold(vec![], |mut quotes, ident| {
-					let param_ident = snake_case_ident(&[ident.to_string().as_str()], false);
+					let param_ident = snake_case_ident(&[ident.to_string().as_str()], false, ident.span());
 					quotes.push(
 						quote!{
 							#param_ident: #ident,
@@ -79,10 +239,39 @@ pub fn compile_rest(input: TokenStream) -> TokenStream {
 					quotes
 				});
 			
-			let method_name = camelCaseIdent(&[
-				endpoint_name.to_string().as_str(),
-				method_name.to_string().as_str(),
-			], true);
+			let method_name = RestOptions::with(|options| method_struct_name(options, endpoint_name, method_name));
+			
+			let trace = if method.attributes.iter().any(|a| matches!(a, MethodAttr::Trace)) {
+				gen_trace_span(vis, endpoint_name, &method.method, &method.uri, &method_name)
+			} else { quote!() };
+			
+			let validate_on_send = if method.attributes.iter().any(|a| matches!(a, MethodAttr::ValidateOnSend)) {
+				let components = method.data_types.iter()
+					.filter_map(|dt| match symbols.resolve(dt) {
+						EndpointDataType::Struct(st) if st.has_validate() => {
+							Some(snake_case_ident(&[st.name.to_string().as_str()], false, st.name.span()))
+						}
+						_ => None,
+					})
+					.collect::<Vec<_>>();
+				gen_validate_on_send(vis, &method_name, &components)
+			} else { quote!() };
+			
+			let config = gen_method_config(vis, &method_name, &method.method, &method.uri, method.attributes.iter(), symbols.form_of(method).is_some());
+			let path = gen_path_params(vis, &method_name, &method.uri, &method.path);
+			let negotiation = match symbols.response_of(method) {
+				Some(response) => gen_content_negotiation(vis, &method_name, &method.accepts, &response.name),
+				None => quote!(),
+			};
+			let error = match symbols.error_of(method) {
+				Some(error) => {
+					let response = symbols.response_of(method)
+						.filter(|response| response.generics.params.is_empty())
+						.map(|response| &response.name);
+					gen_error_decoder(vis, &method_name, &error.name, response)
+				}
+				None => quote!(),
+			};
 			
 			let output = quote!{
 				#( #data_objects )*
@@ -90,25 +279,128 @@ pub fn compile_rest(input: TokenStream) -> TokenStream {
 				#vis struct #method_name {
 					#( #vis #method_params )*
 				}
+				#trace
+				#validate_on_send
+				#config
+				#path
+				#negotiation
+				#error
 			};
 			
-			output.into()
-		}).collect(); // methods: Generator
-		let attrs = &endpoint.attrs;
-		
+			match method.feature() {
+				Some(feature) => gen_feature_gate(feature, output),
+				None => output,
+			}
+		})).collect(); // methods: Generator
 		
 		let output = quote!{
 			#( #methods )*
 		};
 		
-		rust_fmt_quotes(
-			&endpoint_name.to_string(),
-			&methods
-		);
+		// The generated file needs the user's use statements as well, to resolve.
+		if RestOptions::debug() {
+			let generated_file = std::iter::once(quote!( #( #uses )* ))
+				.chain(methods.iter().cloned())
+				.collect::<Vec<_>>();
+			rust_fmt_quotes(
+				&RestOptions::output_path(&format!("generated/{endpoint_name}_generated_code.rs")),
+				&generated_file
+			).map_err(|err| SynError::new(endpoint_name.span(), format!("restify: Failed to dump the generated code: {err}")))?;
+		}
 		
-		output.into()
-	}).collect();
+		Ok(output)
+	}).collect::<syn::Result<_>>()?;
 	
-	let output = quote!{};
-	output.into()
-}
\ No newline at end of file
+	let output = quote!{
+		#( #uses )*
+		#runtime_support
+		#components
+		#routes
+		#( #generated_code )*
+	};
+	Ok(output)
+}
+
+/// Names the invocation's runtime-support module after its first Endpoint, or its first
+/// component when it declares none, i.e., ``` __restify_user ```, so that several
+/// `restify!` invocations can share a single module. See [RestOptions::support].
+fn support_mod_name(components: Option<&Components>, endpoints: &[Endpoint]) -> Option<Ident> {
+	let name = endpoints.first()
+		.map(|endpoint| &endpoint.name)
+		.or_else(|| components?.data_types.first().map(|dt| dt.name()))?;
+	let name = snake_case(&[name.to_string().as_str()], false).to_lowercase();
+	return Some(format_ident!("__restify_{}", name));
+}
+
+/// Generates a user-defined Struct, preceded by the structs of its inline nested fields.
+fn gen_struct(vis: &Visibility, st: &Struct) -> TokenStream2 {
+	let Struct {
+		attributes,
+		name,
+		generics,
+		rest_variant,
+		parameters,
+		nested,
+	} = st;
+	
+	let nested = nested.iter().map(|nested| gen_struct(vis, nested));
+	let struct_name = name;
+	let generated = gen_endpoint_structs(
+		vis,
+		attributes.iter(),
+		name,
+		rest_variant,
+		struct_name,
+		StructParameterSlice::from(parameters).with_generics(generics)
+	);
+	return quote!(
+		#( #nested )*
+		#generated
+	);
+}
+
+/// Generates a single user-defined Struct or Enum, declared either within a REST Method
+/// or within the shared ``` components: { .. } ``` block.
+fn gen_data_type(vis: &Visibility, data_type: &EndpointDataType) -> TokenStream2 {
+	return match data_type {
+		EndpointDataType::Enum(en) => {
+			let Enum {
+				attributes,
+				name,
+				enums,
+			} = en;
+			
+			gen_endpoint_enums(
+				vis,
+				attributes.iter(),
+				name,
+				enums.into()
+			)
+		},
+		EndpointDataType::Struct(st) => gen_struct(vis, st),
+		EndpointDataType::Proto(proto) => gen_proto(vis, proto),
+		// Already generated along with the referenced Type's declaration.
+		EndpointDataType::Reference(_) => quote!(),
+	};
+}
+#[cfg(test)]
+mod rest_api_tests {
+	use super::*;
+	
+	#[test] fn debug_note_reports_endpoint_types() {
+		let debug = syn::parse_str::<RestDebug>(r#"
+			[User: {
+				GET "/api/user/{id}" => {
+					struct Response { name: String }
+				}
+			}]
+		"#).unwrap();
+		let note = debug_note(debug).unwrap();
+		let note = note.split_whitespace().collect::<Vec<_>>().join(" ");
+		assert!(note.starts_with("restify_debug: The generated code"));
+		assert!(note.contains("struct UserGET {"), "{note}");
+		assert!(note.contains("const PATH: &'static str = \"/api/user/{id}\";"), "{note}");
+		assert!(note.contains("struct Response {"), "{note}");
+		assert!(note.contains("struct UserGETPath {"), "{note}");
+	}
+}
@@ -21,7 +21,6 @@ impl DocString {
 	}
 	
 	pub fn merge(mut self, rhs: Self) -> Self{
-		let rhs = rhs;
 		self.body.extend(rhs.body);
 		self
 	}
@@ -41,7 +40,7 @@ impl DocString {
 		let output = quote!{
 			#[doc = #doc_str]
 		};
-		output.into()
+		output
 	}
 }
 
@@ -1,49 +1,44 @@
 use std::fs;
 use std::io::Write;
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Stdio};
 use proc_macro2::TokenStream;
 
-const TMP_FILE: &'static str = "/generated/{0}_generated_code.rs";
-
-/// Working around println and how quote! Formats Rust code.
-/// Temporarily writes generated code to a local file. We then
-/// run "rustfmt" on said file to reformat the generated code.
-/// And Finally we load in the file and print it out to the console.
-#[allow(unused)]
-pub fn rust_fmt(title: &str, quote: &str) {
-	let manifest_dir = env!("CARGO_MANIFEST_DIR");
-	let file = format!("{manifest_dir}{}", TMP_FILE.replace("{0}", title));
-	
-	fs::write(&file, quote).unwrap();
-	Command::new("rustfmt")
-		.arg(&file)
-		.status()
-		.expect("Failed to execute rustfmt");
-	
-	let formatted_code = fs::read_to_string(&file).expect("Unable to read file");
-	println!("Formatted Code:\n{formatted_code}");
+/// Formats generated code with rustfmt, through its stdin. Returns the code unformatted when
+/// rustfmt isn't available, or can't parse it.
+pub fn rust_fmt_string(code: &str) -> String {
+	let rustfmt = Command::new("rustfmt")
+		.args(["--edition", "2021", "--emit", "stdout"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn();
+	let Ok(mut rustfmt) = rustfmt else {
+		return code.to_string();
+	};
+	if let Some(mut stdin) = rustfmt.stdin.take() {
+		if stdin.write_all(code.as_bytes()).is_err() {
+			return code.to_string();
+		}
+	}
+	return match rustfmt.wait_with_output() {
+		Ok(output) if output.status.success() => String::from_utf8(output.stdout)
+			.unwrap_or_else(|_| code.to_string()),
+		_ => code.to_string(),
+	};
 }
 
 /// # Restify Generator Debugging
-/// This method will take a TokenStream slice of the generated code by restify.
-/// Storing it in a file, using **rust_fmt** to format the file(syn wasn't built
-/// to generate pretty code..)
-/// And finally, we reload the formatted file, and print it onto the terminal.
-pub fn rust_fmt_quotes(title: &str, quotes: &[TokenStream]){
-	let manifest_dir = env!("CARGO_MANIFEST_DIR");
-	let file = format!("{manifest_dir}{}", TMP_FILE.replace("{0}", title));
-	let mut raw = String::new();
-	for q in quotes.iter(){
-		raw.push_str(&q.to_string());
-	}
-	
-	fs::write(&file, raw).expect("Failed to create & add data to file");
-	Command::new("rustfmt")
-		.arg(&file)
-		.status()
-		.expect("Failed to execute rustfmt");
+/// Formats the generated code of **quotes** with [rust_fmt_string], then writes it to **file**,
+/// creating its directory if needed. Like `restify_debug!`'s ``` file: ".." ```, the dump is
+/// only ever written to disk, never printed: a proc-macro's stdout is the compiler's.
+pub fn rust_fmt_quotes(file: &Path, quotes: &[TokenStream]) -> std::io::Result<()> {
+	let raw = quotes.iter()
+		.map(|quote| quote.to_string())
+		.collect::<String>();
 	
-	let formatted_code = fs::read_to_string(&file).expect("Unable to read file");
-	println!("Formatted Code:\n{formatted_code}");
-	std::io::stdout().flush().unwrap();
+	if let Some(dir) = file.parent() {
+		fs::create_dir_all(dir)?;
+	}
+	return fs::write(file, rust_fmt_string(&raw));
 }
@@ -0,0 +1,25 @@
+use std::sync::Once;
+use log::LevelFilter;
+
+/// The environment variable controlling Restify's compile-time logging. Accepts env_logger's
+/// filter syntax, i.e., ``` RESTIFY_LOG=trace cargo build ```, and logs nothing when unset.
+pub const LOG_ENV: &str = "RESTIFY_LOG";
+
+/// # Restify Logging
+/// Initializes the logger behind Restify's compile-time diagnostics, once per compiler
+/// process. Parsers and generators log through the **log** macros, rather than printing,
+/// so user builds stay quiet unless ``` RESTIFY_LOG ``` asks otherwise:
+///   - **debug**: What Restify parsed and generated.
+///   - **trace**: The internals of attribute parsing.
+///
+/// Logs are written to stderr.
+pub fn init() {
+	static INIT: Once = Once::new();
+	INIT.call_once(|| {
+		let _ = env_logger::Builder::new()
+			.filter_level(LevelFilter::Off)
+			.parse_env(env_logger::Env::new().filter(LOG_ENV))
+			.format_timestamp(None)
+			.try_init();
+	});
+}
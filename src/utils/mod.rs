@@ -1,13 +1,10 @@
 pub mod fmt;
 pub mod doc_str;
 pub mod format_string;
+pub mod logging;
+pub mod profile;
 
-use proc_macro::Span;
-use std::io::Write;
-use std::ops::Index;
-use std::str::FromStr;
 use displaydoc::Display;
-use syn::spanned::Spanned;
 
 #[derive(Debug, Clone, Display, Eq, PartialEq)]
 pub enum RestVariant {
@@ -19,10 +16,19 @@ pub enum RestVariant {
 	Response,
 	/// ReqRes
 	ReqRes,
+	/// Error
+	Error,
+	/// Form
+	Form,
 	/// Query
 	Query,
+	/// Raw
+	Raw,
 }
 impl RestVariant {
+	/// The name of every REST Component Type, as declared within a `restify!`
+	pub const NAMES: [&'static str; 8] = ["Header", "Request", "Response", "ReqRes", "Error", "Form", "Query", "Raw"];
+	
 	pub fn is_valid(variant: &proc_macro2::Ident) -> bool {
 		return RestVariant::try_from(variant).is_ok();
 	}
@@ -30,27 +36,33 @@ impl RestVariant {
 
 impl TryFrom<&proc_macro2::Ident> for RestVariant {
 	type Error = syn::Error;
-	fn try_from(ident: &proc_macro2::Ident) -> Result<Self, Self::Error> {
-		return RestVariant::try_from(ident.to_string());
+	fn try_from(ident: &proc_macro2::Ident) -> Result<Self, syn::Error> {
+		return RestVariant::try_from(ident.to_string())
+			.map_err(|err| syn::Error::new(ident.span(), err));
 	}
 }
 impl TryFrom<String> for RestVariant {
 	type Error = syn::Error;
-	fn try_from(variant: String) -> Result<Self, Self::Error>  {
+	fn try_from(variant: String) -> Result<Self, syn::Error>  {
 		return match variant.as_str() {
 			"Header"   => Ok(RestVariant::Header),
 			"Request"  => Ok(RestVariant::Request),
 			"Response" => Ok(RestVariant::Response),
 			"ReqRes"   => Ok(RestVariant::ReqRes),
+			"Error"    => Ok(RestVariant::Error),
+			"Form"     => Ok(RestVariant::Form),
 			"Query"    => Ok(RestVariant::Query),
+			"Raw"      => Ok(RestVariant::Raw),
 			unknown    => Err(syn::Error::new(
 				proc_macro2::Span::call_site(),
-				&format!("An Unknown REST variant was found: {unknown}")
+				format!("An Unknown REST variant was found: {unknown}{}", did_you_mean(unknown, &RestVariant::NAMES))
 			))
 		}
 	}
 }
 
+// Variants are spelled as the HTTP Methods they're parsed from, i.e., GET.
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Display, Eq, PartialEq)]
 pub enum RestMethods {
 	/// GET
@@ -67,23 +79,28 @@ pub enum RestMethods {
 	OPTIONS,
 	/// HEAD
 	HEAD,
+	/// TRACE
+	TRACE,
+	/// CONNECT
+	CONNECT,
 }
 impl RestMethods {
-	pub fn is_valid(method: &proc_macro2::Ident) -> bool {
-		return RestMethods::try_from(method).is_ok();
-	}
+	/// The name of every REST Method.
+	pub const NAMES: [&'static str; 9] = ["GET", "POST", "PUT", "DELETE", "PATCH", "OPTIONS", "HEAD", "TRACE", "CONNECT"];
 }
 
 impl TryFrom<&proc_macro2::Ident> for RestMethods {
 	type Error = syn::Error;
 	fn try_from(ident: &proc_macro2::Ident) -> Result<Self, Self::Error> {
 		return RestMethods::try_from(ident.to_string())
+			.map_err(|err| syn::Error::new(ident.span(), err));
 	}
 }
+/// REST Methods are matched case-insensitively, i.e., ``` get ```, ``` Get ``` and ``` GET ```.
 impl TryFrom<String> for RestMethods {
 	type Error = syn::Error;
 	fn try_from(method: String) -> Result<Self, Self::Error> {
-		match method.as_str() {
+		match method.to_ascii_uppercase().as_str() {
 			"GET"     => Ok(RestMethods::GET),
 			"POST"    => Ok(RestMethods::POST),
 			"PUT"     => Ok(RestMethods::PUT),
@@ -91,14 +108,63 @@ impl TryFrom<String> for RestMethods {
 			"PATCH"   => Ok(RestMethods::PATCH),
 			"OPTIONS" => Ok(RestMethods::OPTIONS),
 			"HEAD"    => Ok(RestMethods::HEAD),
+			"TRACE"   => Ok(RestMethods::TRACE),
+			"CONNECT" => Ok(RestMethods::CONNECT),
 			unknown   => Err(syn::Error::new(
 				proc_macro2::Span::call_site(),
-				&format!("An Unknown REST Method was found: {unknown}")
+				format!("An Unknown REST Method was found: {unknown}{}", did_you_mean(unknown, &RestMethods::NAMES))
 			))
 		}
 	}
 }
 
+/// # Closest Match
+/// Returns the known name closest to an unknown one, if it's close enough to be a likely
+/// typo, i.e., "renam" => "rename". Names are compared case-insensitively, by their edit
+/// distance, where swapping two adjacent characters counts as a single edit. Which may be
+/// at most a third of the unknown name's length.
+pub fn closest_match<'k>(unknown: &str, known: &[&'k str]) -> Option<&'k str> {
+	let unknown = unknown.to_ascii_lowercase().chars().collect::<Vec<_>>();
+	let max_distance = (unknown.len() / 3).max(1);
+	return known.iter()
+		.map(|name| {
+			let candidate = name.to_ascii_lowercase().chars().collect::<Vec<_>>();
+			// Optimal string alignment distance, distances[i][j] between the first i and j characters.
+			let mut distances = vec![vec![0; candidate.len() + 1]; unknown.len() + 1];
+			for (i, row) in distances.iter_mut().enumerate() {
+				row[0] = i;
+			}
+			for (j, distance) in distances[0].iter_mut().enumerate() {
+				*distance = j;
+			}
+			for i in 1..=unknown.len() {
+				for j in 1..=candidate.len() {
+					let substitution = (unknown[i - 1] != candidate[j - 1]) as usize;
+					let mut distance = (distances[i - 1][j] + 1)
+						.min(distances[i][j - 1] + 1)
+						.min(distances[i - 1][j - 1] + substitution);
+					if i > 1 && j > 1 && unknown[i - 1] == candidate[j - 2] && unknown[i - 2] == candidate[j - 1] {
+						distance = distance.min(distances[i - 2][j - 2] + 1);
+					}
+					distances[i][j] = distance;
+				}
+			}
+			return (*name, distances[unknown.len()][candidate.len()]);
+		})
+		.filter(|(_, distance)| *distance <= max_distance)
+		.min_by_key(|(_, distance)| *distance)
+		.map(|(name, _)| name);
+}
+
+/// Formats the [closest_match] of an unknown name as a suggestion, to append onto an
+/// error message, i.e., ``` , did you mean "rename"? ```. Empty when nothing is close.
+pub fn did_you_mean(unknown: &str, known: &[&str]) -> String {
+	return match closest_match(unknown, known) {
+		Some(name) => format!(", did you mean \"{name}\"?"),
+		None => String::new(),
+	};
+}
+
 /// # &\[&str\] => snake_case String
 /// Takes in a slice of string slices, converts and concatenates
 /// them into a snake_case styled word.
@@ -114,7 +180,6 @@ impl TryFrom<String> for RestMethods {
 ///   * &["this", "is", "bob"] => "this_is_bob"
 ///   * &["my", "GET", "endpoint"] => "my_get_endpoint"
 ///   * &\["ThisIsMySuperLongName"\] => "this_is_my_super_long_name"
-#[allow(unused)]
 pub fn snake_case(words: &[&str], cap: bool) -> String {
 	words.iter().map(|word| {
 		if word.chars().all(char::is_uppercase) {
@@ -135,12 +200,10 @@ pub fn snake_case(words: &[&str], cap: bool) -> String {
 	}).collect::<Vec<_>>().join("_")
 }
 
-pub fn snake_case_ident(words: &[&str], cap: bool) -> proc_macro2::Ident {
+/// [snake_case] as an Ident, spanned at the declaration it was derived from.
+pub fn snake_case_ident(words: &[&str], cap: bool, span: proc_macro2::Span) -> proc_macro2::Ident {
 	let snake_case = snake_case(words, cap);
-	return proc_macro2::Ident::new(
-		&snake_case,
-		snake_case.span()
-	);
+	return proc_macro2::Ident::new(&snake_case, span);
 }
 
 /// # &\[&str\] => (c|C)amelCase String
@@ -158,7 +221,7 @@ pub fn snake_case_ident(words: &[&str], cap: bool) -> proc_macro2::Ident {
 ///   * camelCase(&["this", "is", "bob"], true) => "ThisIsBob"
 ///   * camelCase(&["my", "GET", "struct"], false) => "myGETStruct"
 ///   * camelCase(&["from_snake_case", false]) => "fromSnakeCase"
-#[allow(non_snake_case, unused)]
+#[allow(non_snake_case)]
 pub fn camelCase(words: &[&str], cap_first: bool) -> String {
 	let mut result = String::new();
 	let mut cap_next = false;
@@ -197,47 +260,10 @@ pub fn camelCase(words: &[&str], cap_first: bool) -> String {
 	result
 }
 
-#[allow(non_snake_case, unused)]
-pub fn camelCaseIdent(words: &[&str], cap: bool) -> proc_macro2::Ident {
-	return proc_macro2::Ident::new(
-		camelCase(words, cap).as_str(),
-		proc_macro2::Span::call_site()
-	);
-}
-
-
-/// Not sure why.
-/// But in my Kitty Terminal with TMUX, when I attempted to print out
-/// a Parsed Tree, it wouldn't print to the terminal.
-/// But the parsed tree would print
-/// out in Jetbrains builtin Terminal Emulator.
-/// IDK, weird
-#[allow(unused)]
-pub fn print_n_flush(output: &str) {
-	println!("{output}");
-	std::io::stdout().flush().unwrap();
-}
-
-/// # Struct/Enum Identifier Creation
-/// Takes a String from string slices.
-/// Concatenates them into a single
-/// string where the First Character of each string slice is Capitalized.
-/// And Thus following Rust's Syntax rules
-///
-/// # Example:
-/// * &["my", "struct", "name"] => "MyStructName
-pub fn create_type_identifier(words: &[&str]) -> String {
-	let mut struct_name = String::new();
-	
-	for word in words {
-		let mut c = word.chars();
-		let cap = match c.next(){
-			None => String::new(),
-			Some(first) => first.to_uppercase().collect::<String>() + c.as_str()
-		};
-		struct_name += &cap;
-	}
-	return struct_name;
+/// [camelCase] as an Ident, spanned at the declaration it was derived from.
+#[allow(non_snake_case)]
+pub fn camelCaseIdent(words: &[&str], cap: bool, span: proc_macro2::Span) -> proc_macro2::Ident {
+	return proc_macro2::Ident::new(camelCase(words, cap).as_str(), span);
 }
 
 #[cfg(test)]
@@ -309,4 +335,23 @@ mod util_tests {
 		assert_eq!(&c4, "fromSnakeCase", "Should be \"fromSnakeCase\"");
 	}
 	
+	#[test] fn expansion_profile() {
+		profile::start(true);
+		assert_eq!(profile::time("parse", || 1 + 1), 2);
+		profile::time_with(|| format!("User: {} {}", "GET", "/api/user"), || ());
+		let report = profile::report().unwrap();
+		
+		let lines = report.lines().collect::<Vec<_>>();
+		assert_eq!(lines[0], "restify: Expansion profile");
+		assert!(lines[1].trim_start().starts_with("parse "));
+		assert!(lines[2].trim_start().starts_with("User: GET /api/user "));
+		assert!(lines[3].trim_start().starts_with("total "));
+		assert!(profile::report().is_none());
+		
+		profile::start(false);
+		assert_eq!(profile::time("parse", || 2), 2);
+		profile::time_with(|| unreachable!("labels aren't built when not profiling"), || ());
+		assert!(profile::report().is_none());
+	}
+	
 }
\ No newline at end of file
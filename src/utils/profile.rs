@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// The environment variable enabling Restify's expansion profiling, i.e.,
+/// ``` RESTIFY_PROFILE=1 cargo build ```. ``` RESTIFY_PROFILE=0 ``` leaves it off.
+pub const PROFILE_ENV: &str = "RESTIFY_PROFILE";
+
+/// When an expansion started, along with how long each of its stages took, in order.
+type Timings = (Instant, Vec<(String, Duration)>);
+
+thread_local! {
+	/// The timings of the expansion currently running, if profiling.
+	static TIMINGS: RefCell<Option<Timings>> = const { RefCell::new(None) };
+}
+
+/// # Restify Profiling
+/// Times each stage of a single `restify!` expansion, so users with huge `restify!` blocks
+/// can find what's slow. Stages are timed with [time], between a [start] and a [report]:
+///   - **parse**: Parsing the `restify!` TokenStream.
+///   - **analysis**: Naming, resolving and analysing what was parsed.
+///   - **generate**: Each shared component, and each REST Method of each Endpoint.
+///
+/// Does nothing unless ``` RESTIFY_PROFILE ``` is set.
+pub fn enabled() -> bool {
+	return std::env::var(PROFILE_ENV).is_ok_and(|profile| !profile.is_empty() && profile != "0");
+}
+
+/// Starts profiling a new expansion when **enabled**, i.e., [enabled], dropping the timings
+/// of any previous one.
+pub fn start(enabled: bool) {
+	let timings = if enabled { Some((Instant::now(), Vec::new())) } else { None };
+	TIMINGS.with(|cell| *cell.borrow_mut() = timings);
+}
+
+/// Runs **stage**, and records how long it took under **label**, when profiling.
+pub fn time<T>(label: &str, stage: impl FnOnce() -> T) -> T {
+	return time_with(|| label.to_string(), stage);
+}
+
+/// Like [time], but only builds the stage's **label** when profiling.
+pub fn time_with<T>(label: impl FnOnce() -> String, stage: impl FnOnce() -> T) -> T {
+	if TIMINGS.with(|cell| cell.borrow().is_none()) {
+		return stage();
+	}
+	let started = Instant::now();
+	let output = stage();
+	let elapsed = started.elapsed();
+	TIMINGS.with(|cell| {
+		if let Some((_, timings)) = cell.borrow_mut().as_mut() {
+			timings.push((label(), elapsed));
+		}
+	});
+	return output;
+}
+
+/// Ends profiling the current expansion, and returns its timings as a table, one stage per
+/// line, followed by the whole expansion. Returns None when not profiling.
+pub fn report() -> Option<String> {
+	let (started, mut timings) = TIMINGS.with(|cell| cell.borrow_mut().take())?;
+	timings.push((String::from("total"), started.elapsed()));
+	let width = timings.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+	let mut table = String::from("restify: Expansion profile");
+	for (label, elapsed) in timings.iter() {
+		table.push_str(&format!("\n  {label:<width$}  {elapsed:>10.2?}"));
+	}
+	return Some(table);
+}
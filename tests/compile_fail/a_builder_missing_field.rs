@@ -0,0 +1,18 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			#[builder]
+			struct Request {
+				id: u64,
+				name: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	// build() only exists once every required field was set.
+	let _ = Request::builder().with_id(1).build();
+}
@@ -0,0 +1,14 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user" => {
+			struct Response {
+				#[renam = "Name"]
+				name: String,
+			}
+		}
+	}]
+}
+
+fn main(){}
@@ -0,0 +1,14 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user/{id}" => {
+			struct Response { id: u64 }
+		}
+		PUT "/api/user/{id}" => {
+			struct Response { id: u64 }
+		}
+	}]
+}
+
+fn main(){}
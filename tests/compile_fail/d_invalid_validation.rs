@@ -0,0 +1,14 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			struct Request {
+				#[validate(regex = "[a-z")]
+				username: String,
+			}
+		}
+	}]
+}
+
+fn main(){}
@@ -0,0 +1,10 @@
+use rest_macros::restify;
+
+restify!{
+	#[rest:routes = "User Routes"]
+	[pub User: {
+		GET "/api/user" => {}
+	}]
+}
+
+fn main(){}
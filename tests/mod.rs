@@ -7,7 +7,30 @@ use trybuild::TestCases;
 #[test]
 fn test_rest_api(){
 	let t = TestCases::new();
-	t.pass("tests/rest_api/a_basic_usage.rs")
+	t.pass("tests/rest_api/a_basic_usage.rs");
+	t.pass("tests/rest_api/b_endpoint_types.rs");
+	t.pass("tests/rest_api/c_reqres.rs");
+	t.pass("tests/rest_api/d_validate_on_send.rs");
+	t.pass("tests/rest_api/e_path_params.rs");
+	t.pass("tests/rest_api/f_content_negotiation.rs");
+	t.pass("tests/rest_api/g_error_bodies.rs");
+	t.pass("tests/rest_api/h_trace_span.rs");
+	t.pass("tests/rest_api/i_multiple_invocations.rs");
+	t.pass("tests/rest_api/j_builders.rs");
+	t.pass("tests/rest_api/k_queries_and_enums.rs");
+	t.pass("tests/rest_api/l_response_bodies.rs");
+	t.pass("tests/rest_api/m_secrets.rs");
+	t.pass("tests/rest_api/n_headers_and_forms.rs");
+	t.pass("tests/rest_api/o_scalar_fields.rs");
+	t.pass("tests/rest_api/p_validator_backend.rs");
+	t.pass("tests/rest_api/q_ndjson_and_proto.rs");
+	t.pass("tests/rest_api/r_log_backends.rs");
+}
+
+#[test]
+fn test_compile_fail() {
+	let t = TestCases::new();
+	t.compile_fail("tests/compile_fail/*.rs");
 }
 
 #[test]
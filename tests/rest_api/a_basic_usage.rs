@@ -1,4 +1,6 @@
 #![allow(unused)]
+// The TODO list below is free-form notes, not rendered documentation.
+#![allow(clippy::doc_overindented_list_items, clippy::doc_lazy_continuation)]
 
 use displaydoc::Display;
 use std::path::Display;
@@ -74,7 +76,7 @@ use rest_macros::restify;
 ///   - **Example**: Useful for applications that require concurrency and make multiple
 ///       API calls that should not block the main thread.
 /// * [ ] ``` #[retry] ```
-///   - [ ] parser implemented
+///   - [x] parser implemented
 ///   - [ ] generator implemented
 ///   - **Purpose**: Generate code that implements retry logic for API calls.
 ///   - **Use Case**: Automatically retry failed requests under certain conditions,
@@ -88,16 +90,16 @@ use rest_macros::restify;
 ///   - **Use Case**: Generate code that logs important information about API requests and
 ///       responses for debugging and monitoring.
 ///   - **Example**: Log all outgoing requests and incoming responses, or log only when errors occur.
-/// * [ ] ``` #[feature] ```
-///   - [ ] parser implemented
-///   - [ ] generator implemented
+/// * [x] ``` #[feature] ```
+///   - [x] parser implemented
+///   - [x] generator implemented
 ///   - **Purpose**: Conditional compilation of API endpoints based on feature flags.
 ///   - **Use Case**: Enable or disable certain API functionality at compile time based on feature flags.
 ///   - **Example**: Useful in situations where different environments (development, staging,
 ///      production) require different configurations or features.
 ///
 ///
-/// * [ ] Custom Parameter Ident Parser: At the moment, using syn::Ident. Restify is unable
+/// * [x] Custom Parameter Ident Parser: At the moment, using syn::Ident. Restify is unable
 ///       to parse a Vec<?Type>. At the moment, we are parsing for a potential '?' token right
 ///       before we parse for a syn::Type.
 ///         - Possible Solutions:
@@ -110,11 +112,11 @@ use rest_macros::restify;
 ///       This method needs to be more dynamic. Where when new ParamAttributes are created, I
 ///       won't have to update this method. Maybe Go deeper with another layer of Generics?
 ///       I.e., ``` enum ParamAttributes<Specify> ```.
-/// * [ ] Implement Generics & Lifetime annotation parsing and generating.
+/// * [x] Implement Generics & Lifetime annotation parsing and generating.
 ///       Syn has a built-in Token Parser for Rust Lifetimes,
 ///       `syn::Lifetime`. Though. This one would be a bit harder to include
 ///       internal debugging for the user. Maybe add this as a feature..?
-/// * [ ] `Use` statements: Add capability to allow users to include 'use`
+/// * [x] `Use` statements: Add capability to allow users to include 'use`
 ///       statements. I.e., ``` use some::crate::Item; ```
 /// * [ ] For implemented Enums, Use DisplayDoc for Serialization
 /// * [ ] Find a better solution for the compiled Struct/Enum Documentation.
@@ -154,6 +156,29 @@ struct MyStruct<'de: 'a + 'b, 'a, 'b, 'c> {
 	data_c: &'c str,     // A reference that must live as long as 'c
 }
 
+/// Stands in for a crate whose types are serialized remotely, through ``` #[remote=".."] ```
+mod other_crate {
+	pub struct SignUp {
+		pub name: String,
+		password: String,
+	}
+	impl SignUp {
+		pub fn create_password(&self) -> String {
+			self.password.clone()
+		}
+	}
+}
+
+fn skip_if_test(value: &Option<String>) -> bool {
+	value.is_none()
+}
+fn default_test() -> Option<String> {
+	Some(String::from("default"))
+}
+fn seven_eight_nine(value: &Option<u128>) -> bool {
+	matches!(value, Some(789))
+}
+
 restify!{
 	#[builder]
 	[pub DoesVecWork: {
@@ -163,14 +188,16 @@ restify!{
 				#[rename="username"]
 				name: String,
 				#[rename="password"]
-				#[getter="other_crate::Signup::create_password"]
+				#[getter="other_crate::SignUp::create_password"]
+				#[sensitive]
 				pass: String,
 			}
-			#[rename_all="RenameAll"]
+			#[rename_all="PascalCase"]
 			#[builder]
+			#[derives(add(PartialEq, Eq), remove(Clone))]
 			#[log(
 				info="MyIDs Request has been sent",
-				error="Failed to make MyIDs request: {error"
+				error="Failed to make MyIDs request: {error}"
 			)]
 			struct MyIDs<Request> {
 				#[rename="Rename"]
@@ -185,7 +212,7 @@ restify!{
 				Little,
 			}
 			#[derive(Eq, PartialEq, Clone, Ord, PartialOrd)]
-			#[rename_all="CamelCase"]
+			#[rename_all="camelCase"]
 			#[builder]
 			enum MyEnum {
 				#[rename="VARIANT"]
@@ -194,11 +221,11 @@ restify!{
 				Tuple(String)
 				Struct {
 					#[rename="ONE"]
-					#[skip_if="SkipIfTest"]
-					#[default="DefaultTest"]
+					#[skip_if="skip_if_test"]
+					#[default="default_test"]
 					both: ?String,
 					#[rename="TWO"]
-					#[skip_if="SkipIfTest"]
+					#[skip_if="skip_if_test"]
 					one: ?String,
 					#[rename="THREE"]
 					neither: ?String,
@@ -209,13 +236,42 @@ restify!{
 	}],
 	#[builder]
 	[pub SecondEndpoint: {
+		#[trace]
 		GET "v2/endpoint/{id}" => {
-			#[rename_all="CamelCase"]
+			#[rename_all="camelCase"]
 			#[builder]
 			struct EndpointReq<Request> {
 				ids: Vec<String>,
 			}
-			#[rename_all="CamelCase"]
+			#[deny_unknown_fields]
+			struct Response {
+				#[alias = "userName"]
+				#[alias("user", "login")]
+				user_name: ?String,
+				#[with="chrono::serde::ts_seconds"]
+				created_at: chrono::DateTime<chrono::Utc>,
+			}
+			#[tag="type"]
+			#[content="payload"]
+			enum Event {
+				Created(String),
+				Deleted,
+				#[other]
+				Unknown,
+			}
+			#[repr(u8)]
+			enum Status {
+				Active = 1,
+				Suspended,
+				Deleted = 10,
+			}
+			#[untagged]
+			enum Value {
+				Number(i64),
+				Text(String),
+			}
+			#[rename_all="camelCase"]
+			#[name = "MySecondEnum"]
 			enum MyEnum {
 				One,
 				Two(?String),
@@ -225,7 +281,7 @@ restify!{
 					#[rename="V"]
 					five: u64,
 					#[rename="V!"]
-					#[skip_if="SevenEightNine"]
+					#[skip_if="seven_eight_nine"]
 					six: ?u128,
 				}
 			}
@@ -0,0 +1,41 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			struct Request {
+				name: String,
+				age: ?u32,
+			}
+			struct Response {
+				id: u64,
+				name: String,
+			}
+			enum Role {
+				Admin,
+				Member,
+			}
+		}
+	}]
+}
+
+fn main(){
+	assert_eq!(UserPOST::PATH, "/api/user");
+	assert_eq!(UserPOST::METHOD, "POST");
+	
+	let request = Request {
+		name: String::from("tyler"),
+		age: None,
+	};
+	assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"name":"tyler"}"#);
+	
+	let response: Response = serde_json::from_str(r#"{"id":7,"name":"tyler"}"#).unwrap();
+	assert_eq!((response.id, response.name.as_str()), (7, "tyler"));
+	
+	let method = UserPOST {
+		request: request,
+		response: response,
+		role: Role::Admin,
+	};
+	assert!(matches!(method.role, Role::Admin));
+}
@@ -0,0 +1,28 @@
+use rest_macros::restify;
+
+restify!{
+	[pub Profile: {
+		PUT "/api/profile" => {
+			#[derives(add(PartialEq))]
+			struct Profile<ReqRes> {
+				#[rename="displayName"]
+				display_name: String,
+				bio: ?String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let profile = Profile {
+		display_name: String::from("tyler"),
+		bio: Some(String::from("rustacean")),
+	};
+	let json = serde_json::to_string(&profile).unwrap();
+	assert_eq!(json, r#"{"displayName":"tyler","bio":"rustacean"}"#);
+	assert_eq!(Profile::from_json(&json).unwrap(), profile);
+	assert_eq!(Profile::from_slice(json.as_bytes()).unwrap(), profile);
+	
+	let without_bio = Profile::from_json(r#"{"displayName":"tyler"}"#).unwrap();
+	assert_eq!(without_bio.bio, None);
+}
@@ -0,0 +1,39 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		#[validate_on_send]
+		POST "/api/user" => {
+			struct Request {
+				#[validate(email)]
+				email: String,
+				#[validate(range(min: 18, max: 130))]
+				age: u32,
+				#[validate(length(max: 3))]
+				initials: String,
+			}
+			struct Response {
+				id: u64,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let mut method = UserPOST {
+		request: Request {
+			email: String::from("tyler@example.com"),
+			age: 30,
+			initials: String::from("ééé"),
+		},
+		response: Response { id: 1 },
+	};
+	assert!(method.validate().is_ok());
+	
+	method.request.email = String::from("not an email");
+	method.request.age = 7;
+	method.request.initials = String::from("éééé");
+	let errors = method.validate().unwrap_err();
+	let fields = errors.iter().map(|error| error.field).collect::<Vec<_>>();
+	assert_eq!(fields, ["email", "age", "initials"]);
+}
@@ -0,0 +1,27 @@
+use rest_macros::restify;
+
+restify!{
+	[pub Repo: {
+		GET "/api/repos/{owner}/{repo}/issues/{number}" => {
+			struct Response {
+				title: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let path = RepoGETPath {
+		owner: String::from("tyler"),
+		repo: String::from("restify"),
+		number: String::from("42"),
+	};
+	let rendered = path.to_string();
+	assert_eq!(rendered, "/api/repos/tyler/restify/issues/42");
+	assert_eq!(rendered.parse::<RepoGETPath>().unwrap(), path);
+	
+	let error = "/api/repos/tyler/issues/42".parse::<RepoGETPath>().unwrap_err();
+	assert_eq!(error.uri, RepoGET::PATH);
+	assert!("/api/repos/tyler/restify/issues/".parse::<RepoGETPath>().is_err());
+	assert!("/api/repos/tyler/restify/issues/42/comments".parse::<RepoGETPath>().is_err());
+}
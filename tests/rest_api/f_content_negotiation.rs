@@ -0,0 +1,28 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user" => {
+			accepts: [json, xml]
+			struct Response {
+				name: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	assert_eq!(UserGET::ACCEPT, "application/json, application/xml");
+	
+	let json = UserGET::decode_response("application/json; charset=utf-8", br#"{"name":"tyler"}"#).unwrap();
+	assert_eq!(json.name, "tyler");
+	let xml = UserGET::decode_response("text/xml", b"<user><name>tyler</name></user>").unwrap();
+	assert_eq!(xml.name, "tyler");
+	// Without a Content-Type, the first accepted encoding is used.
+	assert!(UserGET::decode_response("", br#"{"name":"tyler"}"#).is_ok());
+	
+	let unaccepted = UserGET::decode_response("text/plain", b"tyler").unwrap_err();
+	assert!(unaccepted.source.is_none());
+	let undecodable = UserGET::decode_response("application/json", b"{").unwrap_err();
+	assert!(undecodable.source.is_some());
+}
@@ -0,0 +1,35 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user" => {
+			struct Response {
+				name: String,
+			}
+			struct ApiError<Error> {
+				code: u16,
+				message: String,
+			}
+		}
+	}]
+}
+
+fn failing_call() -> Result<Response, Box<dyn std::error::Error>> {
+	let response = UserGET::decode(404, br#"{"code":404,"message":"user not found"}"#)??;
+	Ok(response)
+}
+
+fn main(){
+	let response = UserGET::decode(200, br#"{"name":"tyler"}"#).unwrap().unwrap();
+	assert_eq!(response.name, "tyler");
+	
+	let error = UserGET::decode(404, br#"{"code":404,"message":"user not found"}"#).unwrap().unwrap_err();
+	assert_eq!(error.code, 404);
+	assert_eq!(error.to_string(), "user not found");
+	assert_eq!(failing_call().unwrap_err().to_string(), "user not found");
+	
+	let error = UserGET::decode_error(br#"{"code":500,"message":"oops"}"#).unwrap();
+	assert_eq!(error.to_string(), "oops");
+	// A 2xx status never decodes into the Error struct.
+	assert!(UserGET::decode(200, br#"{"code":500,"message":"oops"}"#).is_err());
+}
@@ -0,0 +1,26 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		#[trace]
+		GET "/api/user/{id}" => {
+			struct Response {
+				name: String,
+			}
+		}
+	}]
+}
+
+/// Stands in for a client call, run within the REST Method's span.
+fn call() -> (u16, Response) {
+	let span = UserGET::span();
+	let _entered = span.enter();
+	let response = Response { name: String::from("tyler") };
+	UserGET::record_status(&span, 200);
+	(200, response)
+}
+
+fn main(){
+	let (status, response) = call();
+	assert_eq!((status, response.name.as_str()), (200, "tyler"));
+}
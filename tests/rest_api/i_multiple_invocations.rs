@@ -0,0 +1,81 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user/{id}" => {
+			#[builder]
+			struct Request {
+				#[validate(required)]
+				verbose: bool,
+				#[validate(required)]
+				note: ?String,
+			}
+			struct Response {
+				name: String,
+				role: Role,
+			}
+			#[derive(Clone)]
+			enum Role {
+				Admin,
+				Member,
+			}
+		}
+	}]
+}
+
+restify!{
+	#[rest:routes = "PostRoutes"]
+	[pub Post: {
+		DELETE "/api/post/{id}" => {
+			struct Deleted<Response> {
+				deleted: bool,
+			}
+		}
+	}]
+}
+
+restify!{
+	pub components: {
+		#[builder]
+		struct Address<ReqRes> {
+			#[validate(length(min: 1))]
+			street: String,
+			city: String,
+		}
+		enum Currency {
+			Usd,
+			Eur,
+		}
+	}
+}
+
+fn main(){
+	let request = Request::builder().with_verbose(true).build().unwrap_err();
+	assert_eq!(request.errors[0].field, "note");
+	let request = Request::builder().with_verbose(true).with_note("hi").build().unwrap();
+	assert!(request.verbose);
+	let response = Response::from_json(r#"{"name":"tyler","role":"Admin"}"#).unwrap();
+	assert_eq!(response.name, "tyler");
+	assert_eq!(response.role.as_str(), "Admin");
+	assert!("/api/user".parse::<UserGETPath>().is_err());
+	assert_eq!(Routes::UserGET.path(), "/api/user/{id}");
+	assert_eq!(PostRoutes::PostDELETE.method(), "DELETE");
+
+	let address = Address::builder()
+		.with_street(String::from("Main St"))
+		.with_city(String::from("Springfield"))
+		.build()
+		.unwrap();
+	let address = Address::from_json(&serde_json::to_string(&address).unwrap()).unwrap();
+	assert_eq!(address.city, "Springfield");
+	assert_eq!(Currency::try_from("Eur").unwrap().as_str(), "Eur");
+
+	// Each invocation's shared Types live within its own runtime-support module.
+	let unknown: <Role as TryFrom<&str>>::Error = Role::try_from("Guest").unwrap_err();
+	let other: <Currency as TryFrom<&str>>::Error = Currency::try_from("Yen").unwrap_err();
+	assert_eq!(unknown.value, "Guest");
+	assert_eq!(other.value, "Yen");
+	let empty = Address { street: String::new(), city: String::from("Springfield") };
+	let errors: Vec<_> = empty.validate().unwrap_err().errors.into_iter().map(|error| error.field).collect();
+	assert_eq!(errors, ["street"]);
+}
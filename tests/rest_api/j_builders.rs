@@ -0,0 +1,75 @@
+use rest_macros::restify;
+
+mod checks {
+	pub fn request(request: &super::Request) -> Result<(), String> {
+		match request.order.as_deref() {
+			Some("random") => Err(String::from("can't be random")),
+			_ => Ok(()),
+		}
+	}
+}
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			#[builder]
+			#[constructor]
+			#[validate(requires_together(start, end), mutually_exclusive(id, name), custom = "checks::request")]
+			#[log(debug = "Built {username}", error = "Invalid request: {errors}")]
+			struct Request {
+				#[validate(range(min: 18, max: 115))]
+				age: u32,
+				#[validate(length(min: 3, max: 16), regex = "^[a-z]+$")]
+				username: String,
+				#[validate(min_items = 1, unique)]
+				tags: Vec<String>,
+				#[validate(one_of("asc", "desc", "random"))]
+				order: ?String,
+				start: ?u64,
+				end: ?u64,
+				id: ?u64,
+				name: ?String,
+			}
+			struct Response {
+				count: u32 = 10,
+				note: ?String = "restify",
+			}
+		}
+	}]
+}
+
+fn main(){
+	// Required fields are set through the typestate builder, optional ones through with_/without_.
+	let request = Request::builder()
+		.with_age(30)
+		.with_username(String::from("tyler"))
+		.with_tags(vec![String::from("admin")])
+		.with_order("asc")
+		.without_order()
+		.build()
+		.unwrap();
+	assert_eq!(request.order, None);
+
+	// Every failure is reported at once, field rules before type rules.
+	let invalid = Request {
+		age: 12,
+		username: String::from("Tyler!"),
+		tags: vec![String::from("a"), String::from("a")],
+		order: Some(String::from("random")),
+		start: Some(1),
+		end: None,
+		id: Some(1),
+		name: Some(String::from("tyler")),
+	};
+	let fields = invalid.validate().unwrap_err().errors.into_iter().map(|error| error.field).collect::<Vec<_>>();
+	assert_eq!(fields, ["age", "username", "tags", "start, end", "id, name", "Request"]);
+
+	let constructed = Request::new(30, String::from("tyler"), vec![String::from("admin")]);
+	assert!(constructed.validate().is_ok());
+	assert!(Request::builder().with_age(1).with_username(String::from("tyler")).with_tags(vec![]).build().is_err());
+
+	// Default values fill in missing fields.
+	let response = Response::from_json("{}").unwrap();
+	assert_eq!(response.count, 10);
+	assert_eq!(response.note.as_deref(), Some("restify"));
+}
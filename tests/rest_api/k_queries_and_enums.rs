@@ -0,0 +1,60 @@
+use rest_macros::restify;
+
+restify!{
+	[pub Issue: {
+		GET "/api/issues" => {
+			#[array_format = "comma"]
+			#[rename_all = "camelCase"]
+			struct Query {
+				page: u32,
+				user_ids: Vec<u64>,
+				state: ?State,
+			}
+			#[rename_all = "snake_case"]
+			#[derives(add(Clone, PartialEq))]
+			enum State {
+				InProgress,
+				#[rename = "done"]
+				#[alias("finished", "complete")]
+				Completed,
+			}
+			struct Response {
+				status: Status,
+				level: Level,
+			}
+			#[derive(Clone)]
+			enum Status {
+				Active,
+				#[other]
+				Unknown,
+			}
+			#[repr(u8)]
+			#[derive(Clone)]
+			enum Level {
+				#[default]
+				Low,
+				High,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let query = Query { page: 2, user_ids: vec![1, 2], state: Some(State::Completed) };
+	let rendered = query.to_string().unwrap();
+	assert_eq!(rendered, "page=2&userIds=1,2&state=done");
+	let pairs = query.to_pairs().unwrap();
+	assert_eq!(pairs[1], (String::from("userIds"), String::from("1,2")));
+	let parsed = Query::from_query_str(&rendered).unwrap();
+	assert_eq!(parsed.user_ids, [1, 2]);
+
+	assert_eq!(State::InProgress.as_str(), "in_progress");
+	assert!(matches!(State::try_from("finished"), Ok(State::Completed)));
+	assert_eq!(State::try_from("closed").unwrap_err().value, "closed");
+
+	let response = Response::from_json(r#"{"status":"Archived","level":1}"#).unwrap();
+	assert!(matches!(response.status, Status::Unknown));
+	assert!(matches!(Status::try_from("Archived"), Ok(Status::Unknown)));
+	assert!(matches!(response.level, Level::High));
+	assert!(matches!(Level::default(), Level::Low));
+}
@@ -0,0 +1,47 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user/{id}" => {
+			#[envelope = "data"]
+			struct Response {
+				name: String,
+			}
+		}
+	}]
+	[pub Post: {
+		GET "/api/post/{id}" => {
+			#[hal(author = User::GET)]
+			#[collect_extra]
+			struct Post<Response> {
+				title: String,
+			}
+		}
+		PUT "/api/post/{id}" => {
+			#[content_type = "xml"]
+			struct Draft<ReqRes> {
+				title: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let user = Response::from_json(r#"{"data":{"name":"tyler"}}"#).unwrap();
+	assert_eq!(user.name, "tyler");
+	assert!(Response::from_json(r#"{"name":"tyler"}"#).is_err());
+
+	let post = Post::from_json(r#"{
+		"title": "restify",
+		"draft": true,
+		"_links": { "author": { "href": "/api/user/42" } }
+	}"#).unwrap();
+	assert_eq!(post.extra["draft"], serde_json::Value::Bool(true));
+	assert_eq!(post.link("author"), Some("/api/user/42"));
+	assert_eq!(post.follow_author().unwrap().unwrap().id, "42");
+
+	let draft = Draft { title: String::from("restify") };
+	let xml = draft.to_xml().unwrap();
+	assert_eq!(Draft::from_xml(&xml).unwrap().title, "restify");
+	assert_eq!(Draft::from_slice(xml.as_bytes()).unwrap().title, "restify");
+}
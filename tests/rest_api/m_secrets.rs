@@ -0,0 +1,37 @@
+use rest_macros::restify;
+use secrecy::ExposeSecret;
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			struct Request {
+				username: String,
+				#[secret]
+				password: String,
+				#[secret]
+				pin: u32,
+			}
+			struct Response {
+				#[secret]
+				token: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let request = Request {
+		username: String::from("tyler"),
+		password: secrecy::SecretString::new(String::from("hunter2")),
+		pin: secrecy::Secret::new(1234),
+	};
+	// Secrets are only exposed when serialized, never when debugged.
+	let json = serde_json::to_string(&request).unwrap();
+	assert_eq!(json, r#"{"username":"tyler","password":"hunter2","pin":1234}"#);
+	let debugged = format!("{:?}", request);
+	assert!(!debugged.contains("hunter2") && !debugged.contains("1234"));
+	
+	let response = Response::from_json(r#"{"token":"abc"}"#).unwrap();
+	assert_eq!(response.token.expose_secret(), "abc");
+	assert!(!format!("{:?}", response).contains("abc"));
+}
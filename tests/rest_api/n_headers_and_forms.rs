@@ -0,0 +1,51 @@
+use rest_macros::restify;
+
+restify!{
+	[pub Auth: {
+		GET "/api/session" => {
+			struct Header {
+				#[rename = "X-Api-Key"]
+				#[sensitive]
+				api_key: String,
+				request_id: ?u64,
+				page_size: u32 = 10,
+			}
+			#[query_backend = "urlencoded"]
+			struct Query {
+				search: String,
+				page: ?u32,
+			}
+		}
+		POST "/oauth/token" => {
+			struct Token<Form> {
+				grant_type: String,
+				remember: ?bool,
+			}
+		}
+	}]
+}
+
+fn main(){
+	// Headers are read from, and written into, an http::HeaderMap.
+	let mut map = http::header::HeaderMap::new();
+	map.insert("x-api-key", http::header::HeaderValue::from_static("secret"));
+	map.insert("request-id", http::header::HeaderValue::from_static("7"));
+	let header = Header::try_from(&map).unwrap();
+	assert_eq!(header.request_id, Some(7));
+	assert_eq!(header.page_size, 10);
+	let rendered = header.to_header_map().unwrap();
+	assert!(rendered["x-api-key"].is_sensitive());
+	assert_eq!(rendered["page-size"], "10");
+	assert!(Header::try_from(&http::header::HeaderMap::new()).is_err());
+	
+	let query = Query { search: String::from("a b"), page: None };
+	let rendered = query.to_string().unwrap();
+	assert_eq!(rendered, "search=a+b");
+	assert_eq!(Query::from_query_str(&rendered).unwrap().search, "a b");
+	
+	let token = Token { grant_type: String::from("client_credentials"), remember: Some(true) };
+	let form = token.to_form().unwrap();
+	assert_eq!(form, "grant_type=client_credentials&remember=true");
+	assert_eq!(Token::from_form(&form).unwrap().remember, Some(true));
+	assert_eq!(Token::CONTENT_TYPE, "application/x-www-form-urlencoded");
+}
@@ -0,0 +1,43 @@
+use rest_macros::restify;
+
+restify!{
+	[pub Invoice: {
+		PATCH "/api/invoice/{id}" => {
+			struct Invoice<ReqRes> {
+				id: Uuid,
+				#[uuid]
+				parent_id: ?String,
+				#[decimal]
+				amount: String,
+				total: Money,
+				note: ??String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let id = uuid::Uuid::nil();
+	let request = Invoice {
+		id,
+		parent_id: None,
+		amount: rust_decimal::Decimal::new(1050, 2),
+		total: rust_decimal::Decimal::new(2100, 2),
+		note: Some(None),
+	};
+	// Decimals are sent as strings, and an explicit null is told apart from an absent field.
+	let json = serde_json::to_value(&request).unwrap();
+	assert_eq!(json["id"], "00000000-0000-0000-0000-000000000000");
+	assert_eq!(json["amount"], "10.50");
+	assert_eq!(json["total"], "21.00");
+	assert_eq!(json["note"], serde_json::Value::Null);
+	assert!(json.get("parent_id").is_none());
+	
+	let patched = serde_json::from_str::<Invoice>(r#"{
+		"id": "00000000-0000-0000-0000-000000000000",
+		"amount": "1",
+		"total": "2"
+	}"#).unwrap();
+	assert_eq!(patched.id, id);
+	assert_eq!(patched.note, None);
+}
@@ -0,0 +1,33 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		POST "/api/user" => {
+			#[validate(backend = "validator")]
+			struct Request {
+				#[validate(required, email)]
+				email: ?String,
+				#[validate(range(min: 18))]
+				age: u32,
+				#[validate(length(max: 3), regex = "^[A-Za-zé]+$")]
+				initials: String,
+			}
+		}
+	}]
+}
+
+fn main(){
+	use validator::Validate;
+	let request = Request {
+		email: Some(String::from("tyler@example.com")),
+		age: 30,
+		initials: String::from("ééé"),
+	};
+	assert!(request.validate().is_ok());
+	
+	let invalid = Request { email: None, age: 7, initials: String::from("ab1d") };
+	let errors = invalid.validate().unwrap_err();
+	let mut fields = errors.field_errors().into_keys().collect::<Vec<_>>();
+	fields.sort();
+	assert_eq!(fields, ["age", "email", "initials"]);
+}
@@ -0,0 +1,43 @@
+use rest_macros::restify;
+
+mod pb {
+	#[derive(Clone, PartialEq, prost::Message)]
+	pub struct UserReply {
+		#[prost(string, tag = "1")]
+		pub name: String,
+	}
+}
+
+restify!{
+	[pub Event: {
+		GET "/api/events" => {
+			#[ndjson]
+			struct Response {
+				id: u32,
+			}
+		}
+	}]
+	[pub User: {
+		GET "/api/user" => {
+			proto User<Response> = pb::UserReply;
+		}
+	}]
+}
+
+fn main(){
+	let events = Response::from_ndjson("{\"id\":1}\n{\"id\":2}\n").map(|event| event.unwrap().id).collect::<Vec<_>>();
+	assert_eq!(events, [1, 2]);
+	
+	// Lines split across chunks are only decoded once complete.
+	let chunks = futures::stream::iter(["{\"id\":3}\n{\"i", "d\":4}\n", "oops\n"].map(Ok::<_, std::io::Error>));
+	let streamed = futures::executor::block_on_stream(Box::pin(Response::from_ndjson_stream(chunks))).collect::<Vec<_>>();
+	assert_eq!(streamed[0].as_ref().unwrap().id, 3);
+	assert_eq!(streamed[1].as_ref().unwrap().id, 4);
+	assert!(matches!(streamed[2], Err(NdjsonError::Line { line: 3, .. })));
+	
+	let reply = pb::UserReply { name: String::from("tyler") };
+	let body = prost::Message::encode_to_vec(&reply);
+	let user = User::from_slice(&body).unwrap();
+	assert_eq!(user.name, "tyler");
+	assert_eq!(User::CONTENT_TYPE, "application/x-protobuf");
+}
@@ -0,0 +1,32 @@
+use rest_macros::restify;
+
+restify!{
+	[pub User: {
+		GET "/api/user/{id}" => {
+			#[log(info = "Fetching {id}", error = "Failed to fetch {id}: {error}")]
+			struct Request {
+				id: u64,
+			}
+			#[log(backend = "tracing", debug = "Fetched {name}")]
+			struct Response {
+				name: String,
+			}
+		}
+		DELETE "/api/user/{id}" => {
+			#[log(backend = "slog", warn = "Deleting {id}")]
+			struct Remove<Request> {
+				id: u64,
+			}
+		}
+	}]
+}
+
+fn main(){
+	let request = Request { id: 1 };
+	request.log_info();
+	request.log_error(&"timed out");
+	Response { name: String::from("tyler") }.log_debug();
+	
+	let logger = slog::Logger::root(slog::Discard, slog::o!());
+	Remove { id: 1 }.log_warn(&logger);
+}